@@ -6,7 +6,10 @@
 //! ```
 
 use twiml_rust::{
-    voice::{Dial, DialNumber, Gather, Record, Say},
+    voice::{
+        Dial, DialNumber, Gather, GatherInput, PollyEngine, PollyVoice, Record, Say,
+        SsmlBreakStrength, SsmlEmphasisLevel, Voice,
+    },
     TwiML, VoiceResponse,
 };
 
@@ -47,7 +50,7 @@ fn simple_greeting() {
 /// Interactive voice response menu
 fn ivr_menu() {
     let gather = Gather::new()
-        .input(vec!["dtmf".to_string(), "speech".to_string()])
+        .input(vec![GatherInput::Dtmf, GatherInput::Speech])
         .action("https://example.com/handle-input")
         .method("POST")
         .timeout(10)
@@ -102,10 +105,10 @@ fn voicemail() {
 /// Advanced SSML features
 fn advanced_ssml() {
     let say = Say::new("Welcome to our service")
-        .voice("Polly.Joanna")
+        .voice(Voice::Polly(PollyVoice::Joanna, PollyEngine::Neural))
         .language("en-US")
-        .add_break(Some("medium".to_string()), None)
-        .add_emphasis(Some("strong".to_string()), "Please listen carefully")
+        .add_break(Some(SsmlBreakStrength::Medium), None)
+        .add_emphasis(Some(SsmlEmphasisLevel::Strong), "Please listen carefully")
         .add_break(None, Some("1s".to_string()))
         .add_prosody(
             Some("high".to_string()),
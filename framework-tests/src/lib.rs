@@ -0,0 +1,4 @@
+//! Integration tests of the web framework features of `twiml-rust`.
+//!
+//! The tests are in `tests/`. They live in this package because the
+//! frameworks need newer Rust than the library itself.
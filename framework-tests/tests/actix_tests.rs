@@ -0,0 +1,146 @@
+//! What the actix-web integration adds on top of the `http` module: the
+//! validator looked up in `app_data`, errors turned into responses, and the
+//! middleware handing the body it checked on to the handler. The signature
+//! checks themselves are covered by `tests/http_tests.rs`.
+
+#[path = "../../tests/common/mod.rs"]
+mod common;
+
+use actix_web::http::{header, StatusCode};
+use actix_web::middleware::from_fn;
+use actix_web::{test, web, App, HttpResponse};
+use twiml_rust::actix::{verify_signature, TwilioForm};
+use twiml_rust::signature::RequestValidator;
+use twiml_rust::webhook::VoiceRequest;
+use twiml_rust::{TwiML, VoiceResponse};
+
+use common::{TOKEN, VOICE_BODY};
+
+async fn voice(TwilioForm(call): TwilioForm<VoiceRequest>) -> VoiceResponse {
+    VoiceResponse::new().say(format!("Hello {}", call.from))
+}
+
+async fn echo(body: web::Bytes) -> HttpResponse {
+    HttpResponse::Ok().body(body)
+}
+
+macro_rules! app {
+    () => {
+        test::init_service(
+            App::new()
+                .app_data(web::Data::new(RequestValidator::new(TOKEN)))
+                .route("/voice", web::post().to(voice))
+                .service(
+                    web::scope("/hooks")
+                        .wrap(from_fn(verify_signature))
+                        .route("/echo", web::post().to(echo)),
+                ),
+        )
+        .await
+    };
+}
+
+fn signed(path: &str, body: &str) -> test::TestRequest {
+    test::TestRequest::post()
+        .uri(path)
+        .insert_header((header::HOST, "example.com"))
+        .insert_header((header::CONTENT_TYPE, "application/x-www-form-urlencoded"))
+        .insert_header((
+            "X-Twilio-Signature",
+            common::signature("example.com", path, body),
+        ))
+        .set_payload(body.to_string())
+}
+
+#[actix_web::test]
+async fn test_validator_comes_from_app_data() {
+    let app = app!();
+    let response = test::call_service(&app, signed("/voice", VOICE_BODY).to_request()).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get(header::CONTENT_TYPE).unwrap(),
+        "text/xml; charset=utf-8"
+    );
+    let expected = VoiceResponse::new().say("Hello +15551230001").to_xml();
+    assert_eq!(test::read_body(response).await, expected.as_bytes());
+
+    // Without a validator the app is misconfigured, not the request
+    let app = test::init_service(App::new().route("/voice", web::post().to(voice))).await;
+    let response = test::call_service(&app, signed("/voice", VOICE_BODY).to_request()).await;
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[actix_web::test]
+async fn test_errors_map_to_responses() {
+    let app = app!();
+
+    let request = test::TestRequest::post()
+        .uri("/voice")
+        .insert_header((header::HOST, "example.com"))
+        .set_payload(VOICE_BODY)
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert_eq!(
+        test::read_body(response).await,
+        "Missing X-Twilio-Signature"
+    );
+
+    let request = signed("/voice", VOICE_BODY)
+        .set_payload(VOICE_BODY.replace("ringing", "completed"))
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert_eq!(
+        test::read_body(response).await,
+        "Invalid X-Twilio-Signature"
+    );
+
+    let request = signed("/voice", "CallSid=CA123").to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(response).await;
+    assert!(std::str::from_utf8(&body).unwrap().contains("AccountSid"));
+}
+
+#[actix_web::test]
+async fn test_middleware_passes_the_body_on() {
+    let app = app!();
+    let request = signed("/hooks/echo", VOICE_BODY).to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(test::read_body(response).await, VOICE_BODY.as_bytes());
+
+    let request = signed("/hooks/echo", VOICE_BODY)
+        .set_payload("CallSid=CA999")
+        .to_request();
+    let err = test::try_call_service(&app, request).await.unwrap_err();
+    assert_eq!(err.error_response().status(), StatusCode::FORBIDDEN);
+}
+
+#[actix_web::test]
+async fn test_middleware_checks_json_bodies() {
+    let app = app!();
+    // The JSON example from Twilio's security documentation
+    let body = r#"{"property": "value", "boolean": true}"#;
+    let path = "/hooks/echo?foo=1&bar=2&bodySHA256=\
+                0a1ff7634d9ab3b95db5c9a2dfe9416e41502b283a80c7cf19632632f96e6620";
+    // Only the URL is signed
+    let signature = common::signature("example.com", path, "");
+    let json = |body: &'static str| {
+        test::TestRequest::post()
+            .uri(path)
+            .insert_header((header::HOST, "example.com"))
+            .insert_header((header::CONTENT_TYPE, "application/json"))
+            .insert_header(("X-Twilio-Signature", signature.clone()))
+            .set_payload(body)
+            .to_request()
+    };
+
+    let response = test::call_service(&app, json(body)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(test::read_body(response).await, body.as_bytes());
+
+    let err = test::try_call_service(&app, json("{}")).await.unwrap_err();
+    assert_eq!(err.error_response().status(), StatusCode::FORBIDDEN);
+}
@@ -0,0 +1,101 @@
+//! What the axum integration adds on top of the `http` module: the validator
+//! looked up in the router state, and rejections turned into responses.
+//! The signature checks themselves are covered by `tests/http_tests.rs`.
+
+#[path = "../../tests/common/mod.rs"]
+mod common;
+
+use axum::body::{to_bytes, Body};
+use axum::extract::FromRef;
+use axum::http::{header, Request, StatusCode};
+use axum::response::Response;
+use axum::routing::post;
+use axum::Router;
+use tower::ServiceExt;
+use twiml_rust::axum::TwilioForm;
+use twiml_rust::signature::RequestValidator;
+use twiml_rust::webhook::VoiceRequest;
+use twiml_rust::{TwiML, VoiceResponse};
+
+use common::{TOKEN, VOICE_BODY};
+
+async fn voice(TwilioForm(call): TwilioForm<VoiceRequest>) -> VoiceResponse {
+    VoiceResponse::new().say(format!("Hello {}", call.from))
+}
+
+fn app() -> Router {
+    Router::new()
+        .route("/voice", post(voice))
+        .with_state(RequestValidator::new(TOKEN))
+}
+
+/// Application state holding the validator among other things
+#[derive(Clone)]
+struct AppState {
+    validator: RequestValidator,
+}
+
+impl FromRef<AppState> for RequestValidator {
+    fn from_ref(state: &AppState) -> Self {
+        state.validator.clone()
+    }
+}
+
+fn signed(body: &str) -> Request<Body> {
+    Request::post("/voice")
+        .header(header::HOST, "example.com")
+        .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .header(
+            "X-Twilio-Signature",
+            common::signature("example.com", "/voice", body),
+        )
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+async fn text(response: Response) -> String {
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn test_validator_comes_from_the_state() {
+    let response = app().oneshot(signed(VOICE_BODY)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/xml; charset=utf-8"
+    );
+    let expected = VoiceResponse::new().say("Hello +15551230001").to_xml();
+    assert_eq!(text(response).await, expected);
+
+    let app = Router::new()
+        .route("/voice", post(voice))
+        .with_state(AppState {
+            validator: RequestValidator::new(TOKEN),
+        });
+    let response = app.oneshot(signed(VOICE_BODY)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_rejections_map_to_responses() {
+    let mut request = signed(VOICE_BODY);
+    request.headers_mut().remove("X-Twilio-Signature");
+    let response = app().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert_eq!(text(response).await, "Missing X-Twilio-Signature");
+
+    let (parts, _) = signed(VOICE_BODY).into_parts();
+    let tampered = Body::from(VOICE_BODY.replace("ringing", "completed"));
+    let response = app()
+        .oneshot(Request::from_parts(parts, tampered))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert_eq!(text(response).await, "Invalid X-Twilio-Signature");
+
+    let response = app().oneshot(signed("CallSid=CA123")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert!(text(response).await.contains("AccountSid"));
+}
@@ -0,0 +1,235 @@
+//! [actix-web](https://docs.rs/actix-web) integration (`actix` feature).
+//!
+//! [`VoiceResponse`], [`MessagingResponse`] and [`FaxResponse`] implement
+//! [`Responder`] and are served as [`CONTENT_TYPE`]. [`TwilioForm`] extracts
+//! a typed webhook request once its `X-Twilio-Signature` checks out, and the
+//! [`verify_signature`] middleware checks every request of a scope, JSON
+//! bodies included.
+//!
+//! Both read the auth token from a [`RequestValidator`] registered as app
+//! data, either wrapped in [`web::Data`] or as is.
+//!
+//! # Example
+//! ```
+//! use actix_web::{web, App};
+//! use twiml_rust::actix::TwilioForm;
+//! use twiml_rust::signature::RequestValidator;
+//! use twiml_rust::webhook::VoiceRequest;
+//! use twiml_rust::VoiceResponse;
+//!
+//! async fn incoming(TwilioForm(call): TwilioForm<VoiceRequest>) -> VoiceResponse {
+//!     VoiceResponse::new().say(format!("Hello {}", call.from))
+//! }
+//!
+//! let app = App::new()
+//!     .app_data(web::Data::new(RequestValidator::new("your_auth_token")))
+//!     .route("/voice", web::post().to(incoming));
+//! ```
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+
+use actix_web::body::{BoxBody, MessageBody};
+use actix_web::dev::{Payload, ServiceRequest, ServiceResponse};
+use actix_web::http::StatusCode;
+use actix_web::middleware::Next;
+use actix_web::web::{self, Bytes};
+use actix_web::{FromRequest, HttpRequest, HttpResponse, Responder, ResponseError};
+
+use crate::error::Error;
+use crate::fax::FaxResponse;
+use crate::messaging::MessagingResponse;
+use crate::render::CONTENT_TYPE;
+use crate::signature::{self, RequestValidator};
+use crate::voice::VoiceResponse;
+use crate::webhook::{FormParams, WebhookRequest};
+use crate::TwiML;
+
+macro_rules! responder {
+    ($($response:ty),+) => {
+        $(
+            impl Responder for $response {
+                type Body = BoxBody;
+
+                fn respond_to(self, _req: &HttpRequest) -> HttpResponse {
+                    HttpResponse::Ok()
+                        .content_type(CONTENT_TYPE)
+                        .body(self.to_xml())
+                }
+            }
+        )+
+    };
+}
+
+responder!(VoiceResponse, MessagingResponse, FaxResponse);
+
+/// Extracts a webhook request whose `X-Twilio-Signature` is valid
+///
+/// `T` is one of the [`webhook`](crate::webhook) request types, or
+/// [`FormParams`] for the raw parameters.
+///
+/// Twilio signs the public URL it requested. It is rebuilt from `https` and
+/// the `Host` header. Behind a proxy that sets `X-Forwarded-Proto` and
+/// `X-Forwarded-Host`, opt in with
+/// [`RequestValidator::trust_forwarded_headers`]. Where that does not give
+/// the URL Twilio used, read the body as [`Bytes`] and call
+/// [`RequestValidator::validate_form`] with the right URL instead.
+#[derive(Debug, Clone)]
+pub struct TwilioForm<T>(pub T);
+
+impl<T: WebhookRequest + 'static> FromRequest for TwilioForm<T> {
+    type Error = TwilioFormError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        let body = Bytes::from_request(&req, payload);
+        Box::pin(async move {
+            let body = body.await.map_err(TwilioFormError::Payload)?;
+            let (validator, signature) = credentials(&req)?;
+            let body = std::str::from_utf8(&body).map_err(|_| {
+                TwilioFormError::InvalidRequest(Error::parse("form", "body is not UTF-8"))
+            })?;
+            let params = FormParams::parse(body).map_err(TwilioFormError::InvalidRequest)?;
+
+            if !validator.validate(&request_url(&req, validator), &params, signature) {
+                return Err(TwilioFormError::InvalidSignature);
+            }
+            T::from_params(params)
+                .map(TwilioForm)
+                .map_err(TwilioFormError::InvalidRequest)
+        })
+    }
+}
+
+/// Middleware rejecting requests without a valid `X-Twilio-Signature`
+///
+/// Wrap it with [`from_fn`](actix_web::middleware::from_fn). Requests whose
+/// URL carries `bodySHA256` are checked with
+/// [`RequestValidator::validate_body`], all others as form posts. The body
+/// is put back afterwards, so handlers can still extract it.
+///
+/// # Example
+/// ```
+/// use actix_web::middleware::from_fn;
+/// use actix_web::{web, App, HttpResponse};
+/// use twiml_rust::actix::verify_signature;
+/// use twiml_rust::signature::RequestValidator;
+///
+/// let app = App::new()
+///     .app_data(web::Data::new(RequestValidator::new("your_auth_token")))
+///     .service(
+///         web::scope("/twilio")
+///             .wrap(from_fn(verify_signature))
+///             .route("/status", web::post().to(HttpResponse::NoContent)),
+///     );
+/// ```
+pub async fn verify_signature(
+    mut req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let body = req
+        .extract::<Bytes>()
+        .await
+        .map_err(TwilioFormError::Payload)?;
+    let (validator, signature) = credentials(req.request())?;
+
+    let url = request_url(req.request(), validator);
+    let signs_body =
+        FormParams::parse(req.query_string()).is_ok_and(|query| query.get("bodySHA256").is_some());
+    let valid = if signs_body {
+        validator.validate_body(&url, &body, signature)
+    } else {
+        std::str::from_utf8(&body).is_ok_and(|body| validator.validate_form(&url, body, signature))
+    };
+    if !valid {
+        return Err(TwilioFormError::InvalidSignature.into());
+    }
+
+    req.set_payload(Payload::from(body));
+    next.call(req).await
+}
+
+/// The validator registered with the app and the signature of `req`
+fn credentials(req: &HttpRequest) -> Result<(&RequestValidator, &str), TwilioFormError> {
+    let validator = req
+        .app_data::<web::Data<RequestValidator>>()
+        .map(|data| data.get_ref())
+        .or_else(|| req.app_data::<RequestValidator>())
+        .ok_or(TwilioFormError::MissingValidator)?;
+    let signature = req
+        .headers()
+        .get("X-Twilio-Signature")
+        .and_then(|value| value.to_str().ok())
+        .ok_or(TwilioFormError::MissingSignature)?;
+    Ok((validator, signature))
+}
+
+/// The URL Twilio requested, as far as the request tells
+fn request_url(req: &HttpRequest, validator: &RequestValidator) -> String {
+    let uri = req.uri();
+    signature::request_url(
+        |name| req.headers().get(name)?.to_str().ok(),
+        validator.trusts_forwarded_headers(),
+        uri.scheme_str(),
+        uri.authority().map(|authority| authority.as_str()),
+        uri.path_and_query().map_or("/", |path| path.as_str()),
+    )
+}
+
+/// Why [`TwilioForm`] or [`verify_signature`] rejected a request
+#[derive(Debug)]
+pub enum TwilioFormError {
+    /// No [`RequestValidator`] is registered as app data
+    MissingValidator,
+    /// The request has no `X-Twilio-Signature` header
+    MissingSignature,
+    /// The signature does not match the request
+    InvalidSignature,
+    /// The body could not be read
+    Payload(actix_web::Error),
+    /// The body is not a well-formed webhook request of the expected type
+    InvalidRequest(Error),
+}
+
+impl fmt::Display for TwilioFormError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TwilioFormError::MissingValidator => {
+                write!(f, "No RequestValidator registered as app data")
+            }
+            TwilioFormError::MissingSignature => write!(f, "Missing X-Twilio-Signature"),
+            TwilioFormError::InvalidSignature => write!(f, "Invalid X-Twilio-Signature"),
+            TwilioFormError::Payload(err) => write!(f, "{}", err),
+            TwilioFormError::InvalidRequest(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for TwilioFormError {}
+
+impl ResponseError for TwilioFormError {
+    /// `403 Forbidden` for signature failures, `400 Bad Request` for
+    /// malformed requests and `500 Internal Server Error` for a missing
+    /// validator
+    fn status_code(&self) -> StatusCode {
+        match self {
+            TwilioFormError::MissingValidator => StatusCode::INTERNAL_SERVER_ERROR,
+            TwilioFormError::MissingSignature | TwilioFormError::InvalidSignature => {
+                StatusCode::FORBIDDEN
+            }
+            TwilioFormError::Payload(err) => err.as_response_error().status_code(),
+            TwilioFormError::InvalidRequest(_) => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        match self {
+            TwilioFormError::Payload(err) => err.error_response(),
+            _ => HttpResponse::build(self.status_code())
+                .content_type("text/plain; charset=utf-8")
+                .body(self.to_string()),
+        }
+    }
+}
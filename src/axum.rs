@@ -0,0 +1,143 @@
+//! [axum](https://docs.rs/axum) integration (`axum` feature).
+//!
+//! [`VoiceResponse`], [`MessagingResponse`] and [`FaxResponse`] implement
+//! [`IntoResponse`], so handlers can return them directly; they are served
+//! as [`CONTENT_TYPE`](crate::render::CONTENT_TYPE). [`TwilioForm`] extracts a typed webhook request and
+//! rejects it unless its `X-Twilio-Signature` is valid.
+//!
+//! # Example
+//! ```
+//! use axum::routing::post;
+//! use axum::Router;
+//! use twiml_rust::axum::TwilioForm;
+//! use twiml_rust::signature::RequestValidator;
+//! use twiml_rust::webhook::VoiceRequest;
+//! use twiml_rust::VoiceResponse;
+//!
+//! async fn incoming(TwilioForm(call): TwilioForm<VoiceRequest>) -> VoiceResponse {
+//!     VoiceResponse::new().say(format!("Hello {}", call.from))
+//! }
+//!
+//! let app: Router = Router::new()
+//!     .route("/voice", post(incoming))
+//!     .with_state(RequestValidator::new("your_auth_token"));
+//! ```
+
+use std::fmt;
+
+use ::axum::body::Bytes;
+use ::axum::extract::rejection::BytesRejection;
+use ::axum::extract::{FromRef, FromRequest, Request};
+use ::axum::http::StatusCode;
+use ::axum::response::{IntoResponse, Response};
+
+use crate::error::Error;
+use crate::fax::FaxResponse;
+use crate::http::{self, VerifyError};
+use crate::messaging::MessagingResponse;
+use crate::signature::RequestValidator;
+use crate::voice::VoiceResponse;
+use crate::webhook::WebhookRequest;
+
+macro_rules! into_response {
+    ($($response:ty),+) => {
+        $(
+            impl IntoResponse for $response {
+                fn into_response(self) -> Response {
+                    ::axum::http::Response::<String>::from(self).into_response()
+                }
+            }
+        )+
+    };
+}
+
+into_response!(VoiceResponse, MessagingResponse, FaxResponse);
+
+/// Extracts a webhook request whose `X-Twilio-Signature` is valid
+///
+/// `T` is one of the [`webhook`](crate::webhook) request types, or
+/// [`FormParams`](crate::webhook::FormParams) for the raw parameters. The
+/// auth token comes from a [`RequestValidator`] in the router state, either
+/// as the state itself or through [`FromRef`].
+///
+/// The request is checked by [`http::verify_form`], which rebuilds the
+/// public URL Twilio signed from `https` and the `Host` header. Behind a
+/// proxy that sets `X-Forwarded-Proto` and `X-Forwarded-Host`, opt in with
+/// [`RequestValidator::trust_forwarded_headers`]. Where that does not give
+/// the URL Twilio used, read the body as [`Bytes`] and call
+/// [`RequestValidator::validate_form`] with the right URL instead.
+#[derive(Debug, Clone)]
+pub struct TwilioForm<T>(pub T);
+
+impl<T, S> FromRequest<S> for TwilioForm<T>
+where
+    T: WebhookRequest,
+    RequestValidator: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = TwilioFormRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let (parts, body) = req.into_parts();
+        let body = Bytes::from_request(Request::from_parts(parts.clone(), body), state)
+            .await
+            .map_err(TwilioFormRejection::Body)?;
+        let request = Request::from_parts(parts, body);
+
+        http::verify_form(&RequestValidator::from_ref(state), &request)
+            .map(TwilioForm)
+            .map_err(TwilioFormRejection::from)
+    }
+}
+
+/// Why [`TwilioForm`] rejected a request
+#[derive(Debug)]
+pub enum TwilioFormRejection {
+    /// The request has no `X-Twilio-Signature` header
+    MissingSignature,
+    /// The signature does not match the request
+    InvalidSignature,
+    /// The body could not be read
+    Body(BytesRejection),
+    /// The body is not a well-formed webhook request of the expected type
+    InvalidRequest(Error),
+}
+
+impl From<VerifyError> for TwilioFormRejection {
+    fn from(err: VerifyError) -> Self {
+        match err {
+            VerifyError::MissingSignature => TwilioFormRejection::MissingSignature,
+            VerifyError::InvalidSignature => TwilioFormRejection::InvalidSignature,
+            VerifyError::InvalidRequest(err) => TwilioFormRejection::InvalidRequest(err),
+        }
+    }
+}
+
+impl fmt::Display for TwilioFormRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TwilioFormRejection::MissingSignature => write!(f, "Missing X-Twilio-Signature"),
+            TwilioFormRejection::InvalidSignature => write!(f, "Invalid X-Twilio-Signature"),
+            TwilioFormRejection::Body(rejection) => write!(f, "{}", rejection.body_text()),
+            TwilioFormRejection::InvalidRequest(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for TwilioFormRejection {}
+
+impl IntoResponse for TwilioFormRejection {
+    /// `403 Forbidden` for signature failures, `400 Bad Request` for
+    /// malformed requests
+    fn into_response(self) -> Response {
+        match self {
+            TwilioFormRejection::MissingSignature | TwilioFormRejection::InvalidSignature => {
+                (StatusCode::FORBIDDEN, self.to_string()).into_response()
+            }
+            TwilioFormRejection::Body(rejection) => rejection.into_response(),
+            TwilioFormRejection::InvalidRequest(_) => {
+                (StatusCode::BAD_REQUEST, self.to_string()).into_response()
+            }
+        }
+    }
+}
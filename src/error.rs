@@ -19,6 +19,30 @@ pub enum Error {
         reason: String,
     },
 
+    /// TwiML document could not be parsed
+    Parse {
+        /// Path of the element being parsed (e.g. `Response/Dial`)
+        path: String,
+        /// Reason for the failure
+        reason: String,
+    },
+
+    /// Element that has no counterpart in the TwiML model
+    UnknownElement {
+        /// Path of the parent element
+        path: String,
+        /// Name of the unknown element
+        element: String,
+    },
+
+    /// Attribute that has no counterpart in the TwiML model
+    UnknownAttribute {
+        /// Path of the element carrying the attribute
+        path: String,
+        /// Name of the unknown attribute
+        attribute: String,
+    },
+
     /// Generic error
     Other(String),
 }
@@ -30,6 +54,15 @@ impl fmt::Display for Error {
             Error::InvalidParameter { param, reason } => {
                 write!(f, "Invalid parameter '{}': {}", param, reason)
             }
+            Error::Parse { path, reason } => {
+                write!(f, "Failed to parse TwiML at '{}': {}", path, reason)
+            }
+            Error::UnknownElement { path, element } => {
+                write!(f, "Unknown element <{}> in '{}'", element, path)
+            }
+            Error::UnknownAttribute { path, attribute } => {
+                write!(f, "Unknown attribute '{}' on '{}'", attribute, path)
+            }
             Error::Other(msg) => write!(f, "{}", msg),
         }
     }
@@ -51,6 +84,30 @@ impl Error {
         }
     }
 
+    /// Create a new parse error
+    pub fn parse(path: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::Parse {
+            path: path.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Create a new unknown element error
+    pub fn unknown_element(path: impl Into<String>, element: impl Into<String>) -> Self {
+        Self::UnknownElement {
+            path: path.into(),
+            element: element.into(),
+        }
+    }
+
+    /// Create a new unknown attribute error
+    pub fn unknown_attribute(path: impl Into<String>, attribute: impl Into<String>) -> Self {
+        Self::UnknownAttribute {
+            path: path.into(),
+            attribute: attribute.into(),
+        }
+    }
+
     /// Create a new generic error
     pub fn other(message: impl Into<String>) -> Self {
         Self::Other(message.into())
@@ -19,20 +19,34 @@
 //! println!("{}", response.to_xml());
 //! ```
 
-use crate::xml_escape::{escape_xml_attr, escape_xml_text};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::render::{RenderOptions, SelfClosing};
+use crate::validation::{ModelChecker, TwiMLValidator, ValidateModel, ValidationError};
+use crate::xml_escape::escape_xml_attr;
+use crate::xml_parser::{child_path, parse_document, Element, Node};
 use crate::TwiML;
 
 /// Media type for fax storage
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReceiveMediaType {
     /// PDF format - "application/pdf"
+    #[cfg_attr(feature = "serde", serde(rename = "application/pdf"))]
     ApplicationPdf,
     /// TIFF format - "image/tiff"
+    #[cfg_attr(feature = "serde", serde(rename = "image/tiff"))]
     ImageTiff,
 }
 
 impl ReceiveMediaType {
-    fn as_str(&self) -> &str {
+    /// Every value, as written in TwiML
+    pub const VALUES: &'static [&'static str] = &["application/pdf", "image/tiff"];
+
+    /// The TwiML attribute value
+    pub fn as_str(&self) -> &str {
         match self {
             ReceiveMediaType::ApplicationPdf => "application/pdf",
             ReceiveMediaType::ImageTiff => "image/tiff",
@@ -40,8 +54,25 @@ impl ReceiveMediaType {
     }
 }
 
+impl FromStr for ReceiveMediaType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "application/pdf" => Ok(ReceiveMediaType::ApplicationPdf),
+            "image/tiff" => Ok(ReceiveMediaType::ImageTiff),
+            _ => Err(Error::invalid_parameter(
+                "mediaType",
+                format!("expected 'application/pdf' or 'image/tiff', got '{}'", s),
+            )),
+        }
+    }
+}
+
 /// Page size for received faxes
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum ReceivePageSize {
     /// Letter size (8.5" x 11")
     Letter,
@@ -52,7 +83,11 @@ pub enum ReceivePageSize {
 }
 
 impl ReceivePageSize {
-    fn as_str(&self) -> &str {
+    /// Every value, as written in TwiML
+    pub const VALUES: &'static [&'static str] = &["letter", "legal", "a4"];
+
+    /// The TwiML attribute value
+    pub fn as_str(&self) -> &str {
         match self {
             ReceivePageSize::Letter => "letter",
             ReceivePageSize::Legal => "legal",
@@ -61,18 +96,41 @@ impl ReceivePageSize {
     }
 }
 
+impl FromStr for ReceivePageSize {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "letter" => Ok(ReceivePageSize::Letter),
+            "legal" => Ok(ReceivePageSize::Legal),
+            "a4" => Ok(ReceivePageSize::A4),
+            _ => Err(Error::invalid_parameter(
+                "pageSize",
+                format!("expected 'letter', 'legal' or 'a4', got '{}'", s),
+            )),
+        }
+    }
+}
+
 /// Attributes to pass to receive
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct ReceiveAttributes {
     /// action - Receive action URL
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub action: Option<String>,
     /// mediaType - The media type used to store media in the fax media store
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub media_type: Option<ReceiveMediaType>,
     /// method - Receive action URL method
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
     /// pageSize - What size to interpret received pages as
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub page_size: Option<ReceivePageSize>,
     /// storeMedia - Whether or not to store received media in the fax media store
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub store_media: Option<bool>,
 }
 
@@ -115,7 +173,10 @@ impl ReceiveAttributes {
 
 /// `<Receive>` TwiML Verb
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Receive {
+    #[cfg_attr(feature = "serde", serde(default))]
     attributes: ReceiveAttributes,
 }
 
@@ -127,35 +188,64 @@ impl Receive {
         }
     }
 
-    fn to_xml(&self) -> String {
-        let mut attrs = Vec::new();
-
+    fn write_xml<W: fmt::Write + ?Sized>(
+        &self,
+        xml: &mut W,
+        options: &RenderOptions,
+    ) -> fmt::Result {
+        options.write_indent(xml, 1)?;
+        xml.write_str("<Receive")?;
         if let Some(ref action) = self.attributes.action {
-            attrs.push(format!(" action=\"{}\"", escape_xml_attr(action)));
+            write!(xml, " action=\"{}\"", escape_xml_attr(action))?;
         }
         if let Some(ref media_type) = self.attributes.media_type {
-            attrs.push(format!(" mediaType=\"{}\"", media_type.as_str()));
+            write!(xml, " mediaType=\"{}\"", media_type.as_str())?;
         }
         if let Some(ref method) = self.attributes.method {
-            attrs.push(format!(" method=\"{}\"", escape_xml_attr(method)));
+            write!(xml, " method=\"{}\"", escape_xml_attr(method))?;
         }
         if let Some(ref page_size) = self.attributes.page_size {
-            attrs.push(format!(" pageSize=\"{}\"", page_size.as_str()));
+            write!(xml, " pageSize=\"{}\"", page_size.as_str())?;
         }
         if let Some(store_media) = self.attributes.store_media {
-            attrs.push(format!(" storeMedia=\"{}\"", store_media));
+            write!(xml, " storeMedia=\"{}\"", store_media)?;
         }
+        options.write_self_close_as(xml, SelfClosing::Compact)?;
+        options.write_newline(xml)
+    }
+
+    fn parse(element: &Element, path: &str) -> Result<Self> {
+        let mut attrs = element.attributes(path);
+        let attributes = ReceiveAttributes {
+            action: attrs.string("action"),
+            media_type: attrs.string("mediaType").map(|v| v.parse()).transpose()?,
+            method: attrs.string("method"),
+            page_size: attrs.string("pageSize").map(|v| v.parse()).transpose()?,
+            store_media: attrs.bool("storeMedia")?,
+        };
+        attrs.finish()?;
+        element.expect_empty_content(path)?;
+        Ok(Receive { attributes })
+    }
 
-        format!("<Receive{}/>", attrs.join(""))
+    /// The attributes configured on this verb
+    pub fn attributes(&self) -> &ReceiveAttributes {
+        &self.attributes
     }
 }
 
 /// `<Response>` TwiML for Faxes
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct FaxResponse {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     receive: Option<Receive>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     comments_before: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     comments: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     comments_after: Vec<String>,
 }
 
@@ -216,33 +306,131 @@ impl FaxResponse {
     }
 }
 
+impl FaxResponse {
+    /// Parse a TwiML fax document into a `FaxResponse`
+    ///
+    /// `mediaType` and `pageSize` are mapped back into [`ReceiveMediaType`]
+    /// and [`ReceivePageSize`]; unknown values are reported as
+    /// [`Error::InvalidParameter`] naming the attribute.
+    ///
+    /// # Example
+    /// ```
+    /// use twiml_rust::fax::{FaxResponse, ReceivePageSize};
+    ///
+    /// let xml = r#"<Response><Receive action="/fax" pageSize="a4"/></Response>"#;
+    /// let response = FaxResponse::from_xml(xml).unwrap();
+    ///
+    /// let receive = response.receive_verb().unwrap();
+    /// assert_eq!(receive.attributes().page_size, Some(ReceivePageSize::A4));
+    /// ```
+    pub fn from_xml(xml: &str) -> Result<Self> {
+        let document = parse_document(xml)?;
+        let root = &document.root;
+        if root.name != "Response" {
+            return Err(Error::unknown_element("", root.name.clone()));
+        }
+        let path = "Response";
+        root.attributes(path).finish()?;
+
+        let mut response = FaxResponse {
+            comments_before: document.comments_before,
+            comments_after: document.comments_after,
+            ..Default::default()
+        };
+
+        for child in &root.children {
+            match child {
+                Node::Element(element) if element.name == "Receive" => {
+                    if response.receive.is_some() {
+                        return Err(Error::parse(path, "multiple <Receive> elements"));
+                    }
+                    let receive_path = child_path(path, "Receive");
+                    response.receive = Some(Receive::parse(element, &receive_path)?);
+                }
+                Node::Element(element) => {
+                    return Err(Error::unknown_element(path, element.name.clone()))
+                }
+                Node::Comment(comment) => response.comments.push(comment.clone()),
+                Node::Text(text) if text.trim().is_empty() => {}
+                Node::Text(text) => {
+                    return Err(Error::parse(
+                        path,
+                        format!("unexpected text content '{}'", text.trim()),
+                    ))
+                }
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// The `<Receive>` verb, if one is configured
+    pub fn receive_verb(&self) -> Option<&Receive> {
+        self.receive.as_ref()
+    }
+}
+
 impl TwiML for FaxResponse {
-    fn to_xml(&self) -> String {
-        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    /// Everything after the XML declaration on one line, with `<Receive/>`
+    fn default_render_options(&self) -> RenderOptions {
+        RenderOptions::pretty()
+            .inline_elements(true)
+            .self_closing_per_element()
+    }
+
+    fn write_xml_with<W: fmt::Write + ?Sized>(
+        &self,
+        xml: &mut W,
+        options: &RenderOptions,
+    ) -> fmt::Result {
+        options.write_declaration(xml)?;
 
         // Add comments before Response
         for comment in &self.comments_before {
-            xml.push_str(&format!("<!-- {} -->\n", escape_xml_text(comment)));
+            options.write_leading_comment(xml, comment)?;
         }
 
-        xml.push_str("<Response>");
+        xml.write_str("<Response>")?;
 
         // Add comments inside Response
         for comment in &self.comments {
-            xml.push_str(&format!("\n  <!-- {} -->", escape_xml_text(comment)));
+            options.write_comment_line(xml, 1, comment)?;
         }
+        options.write_newline(xml)?;
 
         if let Some(ref receive) = self.receive {
-            xml.push_str(&receive.to_xml());
+            receive.write_xml(xml, options)?;
         }
 
-        xml.push_str("</Response>");
+        xml.write_str("</Response>")?;
 
         // Add comments after Response
         for comment in &self.comments_after {
-            xml.push_str(&format!("\n<!-- {} -->", escape_xml_text(comment)));
+            options.write_comment_line(xml, 0, comment)?;
         }
 
-        xml
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<Vec<ValidationError>> {
+        Ok(self.validate_model())
+    }
+
+    fn validate_strict(&self) -> Result<Vec<ValidationError>> {
+        Ok(self.validate_model_strict())
+    }
+}
+
+impl ValidateModel for FaxResponse {
+    fn validate_model_with(&self, validator: &TwiMLValidator) -> Vec<ValidationError> {
+        let mut checker = ModelChecker::new(validator);
+        if let Some(receive) = &self.receive {
+            let a = &receive.attributes;
+            checker
+                .element("receive.attributes", "Receive")
+                .string("action", "action", a.action.as_deref())
+                .string("method", "method", a.method.as_deref());
+        }
+        checker.finish()
     }
 }
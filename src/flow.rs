@@ -0,0 +1,600 @@
+//! Declarative call flows loaded from YAML or JSON.
+//!
+//! A flow document is a list of steps, each compiling to one top-level verb.
+//! Strings may contain `${name}` placeholders that are filled in from a
+//! context map when the flow is compiled, so the wording of a menu can live
+//! outside the code:
+//!
+//! ```yaml
+//! steps:
+//!   - say: "Hello ${caller_name}, thanks for calling."
+//!   - gather:
+//!       input: [dtmf]
+//!       num_digits: 1
+//!       action: /menu
+//!       prompts:
+//!         - say:
+//!             text: "For sales, press 1. For support, press 2."
+//!             voice: Polly.Joanna-Neural
+//!   - dial:
+//!       number: "${agent_number}"
+//!       timeout: 20
+//!   - hangup
+//! ```
+//!
+//! | Step | Fields |
+//! |------|--------|
+//! | `say` | `text`, `voice`, `language`, `loop` (or just the text) |
+//! | `play` | `url`, `loop`, `digits` (or just the URL) |
+//! | `pause` | `length` (or nothing) |
+//! | `gather` | `input`, `num_digits`, `timeout`, `finish_on_key`, `action`, `method`, `language`, `hints`, `speech_timeout`, `speech_model`, `prompts` (`say`/`play`/`pause` steps) |
+//! | `dial` | `number`, `client`, `caller_id`, `timeout`, `time_limit`, `action`, `method`, `record`, `answer_on_bridge` |
+//! | `record` | `action`, `method`, `max_length`, `timeout`, `finish_on_key`, `play_beep`, `trim`, `transcribe`, `transcribe_callback`, `recording_status_callback` |
+//! | `redirect` | `url`, `method` (or just the URL) |
+//! | `hangup` | none |
+//!
+//! Enumerated values (`voice`, `input`, `record`, ...) use their TwiML
+//! spelling and are checked strictly, so a typo is reported instead of being
+//! passed to Twilio. [`Flow::compile`] validates the resulting response and
+//! fails if the validator finds any errors; error paths like
+//! `verbs[1].Gather.attributes.timeout` index the steps.
+//!
+//! # Example
+//! ```
+//! use std::collections::HashMap;
+//! use twiml_rust::flow::Flow;
+//! use twiml_rust::TwiML;
+//!
+//! let flow = Flow::from_yaml(
+//!     r#"
+//! steps:
+//!   - say: "Hello ${name}"
+//!   - hangup
+//! "#,
+//! )
+//! .unwrap();
+//!
+//! let context = HashMap::from([("name".to_string(), "Ada".to_string())]);
+//! let response = flow.compile(&context).unwrap();
+//! assert!(response.to_xml().contains("<Say>Hello Ada</Say>"));
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use serde::de::{self, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+
+use crate::error::{Error, Result};
+use crate::validation::ValidateModel;
+use crate::voice::{
+    Dial, DialAttributes, DialClient, DialNumber, DialRecord, Gather, GatherInput, Pause,
+    PauseAttributes, Play, Record, Redirect, RedirectAttributes, Say, SpeechModel, Trim, Voice,
+    VoiceResponse,
+};
+
+/// A call flow: the steps of one voice response
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Flow {
+    /// The steps, each compiling to one top-level verb
+    pub steps: Vec<Step>,
+}
+
+/// One step of a [`Flow`]
+#[derive(Debug, Clone)]
+pub enum Step {
+    /// `<Say>` some text
+    Say(SayStep),
+    /// `<Play>` an audio file or DTMF tones
+    Play(PlayStep),
+    /// `<Pause>`; a bare `pause` uses Twilio's default length
+    Pause(PauseStep),
+    /// `<Gather>` digits or speech, with prompts played meanwhile
+    Gather(GatherStep),
+    /// `<Dial>` a number or a client
+    Dial(DialStep),
+    /// `<Record>` the caller
+    Record(RecordStep),
+    /// `<Redirect>` to another TwiML document
+    Redirect(RedirectStep),
+    /// `<Hangup>`
+    Hangup,
+}
+
+/// The map form of [`Step`]
+#[derive(Deserialize)]
+#[serde(remote = "Step", rename_all = "snake_case")]
+enum StepDef {
+    Say(#[serde(deserialize_with = "string_or_map")] SayStep),
+    Play(#[serde(deserialize_with = "string_or_map")] PlayStep),
+    Pause(PauseStep),
+    Gather(GatherStep),
+    Dial(DialStep),
+    Record(RecordStep),
+    Redirect(#[serde(deserialize_with = "string_or_map")] RedirectStep),
+    Hangup,
+}
+
+impl<'de> Deserialize<'de> for Step {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct StepVisitor;
+
+        impl<'de> Visitor<'de> for StepVisitor {
+            type Value = Step;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a step")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> std::result::Result<Step, E> {
+                match value {
+                    "pause" => Ok(Step::Pause(PauseStep::default())),
+                    _ => StepDef::deserialize(de::value::StrDeserializer::new(value)),
+                }
+            }
+
+            fn visit_map<M: MapAccess<'de>>(self, map: M) -> std::result::Result<Step, M::Error> {
+                StepDef::deserialize(de::value::MapAccessDeserializer::new(map))
+            }
+        }
+
+        deserializer.deserialize_any(StepVisitor)
+    }
+}
+
+/// A `say` step
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SayStep {
+    /// The text to speak
+    pub text: String,
+    /// The voice, such as `Polly.Joanna-Neural`
+    pub voice: Option<String>,
+    /// The language, such as `en-US`
+    pub language: Option<String>,
+    /// How many times to repeat the text, written `loop`
+    #[serde(rename = "loop")]
+    pub loop_count: Option<u32>,
+}
+
+impl From<String> for SayStep {
+    fn from(text: String) -> Self {
+        SayStep {
+            text,
+            voice: None,
+            language: None,
+            loop_count: None,
+        }
+    }
+}
+
+/// A `play` step
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PlayStep {
+    /// The URL of the audio file
+    pub url: Option<String>,
+    /// How many times to play it, written `loop`
+    #[serde(rename = "loop")]
+    pub loop_count: Option<u32>,
+    /// DTMF tones to play instead of, or before, the file
+    pub digits: Option<String>,
+}
+
+impl From<String> for PlayStep {
+    fn from(url: String) -> Self {
+        PlayStep {
+            url: Some(url),
+            ..PlayStep::default()
+        }
+    }
+}
+
+/// A `pause` step
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PauseStep {
+    /// Seconds to wait; Twilio waits one second without it
+    pub length: Option<u32>,
+}
+
+/// A `gather` step
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GatherStep {
+    /// `dtmf` and/or `speech`
+    pub input: Option<Vec<String>>,
+    /// Stop after this many digits
+    pub num_digits: Option<u32>,
+    /// Seconds to wait for input
+    pub timeout: Option<u32>,
+    /// The key that ends the input, such as `#`
+    pub finish_on_key: Option<String>,
+    /// URL that receives the gathered input
+    pub action: Option<String>,
+    /// HTTP method for `action`
+    pub method: Option<String>,
+    /// Language of the expected speech
+    pub language: Option<String>,
+    /// Comma-separated words or phrases to expect
+    pub hints: Option<String>,
+    /// Seconds of silence that end speech input, or `auto`
+    pub speech_timeout: Option<String>,
+    /// Speech recognition model, such as `phone_call`
+    pub speech_model: Option<String>,
+    /// `say`, `play` and `pause` steps played while gathering
+    pub prompts: Vec<Step>,
+}
+
+/// A `dial` step
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DialStep {
+    /// Phone number to dial
+    pub number: Option<String>,
+    /// Client identity to dial
+    pub client: Option<String>,
+    /// Caller ID shown to the dialed party
+    pub caller_id: Option<String>,
+    /// Seconds to wait for an answer
+    pub timeout: Option<u32>,
+    /// Maximum call length in seconds
+    pub time_limit: Option<u32>,
+    /// URL requested when the dialed call ends
+    pub action: Option<String>,
+    /// HTTP method for `action`
+    pub method: Option<String>,
+    /// Recording mode, such as `record-from-answer`
+    pub record: Option<String>,
+    /// Keep ringing the caller until the dialed party answers
+    pub answer_on_bridge: Option<bool>,
+}
+
+/// A `record` step
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RecordStep {
+    /// URL requested when the recording ends
+    pub action: Option<String>,
+    /// HTTP method for `action`
+    pub method: Option<String>,
+    /// Maximum recording length in seconds
+    pub max_length: Option<u32>,
+    /// Seconds of silence that end the recording
+    pub timeout: Option<u32>,
+    /// Keys that end the recording
+    pub finish_on_key: Option<String>,
+    /// Play a beep before recording
+    pub play_beep: Option<bool>,
+    /// `trim-silence` or `do-not-trim`
+    pub trim: Option<String>,
+    /// Transcribe the recording
+    pub transcribe: Option<bool>,
+    /// URL that receives the transcription
+    pub transcribe_callback: Option<String>,
+    /// URL notified when the recording is available
+    pub recording_status_callback: Option<String>,
+}
+
+/// A `redirect` step
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RedirectStep {
+    /// URL of the next TwiML document
+    pub url: String,
+    /// HTTP method for `url`
+    pub method: Option<String>,
+}
+
+impl From<String> for RedirectStep {
+    fn from(url: String) -> Self {
+        RedirectStep { url, method: None }
+    }
+}
+
+/// Deserialize a step from its map form or from the bare string shorthand
+fn string_or_map<'de, D, T>(deserializer: D) -> std::result::Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + From<String>,
+{
+    struct StringOrMap<T>(PhantomData<T>);
+
+    impl<'de, T> Visitor<'de> for StringOrMap<T>
+    where
+        T: Deserialize<'de> + From<String>,
+    {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a string or a map")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> std::result::Result<T, E> {
+            Ok(T::from(value.to_string()))
+        }
+
+        fn visit_map<M: MapAccess<'de>>(self, map: M) -> std::result::Result<T, M::Error> {
+            T::deserialize(de::value::MapAccessDeserializer::new(map))
+        }
+    }
+
+    deserializer.deserialize_any(StringOrMap(PhantomData))
+}
+
+impl Flow {
+    /// Load a flow from a YAML document
+    pub fn from_yaml(source: &str) -> Result<Self> {
+        // Steps are written as `- say: ...` maps rather than YAML `!say` tags
+        serde_norway::with::singleton_map_recursive::deserialize(
+            serde_norway::Deserializer::from_str(source),
+        )
+        .map_err(|e| Error::parse("flow", e.to_string()))
+    }
+
+    /// Load a flow from a JSON document
+    pub fn from_json(source: &str) -> Result<Self> {
+        serde_json::from_str(source).map_err(|e| Error::parse("flow", e.to_string()))
+    }
+
+    /// Build the voice response, filling `${name}` placeholders from `context`
+    ///
+    /// Fails with [`Error::InvalidParameter`] for a placeholder missing from
+    /// `context` or a misspelled value, and with [`Error::Validation`] if the
+    /// response does not pass validation.
+    pub fn compile(&self, context: &HashMap<String, String>) -> Result<VoiceResponse> {
+        let compiler = Compiler { context };
+        let mut response = VoiceResponse::new();
+        for (i, step) in self.steps.iter().enumerate() {
+            response = compiler.step(response, &format!("steps[{}]", i), step)?;
+        }
+
+        let errors = response.validate_model();
+        if !errors.is_empty() {
+            let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+            return Err(Error::validation(messages.join("; ")));
+        }
+        Ok(response)
+    }
+}
+
+struct Compiler<'a> {
+    context: &'a HashMap<String, String>,
+}
+
+impl Compiler<'_> {
+    fn step(&self, response: VoiceResponse, path: &str, step: &Step) -> Result<VoiceResponse> {
+        Ok(match step {
+            Step::Say(say) => response.say_with(self.say(&format!("{}.say", path), say)?),
+            Step::Play(play) => response.play_with(self.play(&format!("{}.play", path), play)?),
+            Step::Pause(pause) => response.pause(pause.length),
+            Step::Gather(gather) => {
+                response.gather(self.gather(&format!("{}.gather", path), gather)?)
+            }
+            Step::Dial(dial) => response.dial_with(self.dial(&format!("{}.dial", path), dial)?),
+            Step::Record(record) => {
+                response.record_with(self.record(&format!("{}.record", path), record)?)
+            }
+            Step::Redirect(redirect) => {
+                let path = format!("{}.redirect", path);
+                let attributes = RedirectAttributes {
+                    method: self.opt(&path, "method", &redirect.method)?,
+                };
+                let url = self.text(&format!("{}.url", path), &redirect.url)?;
+                response.redirect_with(Redirect::new(attributes, url))
+            }
+            Step::Hangup => response.hangup(),
+        })
+    }
+
+    fn say(&self, path: &str, step: &SayStep) -> Result<Say> {
+        let mut say = Say::new(self.text(&format!("{}.text", path), &step.text)?);
+        if let Some(voice) = self.parsed::<Voice>(path, "voice", &step.voice)? {
+            say = say.voice(voice);
+        }
+        if let Some(language) = self.opt(path, "language", &step.language)? {
+            say = say.language(language);
+        }
+        if let Some(loop_count) = step.loop_count {
+            say = say.loop_count(loop_count);
+        }
+        Ok(say)
+    }
+
+    fn play(&self, path: &str, step: &PlayStep) -> Result<Play> {
+        let url = self.opt(path, "url", &step.url)?;
+        let digits = self.opt(path, "digits", &step.digits)?;
+        let mut play = match (url, digits) {
+            (Some(url), digits) => {
+                let play = Play::with_url(url);
+                match digits {
+                    Some(digits) => play.digits(digits),
+                    None => play,
+                }
+            }
+            (None, Some(digits)) => Play::with_digits(digits),
+            (None, None) => {
+                return Err(Error::invalid_parameter(
+                    path,
+                    "a play step needs a url or digits",
+                ))
+            }
+        };
+        if let Some(loop_count) = step.loop_count {
+            play = play.loop_count(loop_count);
+        }
+        Ok(play)
+    }
+
+    fn gather(&self, path: &str, step: &GatherStep) -> Result<Gather> {
+        let mut gather = Gather::new();
+        if let Some(input) = &step.input {
+            let input = input
+                .iter()
+                .map(|value| self.parse::<GatherInput>(&format!("{}.input", path), value))
+                .collect::<Result<Vec<_>>>()?;
+            gather = gather.input(input);
+        }
+        if let Some(num_digits) = step.num_digits {
+            gather = gather.num_digits(num_digits);
+        }
+        if let Some(timeout) = step.timeout {
+            gather = gather.timeout(timeout);
+        }
+        if let Some(key) = self.opt(path, "finish_on_key", &step.finish_on_key)? {
+            gather = gather.finish_on_key(key);
+        }
+        if let Some(action) = self.opt(path, "action", &step.action)? {
+            gather = gather.action(action);
+        }
+        if let Some(method) = self.opt(path, "method", &step.method)? {
+            gather = gather.method(method);
+        }
+        if let Some(language) = self.opt(path, "language", &step.language)? {
+            gather = gather.language(language);
+        }
+        if let Some(hints) = self.opt(path, "hints", &step.hints)? {
+            gather = gather.hints(hints);
+        }
+        if let Some(timeout) = self.opt(path, "speech_timeout", &step.speech_timeout)? {
+            gather = gather.speech_timeout(timeout);
+        }
+        if let Some(model) = self.parsed::<SpeechModel>(path, "speech_model", &step.speech_model)? {
+            gather = gather.speech_model(model);
+        }
+        for (i, prompt) in step.prompts.iter().enumerate() {
+            let path = format!("{}.prompts[{}]", path, i);
+            gather = match prompt {
+                Step::Say(say) => gather.add_say(self.say(&format!("{}.say", path), say)?),
+                Step::Play(play) => gather.add_play(self.play(&format!("{}.play", path), play)?),
+                Step::Pause(pause) => gather.add_pause(Pause::new(Some(PauseAttributes {
+                    length: pause.length,
+                }))),
+                _ => {
+                    return Err(Error::invalid_parameter(
+                        path,
+                        "only say, play and pause steps can prompt inside a gather",
+                    ))
+                }
+            };
+        }
+        Ok(gather)
+    }
+
+    fn dial(&self, path: &str, step: &DialStep) -> Result<Dial> {
+        let attributes = DialAttributes {
+            action: self.opt(path, "action", &step.action)?,
+            method: self.opt(path, "method", &step.method)?,
+            timeout: step.timeout,
+            time_limit: step.time_limit,
+            caller_id: self.opt(path, "caller_id", &step.caller_id)?,
+            record: self.parsed::<DialRecord>(path, "record", &step.record)?,
+            answer_on_bridge: step.answer_on_bridge,
+            ..DialAttributes::default()
+        };
+        let mut dial = Dial::with_attributes(attributes);
+        if let Some(number) = self.opt(path, "number", &step.number)? {
+            dial = dial.add_number(DialNumber::new(number));
+        }
+        if let Some(client) = self.opt(path, "client", &step.client)? {
+            dial = dial.add_client(DialClient::new(client));
+        }
+        if step.number.is_none() && step.client.is_none() {
+            return Err(Error::invalid_parameter(
+                path,
+                "a dial step needs a number or a client",
+            ));
+        }
+        Ok(dial)
+    }
+
+    fn record(&self, path: &str, step: &RecordStep) -> Result<Record> {
+        let mut record = Record::new();
+        if let Some(action) = self.opt(path, "action", &step.action)? {
+            record = record.action(action);
+        }
+        if let Some(method) = self.opt(path, "method", &step.method)? {
+            record = record.method(method);
+        }
+        if let Some(max_length) = step.max_length {
+            record = record.max_length(max_length);
+        }
+        if let Some(timeout) = step.timeout {
+            record = record.timeout(timeout);
+        }
+        if let Some(key) = self.opt(path, "finish_on_key", &step.finish_on_key)? {
+            record = record.finish_on_key(key);
+        }
+        if let Some(play_beep) = step.play_beep {
+            record = record.play_beep(play_beep);
+        }
+        if let Some(trim) = self.parsed::<Trim>(path, "trim", &step.trim)? {
+            record = record.trim(trim);
+        }
+        if let Some(transcribe) = step.transcribe {
+            record = record.transcribe(transcribe);
+        }
+        if let Some(callback) = self.opt(path, "transcribe_callback", &step.transcribe_callback)? {
+            record = record.transcribe_callback(callback);
+        }
+        if let Some(callback) = self.opt(
+            path,
+            "recording_status_callback",
+            &step.recording_status_callback,
+        )? {
+            record = record.recording_status_callback(callback);
+        }
+        Ok(record)
+    }
+
+    /// Substitute the placeholders in an optional field
+    fn opt(&self, path: &str, field: &str, value: &Option<String>) -> Result<Option<String>> {
+        value
+            .as_deref()
+            .map(|v| self.text(&format!("{}.{}", path, field), v))
+            .transpose()
+    }
+
+    /// Substitute the placeholders in an optional field and parse the result
+    fn parsed<T>(&self, path: &str, field: &str, value: &Option<String>) -> Result<Option<T>>
+    where
+        T: FromStr<Err = Error>,
+    {
+        let path = format!("{}.{}", path, field);
+        value.as_deref().map(|v| self.parse(&path, v)).transpose()
+    }
+
+    fn parse<T>(&self, path: &str, value: &str) -> Result<T>
+    where
+        T: FromStr<Err = Error>,
+    {
+        self.text(path, value)?.parse().map_err(|e| match e {
+            Error::InvalidParameter { reason, .. } => Error::invalid_parameter(path, reason),
+            other => other,
+        })
+    }
+
+    /// Replace each `${name}` in `value` with its entry in the context
+    fn text(&self, path: &str, value: &str) -> Result<String> {
+        let mut out = String::with_capacity(value.len());
+        let mut rest = value;
+        while let Some(start) = rest.find("${") {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 2..];
+            let end = after.find('}').ok_or_else(|| {
+                Error::invalid_parameter(path, format!("unterminated placeholder in '{}'", value))
+            })?;
+            let name = after[..end].trim();
+            let replacement = self.context.get(name).ok_or_else(|| {
+                Error::invalid_parameter(path, format!("no value for placeholder '${{{}}}'", name))
+            })?;
+            out.push_str(replacement);
+            rest = &after[end + 1..];
+        }
+        out.push_str(rest);
+        Ok(out)
+    }
+}
@@ -0,0 +1,160 @@
+//! Integration through the [`http`](https://docs.rs/http) crate (`http` feature).
+//!
+//! hyper, warp, lambda_http and most other servers speak `http`'s request
+//! and response types, so this module is all the glue they need:
+//! [`VoiceResponse`], [`MessagingResponse`] and [`FaxResponse`] convert into
+//! an `http::Response<String>` served as [`CONTENT_TYPE`], and
+//! [`verify_form`] checks the `X-Twilio-Signature` of an incoming request
+//! before parsing its form body.
+//!
+//! # Example
+//! ```
+//! use bytes::Bytes;
+//! use http::{Request, Response};
+//! use twiml_rust::http::{verify_form, VerifyError};
+//! use twiml_rust::signature::RequestValidator;
+//! use twiml_rust::webhook::VoiceRequest;
+//! use twiml_rust::VoiceResponse;
+//!
+//! fn handle(validator: &RequestValidator, request: Request<Bytes>) -> Response<String> {
+//!     match verify_form::<VoiceRequest, _>(validator, &request) {
+//!         Ok(call) => VoiceResponse::new()
+//!             .say(format!("Hello {}", call.from))
+//!             .into(),
+//!         Err(err) => {
+//!             let mut response = Response::new(err.to_string());
+//!             *response.status_mut() = err.status();
+//!             response
+//!         }
+//!     }
+//! }
+//!
+//! let request = Request::post("/voice")
+//!     .header("Host", "example.com")
+//!     .body(Bytes::from_static(b"CallSid=CA123"))
+//!     .unwrap();
+//! let response = handle(&RequestValidator::new("your_auth_token"), request);
+//! assert_eq!(response.status(), 403);
+//! ```
+
+use std::fmt;
+
+use ::http::header::{self, HeaderValue};
+use ::http::{Request, Response, StatusCode};
+
+use crate::error::Error;
+use crate::fax::FaxResponse;
+use crate::messaging::MessagingResponse;
+use crate::render::CONTENT_TYPE;
+use crate::signature::{self, RequestValidator};
+use crate::voice::VoiceResponse;
+use crate::webhook::{FormParams, WebhookRequest};
+use crate::TwiML;
+
+macro_rules! into_response {
+    ($($response:ty),+) => {
+        $(
+            impl From<$response> for Response<String> {
+                fn from(response: $response) -> Self {
+                    let mut http = Response::new(response.to_xml());
+                    http.headers_mut()
+                        .insert(header::CONTENT_TYPE, HeaderValue::from_static(CONTENT_TYPE));
+                    http
+                }
+            }
+        )+
+    };
+}
+
+into_response!(VoiceResponse, MessagingResponse, FaxResponse);
+
+/// Verify the `X-Twilio-Signature` of a form-encoded webhook request and
+/// parse its body as `T`
+///
+/// `T` is one of the [`webhook`](crate::webhook) request types, or
+/// [`FormParams`] for the raw parameters. The body can be anything that
+/// reads as bytes, such as `Bytes`, `Vec<u8>` or `String`. The signed URL is
+/// rebuilt by [`request_url`].
+pub fn verify_form<T, B>(
+    validator: &RequestValidator,
+    request: &Request<B>,
+) -> Result<T, VerifyError>
+where
+    T: WebhookRequest,
+    B: AsRef<[u8]>,
+{
+    let signature = request
+        .headers()
+        .get("X-Twilio-Signature")
+        .and_then(|value| value.to_str().ok())
+        .ok_or(VerifyError::MissingSignature)?;
+    let body = std::str::from_utf8(request.body().as_ref())
+        .map_err(|_| VerifyError::InvalidRequest(Error::parse("form", "body is not UTF-8")))?;
+    let params = FormParams::parse(body).map_err(VerifyError::InvalidRequest)?;
+
+    let url = request_url(request, validator.trusts_forwarded_headers());
+    if !validator.validate(&url, &params, signature) {
+        return Err(VerifyError::InvalidSignature);
+    }
+    T::from_params(params).map_err(VerifyError::InvalidRequest)
+}
+
+/// The URL Twilio requested, rebuilt from `request`
+///
+/// Twilio signs the public URL. The scheme and host are the request URI's
+/// scheme (else `https`) and the `Host` header (else the URI's authority).
+/// With `trust_forwarded_headers`, the `X-Forwarded-Proto` and
+/// `X-Forwarded-Host` headers a proxy sets take precedence; pass
+/// [`RequestValidator::trusts_forwarded_headers`] to match [`verify_form`].
+/// Pass the result to [`RequestValidator::validate_body`] for JSON requests.
+pub fn request_url<B>(request: &Request<B>, trust_forwarded_headers: bool) -> String {
+    let uri = request.uri();
+    signature::request_url(
+        |name| request.headers().get(name)?.to_str().ok(),
+        trust_forwarded_headers,
+        uri.scheme_str(),
+        uri.authority().map(|authority| authority.as_str()),
+        uri.path_and_query().map_or("/", |path| path.as_str()),
+    )
+}
+
+/// Why [`verify_form`] rejected a request
+#[derive(Debug)]
+pub enum VerifyError {
+    /// The request has no `X-Twilio-Signature` header
+    MissingSignature,
+    /// The signature does not match the request
+    InvalidSignature,
+    /// The body is not a well-formed webhook request of the expected type
+    InvalidRequest(Error),
+}
+
+impl VerifyError {
+    /// The status to answer with: `403 Forbidden` for signature failures,
+    /// `400 Bad Request` for malformed requests
+    pub fn status(&self) -> StatusCode {
+        match self {
+            VerifyError::MissingSignature | VerifyError::InvalidSignature => StatusCode::FORBIDDEN,
+            VerifyError::InvalidRequest(_) => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::MissingSignature => write!(f, "Missing X-Twilio-Signature"),
+            VerifyError::InvalidSignature => write!(f, "Invalid X-Twilio-Signature"),
+            VerifyError::InvalidRequest(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for VerifyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            VerifyError::InvalidRequest(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -12,6 +12,19 @@
 //! - **Fax Responses**: Generate TwiML for fax operations
 //! - **Validation**: Built-in validation to ensure TwiML conforms to Twilio's requirements
 //! - **Type Safety**: Strongly typed API with builder patterns
+//! - **Webhook Requests**: Parse the form-encoded requests Twilio sends into typed structs
+//!   and verify their `X-Twilio-Signature`
+//! - **Call Simulator**: run a voice call flow against your handlers offline, with a
+//!   scripted caller, and inspect what was heard and requested
+//! - **`twiml!` macro**: declarative response trees whose nesting is checked at compile time
+//! - **Serde** (`serde` feature): store response models as JSON; the shape is
+//!   documented in the README under "JSON Call Flows"
+//! - **http** (`http` feature): convert responses into `http::Response` and
+//!   verify signed `http::Request`s, for hyper, warp, lambda_http and others
+//! - **axum** (`axum` feature): return responses from handlers and extract
+//!   signed webhook requests with `axum::TwilioForm`
+//! - **actix-web** (`actix` feature): the same for actix-web, plus the
+//!   `actix::verify_signature` middleware
 //!
 //! ## Example
 //!
@@ -26,30 +39,114 @@
 //! println!("{}", xml);
 //! ```
 
+#[cfg(feature = "actix")]
+pub mod actix;
+#[cfg(feature = "axum")]
+pub mod axum;
+mod crypto;
 pub mod error;
 pub mod fax;
+#[cfg(feature = "flow")]
+pub mod flow;
+#[cfg(feature = "http")]
+pub mod http;
+pub mod macros;
 pub mod messaging;
+pub mod render;
+pub mod schema;
+mod serde_support;
+pub mod signature;
+pub mod simulator;
 pub mod validation;
 pub mod validation_warnings;
 pub mod voice;
+pub mod voice_catalog;
+pub mod webhook;
 pub mod xml_escape;
+mod xml_parser;
+mod xml_tokenizer;
 
 pub use error::{Error, Result};
 pub use fax::{FaxResponse, Receive, ReceiveAttributes, ReceiveMediaType, ReceivePageSize};
 pub use messaging::{
     Body, Media, Message, MessageAttributes, MessagingResponse, Redirect, RedirectAttributes,
 };
+pub use render::{Newline, RenderOptions, SelfClosing};
 pub use validation::{
-    validate_twiml, validate_twiml_strict, TwiMLValidator, ValidationError, ValidationErrorType,
+    validate_twiml, validate_twiml_strict, TwiMLValidator, ValidateModel, ValidationError,
+    ValidationErrorType,
 };
 pub use voice::VoiceResponse;
 
+use std::{fmt, io};
+
 /// Common trait for TwiML responses
 pub trait TwiML {
+    /// Write the TwiML XML into `out` with the given layout
+    fn write_xml_with<W: fmt::Write + ?Sized>(
+        &self,
+        out: &mut W,
+        options: &RenderOptions,
+    ) -> fmt::Result;
+
+    /// The layout [`to_xml`](TwiML::to_xml), [`write_xml`](TwiML::write_xml)
+    /// and [`write_xml_io`](TwiML::write_xml_io) use
+    ///
+    /// Defaults to [`RenderOptions::default`]. The response types in this
+    /// crate return the layout they have always rendered with, so their
+    /// `to_xml()` output does not change.
+    fn default_render_options(&self) -> RenderOptions {
+        RenderOptions::default()
+    }
+
+    /// Write the TwiML XML into `out`, such as a reused `String` buffer
+    fn write_xml<W: fmt::Write + ?Sized>(&self, out: &mut W) -> fmt::Result {
+        self.write_xml_with(out, &self.default_render_options())
+    }
+
+    /// Write the TwiML XML into an `io::Write` sink with the given layout
+    ///
+    /// The XML is written piece by piece; wrap unbuffered sinks in a
+    /// [`std::io::BufWriter`].
+    fn write_xml_io_with<W: io::Write + ?Sized>(
+        &self,
+        out: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        let mut adapter = IoAdapter {
+            inner: out,
+            error: None,
+        };
+        self.write_xml_with(&mut adapter, options).map_err(|_| {
+            adapter
+                .error
+                .unwrap_or_else(|| io::Error::new(io::ErrorKind::Other, "formatter error"))
+        })
+    }
+
+    /// Write the TwiML XML into an `io::Write` sink such as a socket or file
+    fn write_xml_io<W: io::Write + ?Sized>(&self, out: &mut W) -> io::Result<()> {
+        self.write_xml_io_with(out, &self.default_render_options())
+    }
+
+    /// Convert the TwiML to an XML string with the given layout
+    fn to_xml_with(&self, options: &RenderOptions) -> String {
+        let mut xml = String::new();
+        self.write_xml_with(&mut xml, options)
+            .expect("writing to a String does not fail");
+        xml
+    }
+
     /// Convert the TwiML to an XML string
-    fn to_xml(&self) -> String;
+    fn to_xml(&self) -> String {
+        self.to_xml_with(&self.default_render_options())
+    }
 
     /// Validate the TwiML
+    ///
+    /// The default renders the XML and runs [`validate_twiml`] on it. The
+    /// response types in this crate override it to walk their typed model
+    /// instead (see [`ValidateModel`]).
     fn validate(&self) -> Result<Vec<ValidationError>> {
         validate_twiml(&self.to_xml())
     }
@@ -59,3 +156,18 @@ pub trait TwiML {
         validate_twiml_strict(&self.to_xml())
     }
 }
+
+/// Forwards `fmt::Write` to an `io::Write`, keeping the first I/O error
+struct IoAdapter<'a, W: io::Write + ?Sized> {
+    inner: &'a mut W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write + ?Sized> fmt::Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
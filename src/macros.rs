@@ -0,0 +1,433 @@
+//! The [`twiml!`](crate::twiml) macro for building responses declaratively.
+//!
+//! `twiml!` expands to the same builder calls you would write by hand, so a
+//! response reads like the document it produces:
+//!
+//! ```
+//! use twiml_rust::voice::{GatherInput, PollyEngine, PollyVoice, Voice};
+//! use twiml_rust::{twiml, TwiML};
+//!
+//! let caller = "Ada";
+//! let vip = true;
+//! let agents = ["+15551230001", "+15551230002"];
+//!
+//! let response = twiml!(voice {
+//!     Say(format!("Hello {caller}")).voice(Voice::Polly(PollyVoice::Joanna, PollyEngine::Neural));
+//!     Gather.input(vec![GatherInput::Dtmf]).num_digits(1).action("/menu") {
+//!         Say("For sales, press 1.");
+//!         Pause(None);
+//!         Say("For support, press 2.");
+//!     }
+//!     if vip {
+//!         Dial.timeout(20) {
+//!             for number in agents {
+//!                 DialNumber(number);
+//!             }
+//!         }
+//!     } else {
+//!         .redirect("/queue");
+//!     }
+//!     Hangup;
+//! });
+//!
+//! let xml = response.to_xml();
+//! assert!(xml.contains("<Gather input=\"dtmf\" action=\"/menu\" numDigits=\"1\">"));
+//! assert!(xml.contains("<Number>+15551230002</Number>"));
+//! ```
+//!
+//! The body of `voice { ... }` or `messaging { ... }` is a list of
+//! statements:
+//!
+//! | Statement | Expands to |
+//! |-----------|------------|
+//! | `Name;` | `Name::new()` appended to the parent |
+//! | `Name(args);` | `Name::new(args)` appended to the parent |
+//! | `Name::ctor(args);` | `Name::ctor(args)` appended to the parent |
+//! | `... .setter(args) ... ;` | builder calls on the element before it is appended |
+//! | `... { children }` | the element with `children` appended to it, in place of `;` |
+//! | `.method(args);` | `parent.method(args)`, any builder method of the parent |
+//! | `(expr);` | an already built element appended to the parent |
+//! | `let pattern = expr;` | a binding visible to the statements that follow |
+//! | `if` / `else if` / `else`, `if let`, `for` | the same control flow around the statements in their blocks |
+//!
+//! `Name` is looked up in [`voice`] or [`messaging`](crate::messaging), so
+//! element types need no imports; values passed as arguments, such as
+//! `Voice::Alice`, do. `Play`, `Prompt`, `Stream` and `Parameter` must be
+//! built with one of their constructors, as their `new()` is deprecated:
+//!
+//! ```compile_fail
+//! use twiml_rust::twiml;
+//!
+//! // error: `Play` needs a URL or digits
+//! let response = twiml!(voice {
+//!     Play;
+//! });
+//! ```
+//!
+//! An element is appended with [`Nest`], which is only implemented for the
+//! parent/child pairs TwiML allows. Nesting mistakes are therefore compile
+//! errors rather than validation errors:
+//!
+//! ```compile_fail
+//! use twiml_rust::twiml;
+//!
+//! // error: the trait bound `Gather: Nest<Dial>` is not satisfied
+//! let response = twiml!(voice {
+//!     Gather {
+//!         Dial.number("+15551234567");
+//!     }
+//! });
+//! ```
+//!
+//! Each statement is one level of macro recursion, and each token of an `if`
+//! or `for` header one more. Very long bodies may need a higher
+//! `#![recursion_limit]`.
+
+use crate::messaging::{Body, Media, Message, MessagingResponse, MessagingVerb, Redirect};
+use crate::voice;
+use crate::voice::{
+    AiSession, Assistant, Autopilot, Connect, Conversation, ConversationRelay,
+    ConversationRelaySession, Dial, DialApplication, DialClient, DialConference, DialNumber,
+    DialQueue, DialSim, DialSip, DialWhatsApp, Echo, Enqueue, Gather, Hangup, Language, Leave,
+    Parameter, Pause, Pay, Play, Prompt, Queue, Record, Recording, Refer, ReferSip, Reject,
+    RenderedFragment, Room, Say, Siprec, Sms, Start, Stop, Stream, Transcription, VirtualAgent,
+    VoiceResponse,
+};
+
+/// A TwiML element that accepts `C` as a child
+///
+/// This is how [`twiml!`](crate::twiml) appends elements; it is implemented
+/// for every parent/child pair TwiML allows and nothing else.
+pub trait Nest<C> {
+    /// Append `child` and return the parent
+    fn nest(self, child: C) -> Self;
+}
+
+/// Implement [`Nest`] by forwarding to a builder method of the parent
+macro_rules! nest {
+    ($parent:ty { $($child:ty => $method:ident),* $(,)? }) => {
+        $(
+            impl Nest<$child> for $parent {
+                fn nest(self, child: $child) -> Self {
+                    self.$method(child)
+                }
+            }
+        )*
+    };
+}
+
+nest!(VoiceResponse {
+    Connect => connect_with,
+    Dial => dial_with,
+    Enqueue => enqueue_with,
+    Gather => gather_with,
+    Pay => pay_with,
+    Play => play_with,
+    Prompt => prompt_with,
+    Queue => queue_with,
+    Record => record_with,
+    voice::Redirect => redirect_with,
+    Refer => refer_with,
+    Reject => reject_with,
+    Say => say_with,
+    Sms => sms_with,
+    Start => start_with,
+    RenderedFragment => fragment,
+});
+
+impl Nest<Echo> for VoiceResponse {
+    fn nest(self, _: Echo) -> Self {
+        self.echo()
+    }
+}
+
+impl Nest<Hangup> for VoiceResponse {
+    fn nest(self, _: Hangup) -> Self {
+        self.hangup()
+    }
+}
+
+impl Nest<Leave> for VoiceResponse {
+    fn nest(self, _: Leave) -> Self {
+        self.leave()
+    }
+}
+
+impl Nest<Pause> for VoiceResponse {
+    fn nest(self, pause: Pause) -> Self {
+        self.pause(pause.attributes.length)
+    }
+}
+
+impl Nest<Stop> for VoiceResponse {
+    fn nest(self, _: Stop) -> Self {
+        self.stop()
+    }
+}
+
+nest!(Gather {
+    Say => add_say,
+    Play => add_play,
+    Pause => add_pause,
+});
+
+nest!(Dial {
+    DialNumber => add_number,
+    DialClient => add_client,
+    DialConference => add_conference,
+    DialQueue => add_queue,
+    DialSip => add_sip,
+    DialSim => add_sim,
+    DialApplication => add_application,
+    DialWhatsApp => add_whatsapp,
+});
+
+nest!(Connect {
+    Stream => add_stream,
+    Room => add_room,
+    Conversation => add_conversation,
+    VirtualAgent => add_virtual_agent,
+    Autopilot => add_autopilot,
+    AiSession => add_ai_session,
+    ConversationRelaySession => add_conversation_relay_session,
+    Assistant => add_assistant,
+    ConversationRelay => add_conversation_relay,
+});
+
+nest!(Start {
+    Stream => add_stream,
+    Siprec => add_siprec,
+    Transcription => add_transcription,
+    Recording => add_recording,
+});
+
+nest!(Refer { ReferSip => add_refer_sip });
+nest!(Stream { Parameter => add_parameter });
+nest!(VirtualAgent { Parameter => add_parameter });
+nest!(DialApplication { Parameter => add_parameter });
+nest!(ConversationRelay {
+    Language => add_language,
+    Parameter => add_parameter,
+});
+
+impl Nest<Prompt> for Pay {
+    fn nest(mut self, prompt: Prompt) -> Self {
+        self.prompts.push(prompt);
+        self
+    }
+}
+
+impl Nest<Parameter> for Pay {
+    fn nest(mut self, parameter: Parameter) -> Self {
+        self.parameters.push(parameter);
+        self
+    }
+}
+
+nest!(MessagingResponse { Message => message_with_nouns });
+
+impl Nest<Redirect> for MessagingResponse {
+    fn nest(mut self, redirect: Redirect) -> Self {
+        self.verbs.push(MessagingVerb::Redirect(redirect));
+        self
+    }
+}
+
+nest!(Message {
+    Body => body,
+    Media => add_media,
+});
+
+/// Build a [`VoiceResponse`] or [`MessagingResponse`] from a block of
+/// elements
+///
+/// See the [`macros`](crate::macros) module for the syntax.
+///
+/// # Example
+/// ```
+/// use twiml_rust::{twiml, TwiML};
+///
+/// let response = twiml!(messaging {
+///     Message::default() {
+///         Body("Your order has shipped");
+///         Media("https://example.com/label.png");
+///     }
+/// });
+/// assert!(response.to_xml().contains("<Body>Your order has shipped</Body>"));
+/// ```
+#[macro_export]
+macro_rules! twiml {
+    (voice { $($body:tt)* }) => {{
+        #[allow(unused_mut)]
+        let mut response = $crate::voice::VoiceResponse::new();
+        $crate::__twiml_children!(voice response; $($body)*);
+        response
+    }};
+    (messaging { $($body:tt)* }) => {{
+        #[allow(unused_mut)]
+        let mut response = $crate::messaging::MessagingResponse::new();
+        $crate::__twiml_children!(messaging response; $($body)*);
+        response
+    }};
+}
+
+/// Expand the statements of a `twiml!` block, appending to `$parent`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __twiml_children {
+    ($module:ident $parent:ident;) => {};
+    ($module:ident $parent:ident; ; $($rest:tt)*) => {
+        $crate::__twiml_children!($module $parent; $($rest)*);
+    };
+    ($module:ident $parent:ident; . $method:ident ( $($args:tt)* ) ; $($rest:tt)*) => {
+        $parent = $parent.$method($($args)*);
+        $crate::__twiml_children!($module $parent; $($rest)*);
+    };
+    ($module:ident $parent:ident; ( $child:expr ) ; $($rest:tt)*) => {
+        $parent = $crate::macros::Nest::nest($parent, $child);
+        $crate::__twiml_children!($module $parent; $($rest)*);
+    };
+    ($module:ident $parent:ident; let $pattern:pat = $value:expr ; $($rest:tt)*) => {
+        let $pattern = $value;
+        $crate::__twiml_children!($module $parent; $($rest)*);
+    };
+    ($module:ident $parent:ident; if $($rest:tt)*) => {
+        $crate::__twiml_if!($module $parent; [] [] $($rest)*);
+    };
+    ($module:ident $parent:ident; for $pattern:pat in $($rest:tt)*) => {
+        $crate::__twiml_for!($module $parent; ($pattern) [] $($rest)*);
+    };
+    (
+        $module:ident $parent:ident;
+        $name:ident :: $ctor:ident ( $($args:tt)* )
+        $(. $setter:ident ( $($setter_args:tt)* ))*
+        ; $($rest:tt)*
+    ) => {
+        $parent = $crate::macros::Nest::nest(
+            $parent,
+            $crate::$module::$name::$ctor($($args)*) $(.$setter($($setter_args)*))*,
+        );
+        $crate::__twiml_children!($module $parent; $($rest)*);
+    };
+    (
+        $module:ident $parent:ident;
+        $name:ident :: $ctor:ident ( $($args:tt)* )
+        $(. $setter:ident ( $($setter_args:tt)* ))*
+        { $($body:tt)* } $($rest:tt)*
+    ) => {
+        $parent = $crate::macros::Nest::nest($parent, {
+            #[allow(unused_mut)]
+            let mut element =
+                $crate::$module::$name::$ctor($($args)*) $(.$setter($($setter_args)*))*;
+            $crate::__twiml_children!($module element; $($body)*);
+            element
+        });
+        $crate::__twiml_children!($module $parent; $($rest)*);
+    };
+    (voice $parent:ident; Play $($rest:tt)*) => {
+        compile_error!("`Play` needs a URL or digits: use `Play::with_url(..)` or `Play::with_digits(..)`");
+    };
+    (voice $parent:ident; Prompt $($rest:tt)*) => {
+        compile_error!("`Prompt` needs `for`: use `Prompt::with_for(..)`");
+    };
+    (voice $parent:ident; Stream $($rest:tt)*) => {
+        compile_error!("`Stream` needs a URL: use `Stream::with_url(..)`");
+    };
+    (voice $parent:ident; Parameter $($rest:tt)*) => {
+        compile_error!("`Parameter` needs a name and value: use `Parameter::with_value(..)`");
+    };
+    (
+        $module:ident $parent:ident;
+        $name:ident $(( $($args:tt)* ))?
+        $(. $setter:ident ( $($setter_args:tt)* ))*
+        ; $($rest:tt)*
+    ) => {
+        $parent = $crate::macros::Nest::nest(
+            $parent,
+            $crate::$module::$name::new($($($args)*)?) $(.$setter($($setter_args)*))*,
+        );
+        $crate::__twiml_children!($module $parent; $($rest)*);
+    };
+    (
+        $module:ident $parent:ident;
+        $name:ident $(( $($args:tt)* ))?
+        $(. $setter:ident ( $($setter_args:tt)* ))*
+        { $($body:tt)* } $($rest:tt)*
+    ) => {
+        $parent = $crate::macros::Nest::nest($parent, {
+            #[allow(unused_mut)]
+            let mut element =
+                $crate::$module::$name::new($($($args)*)?) $(.$setter($($setter_args)*))*;
+            $crate::__twiml_children!($module element; $($body)*);
+            element
+        });
+        $crate::__twiml_children!($module $parent; $($rest)*);
+    };
+}
+
+/// Collect an `if` / `else if` / `else` chain of a `twiml!` block
+///
+/// The first bracket holds the branches already expanded, the second the
+/// condition being collected up to its block.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __twiml_if {
+    (
+        $module:ident $parent:ident; [$($chain:tt)*] [$($cond:tt)+]
+        { $($then:tt)* } else if $($rest:tt)*
+    ) => {
+        $crate::__twiml_if!(
+            $module $parent;
+            [$($chain)* if $($cond)+ { $crate::__twiml_children!($module $parent; $($then)*); } else]
+            []
+            $($rest)*
+        );
+    };
+    (
+        $module:ident $parent:ident; [$($chain:tt)*] [$($cond:tt)+]
+        { $($then:tt)* } else { $($otherwise:tt)* } $($rest:tt)*
+    ) => {
+        $($chain)* if $($cond)+ {
+            $crate::__twiml_children!($module $parent; $($then)*);
+        } else {
+            $crate::__twiml_children!($module $parent; $($otherwise)*);
+        }
+        $crate::__twiml_children!($module $parent; $($rest)*);
+    };
+    (
+        $module:ident $parent:ident; [$($chain:tt)*] [$($cond:tt)+]
+        { $($then:tt)* } $($rest:tt)*
+    ) => {
+        $($chain)* if $($cond)+ {
+            $crate::__twiml_children!($module $parent; $($then)*);
+        }
+        $crate::__twiml_children!($module $parent; $($rest)*);
+    };
+    (
+        $module:ident $parent:ident; [$($chain:tt)*] [$($cond:tt)*]
+        $next:tt $($rest:tt)*
+    ) => {
+        $crate::__twiml_if!($module $parent; [$($chain)*] [$($cond)* $next] $($rest)*);
+    };
+}
+
+/// Collect the iterator expression of a `for` loop in a `twiml!` block
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __twiml_for {
+    (
+        $module:ident $parent:ident; ($pattern:pat) [$($iter:tt)+]
+        { $($body:tt)* } $($rest:tt)*
+    ) => {
+        for $pattern in $($iter)+ {
+            $crate::__twiml_children!($module $parent; $($body)*);
+        }
+        $crate::__twiml_children!($module $parent; $($rest)*);
+    };
+    (
+        $module:ident $parent:ident; ($pattern:pat) [$($iter:tt)*]
+        $next:tt $($rest:tt)*
+    ) => {
+        $crate::__twiml_for!($module $parent; ($pattern) [$($iter)* $next] $($rest)*);
+    };
+}
@@ -33,21 +33,34 @@
 //! println!("{}", response.to_xml());
 //! ```
 
+use std::fmt;
+
+use crate::error::{Error, Result};
+use crate::render::RenderOptions;
+use crate::validation::{ModelChecker, TwiMLValidator, ValidateModel, ValidationError};
 use crate::xml_escape::{escape_xml_attr, escape_xml_text};
+use crate::xml_parser::{child_path, parse_document, Element, Node};
 use crate::TwiML;
 
 /// Attributes to pass to message
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct MessageAttributes {
     /// action - A URL specifying where Twilio should send status callbacks for the created outbound message.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub action: Option<String>,
     /// from - Phone Number to send Message from
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub from: Option<String>,
     /// method - Action URL Method
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
     /// statusCallback - Status callback URL. Deprecated in favor of action.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback: Option<String>,
     /// to - Phone Number to send Message to
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub to: Option<String>,
 }
 
@@ -90,8 +103,11 @@ impl MessageAttributes {
 
 /// Attributes to pass to redirect
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct RedirectAttributes {
     /// method - Redirect URL method
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
 }
 
@@ -110,6 +126,8 @@ impl RedirectAttributes {
 
 /// `<Body>` TwiML Noun
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Body {
     message: String,
 }
@@ -122,13 +140,21 @@ impl Body {
         }
     }
 
-    fn to_xml(&self) -> String {
-        format!("<Body>{}</Body>", escape_xml_text(&self.message))
+    fn write_xml<W: fmt::Write + ?Sized>(
+        &self,
+        xml: &mut W,
+        options: &RenderOptions,
+    ) -> fmt::Result {
+        options.write_indent(xml, 2)?;
+        write!(xml, "<Body>{}</Body>", escape_xml_text(&self.message))?;
+        options.write_newline(xml)
     }
 }
 
 /// `<Media>` TwiML Noun
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Media {
     url: String,
 }
@@ -139,16 +165,30 @@ impl Media {
         Self { url: url.into() }
     }
 
-    fn to_xml(&self) -> String {
-        format!("<Media>{}</Media>", escape_xml_text(&self.url))
+    fn write_xml<W: fmt::Write + ?Sized>(
+        &self,
+        xml: &mut W,
+        options: &RenderOptions,
+    ) -> fmt::Result {
+        options.write_indent(xml, 2)?;
+        write!(xml, "<Media>{}</Media>", escape_xml_text(&self.url))?;
+        options.write_newline(xml)
     }
 }
 
 /// `<Message>` TwiML Verb
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Message {
+    #[cfg_attr(feature = "serde", serde(default))]
     attributes: MessageAttributes,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     body: Option<Body>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     media: Vec<Media>,
 }
 
@@ -189,55 +229,72 @@ impl Message {
         self
     }
 
-    fn to_xml(&self) -> String {
-        let mut xml = String::from("<Message");
+    fn write_xml<W: fmt::Write + ?Sized>(
+        &self,
+        xml: &mut W,
+        options: &RenderOptions,
+    ) -> fmt::Result {
+        options.write_indent(xml, 1)?;
+        xml.write_str("<Message")?;
 
         // Add attributes
         if let Some(ref action) = self.attributes.action {
-            xml.push_str(&format!(" action=\"{}\"", escape_xml_attr(action)));
+            write!(xml, " action=\"{}\"", escape_xml_attr(action))?;
         }
         if let Some(ref from) = self.attributes.from {
-            xml.push_str(&format!(" from=\"{}\"", escape_xml_attr(from)));
+            write!(xml, " from=\"{}\"", escape_xml_attr(from))?;
         }
         if let Some(ref method) = self.attributes.method {
-            xml.push_str(&format!(" method=\"{}\"", escape_xml_attr(method)));
+            write!(xml, " method=\"{}\"", escape_xml_attr(method))?;
         }
         if let Some(ref status_callback) = self.attributes.status_callback {
-            xml.push_str(&format!(
+            write!(
+                xml,
                 " statusCallback=\"{}\"",
                 escape_xml_attr(status_callback)
-            ));
+            )?;
         }
         if let Some(ref to) = self.attributes.to {
-            xml.push_str(&format!(" to=\"{}\"", escape_xml_attr(to)));
+            write!(xml, " to=\"{}\"", escape_xml_attr(to))?;
         }
 
         // Check if we have body or media
         if self.body.is_some() || !self.media.is_empty() {
-            xml.push('>');
+            xml.write_char('>')?;
+            options.write_newline(xml)?;
 
             // Add Body noun if present
             if let Some(ref body) = self.body {
-                xml.push_str(&body.to_xml());
+                body.write_xml(xml, options)?;
             }
 
             // Add Media nouns
             for media in &self.media {
-                xml.push_str(&media.to_xml());
+                media.write_xml(xml, options)?;
             }
 
-            xml.push_str("</Message>");
+            options.write_indent(xml, 1)?;
+            xml.write_str("</Message>")?;
         } else {
-            xml.push_str(" />");
+            options.write_self_close(xml)?;
         }
 
-        xml
+        options.write_newline(xml)
+    }
+}
+
+impl Default for Message {
+    fn default() -> Self {
+        Self::with_nouns(MessageAttributes::default())
     }
 }
 
 /// `<Redirect>` TwiML Verb
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Redirect {
+    #[cfg_attr(feature = "serde", serde(default))]
     attributes: RedirectAttributes,
     url: String,
 }
@@ -251,20 +308,26 @@ impl Redirect {
         }
     }
 
-    fn to_xml(&self) -> String {
-        let mut xml = String::from("<Redirect");
+    fn write_xml<W: fmt::Write + ?Sized>(
+        &self,
+        xml: &mut W,
+        options: &RenderOptions,
+    ) -> fmt::Result {
+        options.write_indent(xml, 1)?;
+        xml.write_str("<Redirect")?;
 
         if let Some(ref method) = self.attributes.method {
-            xml.push_str(&format!(" method=\"{}\"", escape_xml_attr(method)));
+            write!(xml, " method=\"{}\"", escape_xml_attr(method))?;
         }
 
-        xml.push_str(&format!(">{}</Redirect>", escape_xml_text(&self.url)));
-        xml
+        write!(xml, ">{}</Redirect>", escape_xml_text(&self.url))?;
+        options.write_newline(xml)
     }
 }
 
 /// Top-level TwiML verbs for messaging
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum MessagingVerb {
     Message(Message),
     Redirect(Redirect),
@@ -272,10 +335,15 @@ pub(crate) enum MessagingVerb {
 
 /// `<Response>` TwiML for Messages
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct MessagingResponse {
     pub(crate) verbs: Vec<MessagingVerb>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     comments_before: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     comments: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     comments_after: Vec<String>,
 }
 
@@ -423,39 +491,216 @@ impl MessagingResponse {
 }
 
 impl TwiML for MessagingResponse {
-    fn to_xml(&self) -> String {
-        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    /// Everything after the XML declaration on one line
+    fn default_render_options(&self) -> RenderOptions {
+        RenderOptions::pretty()
+            .inline_elements(true)
+            .self_closing_per_element()
+    }
+
+    fn write_xml_with<W: fmt::Write + ?Sized>(
+        &self,
+        xml: &mut W,
+        options: &RenderOptions,
+    ) -> fmt::Result {
+        options.write_declaration(xml)?;
 
         // Add comments before Response
         for comment in &self.comments_before {
-            xml.push_str(&format!("<!-- {} -->\n", escape_xml_text(comment)));
+            options.write_leading_comment(xml, comment)?;
         }
 
-        xml.push_str("<Response>");
+        xml.write_str("<Response>")?;
 
         // Add comments inside Response
         for comment in &self.comments {
-            xml.push_str(&format!("\n  <!-- {} -->", escape_xml_text(comment)));
+            options.write_comment_line(xml, 1, comment)?;
         }
+        options.write_newline(xml)?;
 
         for verb in &self.verbs {
             match verb {
                 MessagingVerb::Message(message) => {
-                    xml.push_str(&message.to_xml());
+                    message.write_xml(xml, options)?;
                 }
                 MessagingVerb::Redirect(redirect) => {
-                    xml.push_str(&redirect.to_xml());
+                    redirect.write_xml(xml, options)?;
                 }
             }
         }
 
-        xml.push_str("</Response>");
+        xml.write_str("</Response>")?;
 
         // Add comments after Response
         for comment in &self.comments_after {
-            xml.push_str(&format!("\n<!-- {} -->", escape_xml_text(comment)));
+            options.write_comment_line(xml, 0, comment)?;
+        }
+
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<Vec<ValidationError>> {
+        Ok(self.validate_model())
+    }
+
+    fn validate_strict(&self) -> Result<Vec<ValidationError>> {
+        Ok(self.validate_model_strict())
+    }
+}
+
+impl MessagingResponse {
+    /// Parse a TwiML messaging document into a `MessagingResponse`
+    ///
+    /// Handles `<Message>` with `<Body>`/`<Media>` nouns, the attribute-only
+    /// form (`<Message to="..."/>`) and bare text content without a `<Body>`,
+    /// which is read as the message body. Unknown elements or attributes are
+    /// reported as errors instead of being dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use twiml_rust::{MessagingResponse, TwiML};
+    ///
+    /// let xml = r#"<Response><Message to="+15551234567">Hello!</Message></Response>"#;
+    /// let response = MessagingResponse::from_xml(xml).unwrap();
+    /// assert!(response.to_xml().contains("<Body>Hello!</Body>"));
+    /// ```
+    pub fn from_xml(xml: &str) -> Result<Self> {
+        let document = parse_document(xml)?;
+        let root = &document.root;
+        if root.name != "Response" {
+            return Err(Error::unknown_element("", root.name.clone()));
+        }
+        let path = "Response";
+        root.attributes(path).finish()?;
+
+        let mut response = MessagingResponse {
+            comments_before: document.comments_before,
+            comments_after: document.comments_after,
+            ..Default::default()
+        };
+
+        for child in &root.children {
+            match child {
+                Node::Element(element) => {
+                    let verb_path = child_path(path, &element.name);
+                    let verb = match element.name.as_str() {
+                        "Message" => MessagingVerb::Message(Message::parse(element, &verb_path)?),
+                        "Redirect" => {
+                            MessagingVerb::Redirect(Redirect::parse(element, &verb_path)?)
+                        }
+                        other => return Err(Error::unknown_element(path, other)),
+                    };
+                    response.verbs.push(verb);
+                }
+                Node::Comment(comment) => response.comments.push(comment.clone()),
+                Node::Text(text) if text.trim().is_empty() => {}
+                Node::Text(text) => {
+                    return Err(Error::parse(
+                        path,
+                        format!("unexpected text content '{}'", text.trim()),
+                    ))
+                }
+            }
         }
 
-        xml
+        Ok(response)
+    }
+}
+
+impl Message {
+    fn parse(element: &Element, path: &str) -> Result<Self> {
+        let mut attrs = element.attributes(path);
+        let attributes = MessageAttributes {
+            action: attrs.string("action"),
+            from: attrs.string("from"),
+            method: attrs.string("method"),
+            status_callback: attrs.string("statusCallback"),
+            to: attrs.string("to"),
+        };
+        attrs.finish()?;
+
+        let mut message = Message::with_nouns(attributes);
+        let mut text = String::new();
+        for child in &element.children {
+            match child {
+                Node::Text(t) => text.push_str(t),
+                Node::Element(noun) => {
+                    let noun_path = child_path(path, &noun.name);
+                    noun.attributes(&noun_path).finish()?;
+                    match noun.name.as_str() {
+                        "Body" if message.body.is_none() => {
+                            message.body = Some(Body::new(noun.text(&noun_path)?));
+                        }
+                        "Body" => {
+                            return Err(Error::parse(path, "multiple <Body> elements"));
+                        }
+                        "Media" => message
+                            .media
+                            .push(Media::new(noun.text(&noun_path)?.trim())),
+                        other => return Err(Error::unknown_element(path, other)),
+                    }
+                }
+                Node::Comment(_) => {}
+            }
+        }
+
+        if !text.trim().is_empty() {
+            if message.body.is_some() || !message.media.is_empty() {
+                return Err(Error::parse(
+                    path,
+                    "bare text cannot be mixed with <Body> or <Media>",
+                ));
+            }
+            message.body = Some(Body::new(text.trim()));
+        }
+
+        Ok(message)
+    }
+}
+
+impl Redirect {
+    fn parse(element: &Element, path: &str) -> Result<Self> {
+        let mut attrs = element.attributes(path);
+        let attributes = RedirectAttributes {
+            method: attrs.string("method"),
+        };
+        attrs.finish()?;
+        Ok(Redirect::new(attributes, element.text(path)?.trim()))
+    }
+}
+
+impl ValidateModel for MessagingResponse {
+    fn validate_model_with(&self, validator: &TwiMLValidator) -> Vec<ValidationError> {
+        let mut checker = ModelChecker::new(validator);
+        for (i, verb) in self.verbs.iter().enumerate() {
+            match verb {
+                MessagingVerb::Message(message) => {
+                    let path = format!("verbs[{}].Message", i);
+                    let a = &message.attributes;
+                    checker
+                        .element(format!("{}.attributes", path), "Message")
+                        .string("action", "action", a.action.as_deref())
+                        .string("method", "method", a.method.as_deref())
+                        .string(
+                            "status_callback",
+                            "statusCallback",
+                            a.status_callback.as_deref(),
+                        );
+                    if let Some(body) = &message.body {
+                        checker.element(format!("{}.body", path), "Body").content(
+                            "message",
+                            &body.message,
+                            false,
+                        );
+                    }
+                }
+                MessagingVerb::Redirect(redirect) => {
+                    checker
+                        .element(format!("verbs[{}].Redirect.attributes", i), "Redirect")
+                        .string("method", "method", redirect.attributes.method.as_deref());
+                }
+            }
+        }
+        checker.finish()
     }
 }
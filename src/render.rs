@@ -0,0 +1,249 @@
+//! Layout options for rendering TwiML responses.
+//!
+//! [`RenderOptions`] controls the whitespace and markup style of the XML that
+//! [`TwiML::to_xml_with`](crate::TwiML::to_xml_with) produces. Every response
+//! type honors the same options, so the output is byte-stable across them.
+//! [`TwiML::to_xml`](crate::TwiML::to_xml) keeps each type's own layout, see
+//! [`TwiML::default_render_options`](crate::TwiML::default_render_options).
+//!
+//! # Example
+//! ```
+//! use twiml_rust::{MessagingResponse, RenderOptions, TwiML};
+//!
+//! let response = MessagingResponse::new().message("Hi");
+//! assert_eq!(
+//!     response.to_xml_with(&RenderOptions::compact()),
+//!     "<Response><Message><Body>Hi</Body></Message></Response>"
+//! );
+//! ```
+
+use std::fmt;
+
+use crate::xml_escape::escape_xml_text;
+
+/// The `Content-Type` to serve rendered TwiML with
+///
+/// Every renderer writes UTF-8, and Twilio accepts both `text/xml` and
+/// `application/xml`.
+pub const CONTENT_TYPE: &str = "text/xml; charset=utf-8";
+
+/// Line terminator written between elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Newline {
+    /// No line breaks; everything is written on one line
+    None,
+    /// `\n`
+    Lf,
+    /// `\r\n`
+    CrLf,
+}
+
+impl Newline {
+    /// The line terminator as written to the output
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Newline::None => "",
+            Newline::Lf => "\n",
+            Newline::CrLf => "\r\n",
+        }
+    }
+}
+
+/// How empty elements are closed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SelfClosing {
+    /// `<Hangup/>`
+    Compact,
+    /// `<Hangup />`
+    Spaced,
+}
+
+impl SelfClosing {
+    /// The closing markup as written to the output
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SelfClosing::Compact => "/>",
+            SelfClosing::Spaced => " />",
+        }
+    }
+}
+
+/// Layout options for rendering TwiML
+///
+/// The default is [`RenderOptions::pretty`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RenderOptions {
+    /// Spaces per nesting level
+    pub indent: usize,
+    /// Line terminator between elements
+    pub newline: Newline,
+    /// Whether to write the `<?xml ...?>` declaration
+    pub xml_declaration: bool,
+    /// How empty elements are closed
+    ///
+    /// `None` keeps each element's own form: `<Receive/>` and
+    /// `<SipHeader/>`, `<Hangup />` everywhere else.
+    pub self_closing: Option<SelfClosing>,
+    /// Whether to write elements back to back without line breaks
+    ///
+    /// The declaration and comments still go on their own lines.
+    pub inline_elements: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self::pretty()
+    }
+}
+
+impl RenderOptions {
+    /// Create the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// One element per line, indented by two spaces, with the XML declaration
+    pub fn pretty() -> Self {
+        Self {
+            indent: 2,
+            newline: Newline::Lf,
+            xml_declaration: true,
+            self_closing: Some(SelfClosing::Spaced),
+            inline_elements: false,
+        }
+    }
+
+    /// Everything on one line with no XML declaration, for the wire
+    pub fn compact() -> Self {
+        Self {
+            indent: 0,
+            newline: Newline::None,
+            xml_declaration: false,
+            self_closing: Some(SelfClosing::Compact),
+            inline_elements: false,
+        }
+    }
+
+    /// Set the number of spaces per nesting level
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Set the line terminator
+    pub fn newline(mut self, newline: Newline) -> Self {
+        self.newline = newline;
+        self
+    }
+
+    /// Set whether to write the `<?xml ...?>` declaration
+    pub fn xml_declaration(mut self, xml_declaration: bool) -> Self {
+        self.xml_declaration = xml_declaration;
+        self
+    }
+
+    /// Set how empty elements are closed
+    pub fn self_closing(mut self, self_closing: SelfClosing) -> Self {
+        self.self_closing = Some(self_closing);
+        self
+    }
+
+    /// Close each empty element in its own form, see [`RenderOptions::self_closing`]
+    pub fn self_closing_per_element(mut self) -> Self {
+        self.self_closing = None;
+        self
+    }
+
+    /// Set whether to write elements back to back without line breaks
+    pub fn inline_elements(mut self, inline_elements: bool) -> Self {
+        self.inline_elements = inline_elements;
+        self
+    }
+
+    /// Write the XML declaration, if enabled, followed by a line break
+    pub(crate) fn write_declaration<W: fmt::Write + ?Sized>(&self, xml: &mut W) -> fmt::Result {
+        if self.xml_declaration {
+            xml.write_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+            self.write_break(xml)?;
+        }
+        Ok(())
+    }
+
+    /// Write a comment on its own line, indented for `depth`
+    ///
+    /// Comments get their own line even with `inline_elements`.
+    pub(crate) fn write_comment_line<W: fmt::Write + ?Sized>(
+        &self,
+        xml: &mut W,
+        depth: usize,
+        comment: &str,
+    ) -> fmt::Result {
+        self.write_break(xml)?;
+        for _ in 0..self.indent * depth {
+            xml.write_char(' ')?;
+        }
+        write!(xml, "<!-- {} -->", escape_xml_text(comment))
+    }
+
+    /// Write a comment that precedes `<Response>`, followed by a line break
+    pub(crate) fn write_leading_comment<W: fmt::Write + ?Sized>(
+        &self,
+        xml: &mut W,
+        comment: &str,
+    ) -> fmt::Result {
+        write!(xml, "<!-- {} -->", escape_xml_text(comment))?;
+        self.write_break(xml)
+    }
+
+    /// Write a line break regardless of `inline_elements`
+    fn write_break<W: fmt::Write + ?Sized>(&self, xml: &mut W) -> fmt::Result {
+        xml.write_str(self.newline.as_str())
+    }
+
+    /// Write a line break between elements
+    pub(crate) fn write_newline<W: fmt::Write + ?Sized>(&self, xml: &mut W) -> fmt::Result {
+        if self.inline_elements {
+            return Ok(());
+        }
+        self.write_break(xml)
+    }
+
+    /// Write the indentation of an element at `depth` nesting levels
+    pub(crate) fn write_indent<W: fmt::Write + ?Sized>(
+        &self,
+        xml: &mut W,
+        depth: usize,
+    ) -> fmt::Result {
+        if self.inline_elements {
+            return Ok(());
+        }
+        for _ in 0..self.indent * depth {
+            xml.write_char(' ')?;
+        }
+        Ok(())
+    }
+
+    /// Write a line break followed by the indentation for `depth`
+    pub(crate) fn write_line<W: fmt::Write + ?Sized>(
+        &self,
+        xml: &mut W,
+        depth: usize,
+    ) -> fmt::Result {
+        self.write_newline(xml)?;
+        self.write_indent(xml, depth)
+    }
+
+    /// Close an empty element
+    pub(crate) fn write_self_close<W: fmt::Write + ?Sized>(&self, xml: &mut W) -> fmt::Result {
+        self.write_self_close_as(xml, SelfClosing::Spaced)
+    }
+
+    /// Close an empty element whose own form is `own`
+    pub(crate) fn write_self_close_as<W: fmt::Write + ?Sized>(
+        &self,
+        xml: &mut W,
+        own: SelfClosing,
+    ) -> fmt::Result {
+        xml.write_str(self.self_closing.unwrap_or(own).as_str())
+    }
+}
@@ -0,0 +1,561 @@
+//! TwiML Schema
+//!
+//! Tables describing where each TwiML element may appear. The validator uses
+//! them to report misplaced verbs and nouns in hand-written documents.
+
+use crate::fax::{ReceiveMediaType, ReceivePageSize};
+use crate::voice::{
+    CallStatusEvent, CardType, ConferenceBeep, ConferenceEvent, ConferenceJitterBufferSize,
+    ConferenceRecord, ConferenceRegion, DialEvents, DialRecord, DialRingTone, GatherInput,
+    MediaTrack, PayBankAccountType, PayInput, PayLanguage, PayPaymentMethod, PayTokenType,
+    PromptErrorType, PromptFor, RecordingChannels, RecordingEvent, RecordingTrack, RejectReason,
+    SpeechModel, SsmlBreakStrength, SsmlEmphasisLevel, SsmlPhonemeAlphabet, SsmlSayAsFormat,
+    SsmlSayAsInterpretAs, Trim,
+};
+
+/// The child elements a TwiML element accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElementSchema {
+    /// Element name as written in TwiML
+    pub name: &'static str,
+    /// Elements allowed directly inside this one
+    pub children: &'static [&'static str],
+}
+
+/// Verbs allowed directly inside `<Response>` in any kind of document
+///
+/// A single document may only use the verbs of one kind; see
+/// [`ResponseKind`].
+pub const TOP_LEVEL_VERBS: &[&str] = &[
+    "Connect", "Dial", "Echo", "Enqueue", "Gather", "Hangup", "Leave", "Pause", "Pay", "Play",
+    "Prompt", "Queue", "Record", "Redirect", "Refer", "Reject", "Say", "Sms", "Start", "Stop",
+    "Message", "Receive",
+];
+
+/// Verbs allowed directly inside the `<Response>` of a voice document
+pub const VOICE_VERBS: &[&str] = &[
+    "Connect", "Dial", "Echo", "Enqueue", "Gather", "Hangup", "Leave", "Pause", "Pay", "Play",
+    "Prompt", "Queue", "Record", "Redirect", "Refer", "Reject", "Say", "Sms", "Start", "Stop",
+];
+
+/// Verbs allowed directly inside the `<Response>` of a messaging document
+pub const MESSAGING_VERBS: &[&str] = &["Message", "Redirect"];
+
+/// Verbs allowed directly inside the `<Response>` of a fax document
+pub const FAX_VERBS: &[&str] = &["Receive"];
+
+/// The kind of document a `<Response>` holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseKind {
+    /// A `VoiceResponse`, answering a call
+    Voice,
+    /// A `MessagingResponse`, answering a message
+    Messaging,
+    /// A `FaxResponse`, answering a fax
+    Fax,
+}
+
+impl ResponseKind {
+    /// Verbs allowed directly inside `<Response>` in this kind of document
+    pub fn verbs(self) -> &'static [&'static str] {
+        match self {
+            Self::Voice => VOICE_VERBS,
+            Self::Messaging => MESSAGING_VERBS,
+            Self::Fax => FAX_VERBS,
+        }
+    }
+
+    /// The only kind of document `verb` may appear in at the top level, or
+    /// `None` if it is shared (like `<Redirect>`) or not a top-level verb
+    pub fn of_verb(verb: &str) -> Option<Self> {
+        let kinds: Vec<Self> = [Self::Voice, Self::Messaging, Self::Fax]
+            .into_iter()
+            .filter(|kind| kind.verbs().contains(&verb))
+            .collect();
+        match kinds[..] {
+            [kind] => Some(kind),
+            _ => None,
+        }
+    }
+
+    /// Lowercase name, as in "a voice response"
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Voice => "voice",
+            Self::Messaging => "messaging",
+            Self::Fax => "fax",
+        }
+    }
+}
+
+/// SSML elements that may appear inside `<Say>` and inside each other
+const SSML_ELEMENTS: &[&str] = &[
+    "break",
+    "emphasis",
+    "lang",
+    "p",
+    "phoneme",
+    "prosody",
+    "s",
+    "say-as",
+    "sub",
+    "w",
+    "amazon:effect",
+    "amazon:domain",
+];
+
+/// SSML elements allowed inside a sentence (everything but `<p>` and `<s>`)
+const SSML_INLINE: &[&str] = &[
+    "break",
+    "emphasis",
+    "lang",
+    "phoneme",
+    "prosody",
+    "say-as",
+    "sub",
+    "w",
+    "amazon:effect",
+    "amazon:domain",
+];
+
+/// Every element that accepts child elements. Elements not listed here accept
+/// none.
+pub const ELEMENTS: &[ElementSchema] = &[
+    ElementSchema {
+        name: "Response",
+        children: TOP_LEVEL_VERBS,
+    },
+    // Voice verbs
+    ElementSchema {
+        name: "Connect",
+        children: &[
+            "Stream",
+            "Room",
+            "Conversation",
+            "VirtualAgent",
+            "Autopilot",
+            "AiSession",
+            "ConversationRelaySession",
+            "Assistant",
+            "ConversationRelay",
+        ],
+    },
+    ElementSchema {
+        name: "Dial",
+        children: &[
+            "Number",
+            "Client",
+            "Conference",
+            "Queue",
+            "Sip",
+            "Sim",
+            "Application",
+            "WhatsApp",
+        ],
+    },
+    ElementSchema {
+        name: "Enqueue",
+        children: &["Task"],
+    },
+    ElementSchema {
+        name: "Gather",
+        children: &["Say", "Play", "Pause"],
+    },
+    ElementSchema {
+        name: "Pay",
+        children: &["Prompt", "Parameter"],
+    },
+    ElementSchema {
+        name: "Prompt",
+        children: &["Say", "Play", "Pause"],
+    },
+    ElementSchema {
+        name: "Refer",
+        children: &["Sip"],
+    },
+    ElementSchema {
+        name: "Say",
+        children: SSML_ELEMENTS,
+    },
+    ElementSchema {
+        name: "Start",
+        children: &["Stream", "Siprec", "Transcription", "Recording"],
+    },
+    ElementSchema {
+        name: "Stop",
+        children: &["Stream", "Siprec", "Transcription"],
+    },
+    // Nouns with nested elements
+    ElementSchema {
+        name: "Application",
+        children: &["ApplicationSid", "Parameter"],
+    },
+    ElementSchema {
+        name: "Assistant",
+        children: &["Language", "Parameter"],
+    },
+    ElementSchema {
+        name: "Client",
+        children: &["Identity", "Parameter"],
+    },
+    ElementSchema {
+        name: "ConversationRelay",
+        children: &["Language", "Parameter"],
+    },
+    ElementSchema {
+        name: "Sip",
+        children: &["SipHeader"],
+    },
+    ElementSchema {
+        name: "Siprec",
+        children: &["Parameter"],
+    },
+    ElementSchema {
+        name: "Stream",
+        children: &["Parameter"],
+    },
+    ElementSchema {
+        name: "Transcription",
+        children: &["Parameter"],
+    },
+    ElementSchema {
+        name: "VirtualAgent",
+        children: &["Config", "Parameter"],
+    },
+    // Messaging
+    ElementSchema {
+        name: "Message",
+        children: &["Body", "Media"],
+    },
+    // SSML
+    ElementSchema {
+        name: "p",
+        children: SSML_ELEMENTS,
+    },
+    ElementSchema {
+        name: "s",
+        children: SSML_INLINE,
+    },
+    ElementSchema {
+        name: "emphasis",
+        children: SSML_INLINE,
+    },
+    ElementSchema {
+        name: "lang",
+        children: SSML_ELEMENTS,
+    },
+    ElementSchema {
+        name: "prosody",
+        children: SSML_ELEMENTS,
+    },
+    ElementSchema {
+        name: "amazon:effect",
+        children: SSML_ELEMENTS,
+    },
+    ElementSchema {
+        name: "amazon:domain",
+        children: SSML_ELEMENTS,
+    },
+];
+
+/// Look up the schema entry for an element
+pub fn element(name: &str) -> Option<&'static ElementSchema> {
+    ELEMENTS.iter().find(|e| e.name == name)
+}
+
+/// Elements allowed directly inside `parent` (empty if it takes none)
+pub fn allowed_children(parent: &str) -> &'static [&'static str] {
+    element(parent).map(|e| e.children).unwrap_or(&[])
+}
+
+/// Whether `child` may appear directly inside `parent`
+pub fn is_allowed_child(parent: &str, child: &str) -> bool {
+    allowed_children(parent).contains(&child)
+}
+
+/// Elements that accept `child` directly inside them
+pub fn allowed_parents(child: &str) -> Vec<&'static str> {
+    ELEMENTS
+        .iter()
+        .filter(|e| e.children.contains(&child))
+        .map(|e| e.name)
+        .collect()
+}
+
+/// The values an attribute accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeDomain {
+    /// Exactly one of the listed values
+    Enum(&'static [&'static str]),
+    /// A space-separated list of the listed values
+    EnumList(&'static [&'static str]),
+    /// A whole number within `min..=max` (no upper bound if `max` is `None`)
+    Integer { min: u32, max: Option<u32> },
+    /// `true` or `false`
+    Boolean,
+    /// `GET` or `POST`
+    HttpMethod,
+}
+
+impl AttributeDomain {
+    /// Check `value` against the domain, describing the problem on failure
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        match self {
+            Self::Enum(allowed) => {
+                if allowed.contains(&value) {
+                    Ok(())
+                } else {
+                    Err(format!("expected one of: {}", allowed.join(", ")))
+                }
+            }
+            Self::EnumList(allowed) => {
+                match value.split_whitespace().find(|v| !allowed.contains(v)) {
+                    Some(v) => Err(format!("'{}' is not one of: {}", v, allowed.join(", "))),
+                    None => Ok(()),
+                }
+            }
+            Self::Integer { min, max } => {
+                let number: u32 = value
+                    .parse()
+                    .map_err(|_| "expected a whole number".to_string())?;
+                if number < *min {
+                    return Err(format!("must be at least {}", min));
+                }
+                match max {
+                    Some(max) if number > *max => Err(format!("must be at most {}", max)),
+                    _ => Ok(()),
+                }
+            }
+            Self::Boolean => match value {
+                "true" | "false" => Ok(()),
+                _ => Err("expected 'true' or 'false'".to_string()),
+            },
+            Self::HttpMethod => match value {
+                "GET" | "POST" => Ok(()),
+                _ => Err("expected GET or POST".to_string()),
+            },
+        }
+    }
+
+    /// Whether the domain is a fixed set of values
+    pub fn is_enum(&self) -> bool {
+        matches!(self, Self::Enum(_) | Self::EnumList(_) | Self::HttpMethod)
+    }
+}
+
+/// The domain of one attribute on one element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttributeSchema {
+    /// Element name as written in TwiML
+    pub element: &'static str,
+    /// Attribute name as written in TwiML
+    pub name: &'static str,
+    /// Values the attribute accepts
+    pub domain: AttributeDomain,
+}
+
+const fn attr(
+    element: &'static str,
+    name: &'static str,
+    domain: AttributeDomain,
+) -> AttributeSchema {
+    AttributeSchema {
+        element,
+        name,
+        domain,
+    }
+}
+
+const fn int(min: u32, max: u32) -> AttributeDomain {
+    AttributeDomain::Integer {
+        min,
+        max: Some(max),
+    }
+}
+
+const fn at_least(min: u32) -> AttributeDomain {
+    AttributeDomain::Integer { min, max: None }
+}
+
+use AttributeDomain::{Boolean, Enum, EnumList};
+
+/// Every attribute with a restricted domain. Any other attribute named
+/// `method` or ending in `Method` is restricted to HTTP methods.
+///
+/// Enumerated domains come from the `VALUES` of the attribute's enum, so
+/// the schema and the typed API accept the same values.
+pub const ATTRIBUTES: &[AttributeSchema] = &[
+    // <Dial>
+    attr("Dial", "answerOnBridge", Boolean),
+    attr("Dial", "events", Enum(DialEvents::VALUES)),
+    attr("Dial", "hangupOnStar", Boolean),
+    attr("Dial", "record", Enum(DialRecord::VALUES)),
+    attr(
+        "Dial",
+        "recordingStatusCallbackEvent",
+        EnumList(RecordingEvent::VALUES),
+    ),
+    attr("Dial", "recordingTrack", Enum(RecordingTrack::VALUES)),
+    attr("Dial", "ringTone", Enum(DialRingTone::VALUES)),
+    attr("Dial", "sequential", Boolean),
+    attr("Dial", "timeLimit", int(1, 14400)),
+    attr("Dial", "timeout", int(5, 600)),
+    attr("Dial", "trim", Enum(Trim::VALUES)),
+    // <Dial> nouns
+    attr(
+        "Number",
+        "statusCallbackEvent",
+        EnumList(CallStatusEvent::VALUES),
+    ),
+    attr(
+        "Number",
+        "machineDetection",
+        Enum(&["Enable", "DetectMessageEnd"]),
+    ),
+    attr("Number", "machineDetectionTimeout", int(3, 59)),
+    attr("Number", "machineDetectionSpeechThreshold", int(1000, 6000)),
+    attr(
+        "Number",
+        "machineDetectionSpeechEndThreshold",
+        int(500, 5000),
+    ),
+    attr("Number", "machineDetectionSilenceTimeout", int(2000, 10000)),
+    attr(
+        "Client",
+        "statusCallbackEvent",
+        EnumList(CallStatusEvent::VALUES),
+    ),
+    attr(
+        "Sip",
+        "statusCallbackEvent",
+        EnumList(CallStatusEvent::VALUES),
+    ),
+    attr(
+        "WhatsApp",
+        "statusCallbackEvent",
+        EnumList(CallStatusEvent::VALUES),
+    ),
+    attr("Conference", "beep", Enum(ConferenceBeep::VALUES)),
+    attr("Conference", "beepOnCustomerEntrance", Boolean),
+    attr("Conference", "coaching", Boolean),
+    attr("Conference", "endConferenceOnExit", Boolean),
+    attr(
+        "Conference",
+        "jitterBufferSize",
+        Enum(ConferenceJitterBufferSize::VALUES),
+    ),
+    attr("Conference", "maxParticipants", int(2, 250)),
+    attr("Conference", "muted", Boolean),
+    attr("Conference", "record", Enum(ConferenceRecord::VALUES)),
+    attr(
+        "Conference",
+        "recordingStatusCallbackEvent",
+        EnumList(RecordingEvent::VALUES),
+    ),
+    attr("Conference", "region", Enum(ConferenceRegion::VALUES)),
+    attr("Conference", "startConferenceOnEnter", Boolean),
+    attr(
+        "Conference",
+        "statusCallbackEvent",
+        EnumList(ConferenceEvent::VALUES),
+    ),
+    attr("Conference", "trim", Enum(Trim::VALUES)),
+    // <Enqueue>
+    attr("Enqueue", "maxQueueSize", int(1, 5000)),
+    // <Gather>
+    attr("Gather", "actionOnEmptyResult", Boolean),
+    attr("Gather", "bargeIn", Boolean),
+    attr("Gather", "debug", Boolean),
+    attr("Gather", "dtmfDetection", Boolean),
+    attr("Gather", "enhanced", Boolean),
+    attr("Gather", "input", EnumList(GatherInput::VALUES)),
+    attr("Gather", "maxSpeechTime", int(1, 60)),
+    attr("Gather", "numDigits", at_least(1)),
+    attr("Gather", "profanityFilter", Boolean),
+    attr("Gather", "speechModel", Enum(SpeechModel::VALUES)),
+    attr("Gather", "timeout", at_least(1)),
+    // <Pause>, <Play>, <Say>
+    attr("Pause", "length", at_least(1)),
+    attr("Play", "loop", at_least(0)),
+    attr("Say", "loop", at_least(0)),
+    // <Pay> and <Prompt>
+    attr("Pay", "bankAccountType", Enum(PayBankAccountType::VALUES)),
+    attr("Pay", "input", Enum(PayInput::VALUES)),
+    attr("Pay", "language", Enum(PayLanguage::VALUES)),
+    attr("Pay", "maxAttempts", int(1, 3)),
+    attr("Pay", "minPostalCodeLength", at_least(0)),
+    attr("Pay", "paymentMethod", Enum(PayPaymentMethod::VALUES)),
+    attr("Pay", "postalCode", Boolean),
+    attr("Pay", "securityCode", Boolean),
+    attr("Pay", "timeout", at_least(1)),
+    attr("Pay", "tokenType", Enum(PayTokenType::VALUES)),
+    attr("Pay", "validCardTypes", EnumList(CardType::VALUES)),
+    attr("Prompt", "cardType", EnumList(CardType::VALUES)),
+    attr("Prompt", "errorType", EnumList(PromptErrorType::VALUES)),
+    attr("Prompt", "for", Enum(PromptFor::VALUES)),
+    attr("Prompt", "requireMatchingInputs", Boolean),
+    // <Record>
+    attr("Record", "maxLength", int(1, 14400)),
+    attr("Record", "playBeep", Boolean),
+    attr(
+        "Record",
+        "recordingChannels",
+        Enum(RecordingChannels::VALUES),
+    ),
+    attr(
+        "Record",
+        "recordingStatusCallbackEvent",
+        EnumList(RecordingEvent::VALUES),
+    ),
+    attr("Record", "recordingTrack", Enum(RecordingTrack::VALUES)),
+    attr("Record", "timeout", at_least(0)),
+    attr("Record", "transcribe", Boolean),
+    attr("Record", "trim", Enum(Trim::VALUES)),
+    // <Reject>
+    attr("Reject", "reason", Enum(RejectReason::VALUES)),
+    // <Connect> and <Start> nouns
+    attr("ConversationRelay", "dtmfDetection", Boolean),
+    attr("ConversationRelay", "interruptible", Boolean),
+    attr("ConversationRelay", "maxDuration", at_least(1)),
+    attr("ConversationRelay", "profanityFilter", Boolean),
+    attr("ConversationRelay", "transcriptionEnabled", Boolean),
+    attr("Recording", "channels", Enum(RecordingChannels::VALUES)),
+    attr(
+        "Recording",
+        "recordingStatusCallbackEvent",
+        EnumList(RecordingEvent::VALUES),
+    ),
+    attr("Recording", "track", Enum(RecordingTrack::VALUES)),
+    attr("Recording", "trim", Enum(Trim::VALUES)),
+    attr("Siprec", "track", Enum(MediaTrack::VALUES)),
+    attr("Stream", "track", Enum(MediaTrack::VALUES)),
+    attr("Transcription", "enableAutomaticPunctuation", Boolean),
+    attr("Transcription", "partialResults", Boolean),
+    attr("Transcription", "profanityFilter", Boolean),
+    attr("Transcription", "track", Enum(MediaTrack::VALUES)),
+    // <Receive>
+    attr("Receive", "mediaType", Enum(ReceiveMediaType::VALUES)),
+    attr("Receive", "pageSize", Enum(ReceivePageSize::VALUES)),
+    attr("Receive", "storeMedia", Boolean),
+    // SSML
+    attr("break", "strength", Enum(SsmlBreakStrength::VALUES)),
+    attr("emphasis", "level", Enum(SsmlEmphasisLevel::VALUES)),
+    attr("phoneme", "alphabet", Enum(SsmlPhonemeAlphabet::VALUES)),
+    attr("say-as", "format", Enum(SsmlSayAsFormat::VALUES)),
+    attr("say-as", "interpret-as", Enum(SsmlSayAsInterpretAs::VALUES)),
+];
+
+/// The domain of `name` on `element`, if it is restricted
+///
+/// An entry in [`ATTRIBUTES`] wins, so attributes like `paymentMethod` on
+/// `<Pay>` keep their own values; other `method` and `*Method` attributes
+/// take HTTP methods.
+pub fn attribute_domain(element: &str, name: &str) -> Option<AttributeDomain> {
+    ATTRIBUTES
+        .iter()
+        .find(|a| a.element == element && a.name == name)
+        .map(|a| a.domain)
+        .or_else(|| {
+            (name == "method" || name.ends_with("Method")).then_some(AttributeDomain::HttpMethod)
+        })
+}
@@ -0,0 +1,32 @@
+//! Serde support for values written as a single TwiML attribute string.
+//!
+//! Enums such as `DialRecord` or `Voice` serialize to the same string they
+//! render as in TwiML. Deserializing is as lenient as `from_xml`: a value this
+//! crate does not know becomes the enum's `Custom` variant.
+
+/// Implement `Serialize`/`Deserialize` through `Display` and `AttributeValue`
+macro_rules! serde_attribute_value {
+    ($name:ty) => {
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> std::result::Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> std::result::Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                Ok(<$name as crate::xml_parser::AttributeValue>::from_attribute(&value))
+            }
+        }
+    };
+}
+
+pub(crate) use serde_attribute_value;
@@ -0,0 +1,231 @@
+//! Verification of the `X-Twilio-Signature` header.
+//!
+//! Twilio signs every webhook request with the auth token of your account:
+//! the HMAC-SHA1 of the full request URL followed by the POST parameters
+//! sorted by name, each written as name then value, base64-encoded. A
+//! request with a JSON body instead carries the hex SHA-256 of the body in a
+//! `bodySHA256` query parameter, and only the URL is signed.
+//!
+//! [`RequestValidator`] recomputes the signature and compares it in constant
+//! time. It needs no dependencies.
+//!
+//! # Example
+//! ```
+//! use twiml_rust::signature::RequestValidator;
+//! use twiml_rust::webhook::FormParams;
+//!
+//! let validator = RequestValidator::new("12345");
+//! let params: FormParams = [
+//!     ("CallSid", "CA1234567890ABCDE"),
+//!     ("Caller", "+14158675309"),
+//!     ("Digits", "1234"),
+//!     ("From", "+14158675309"),
+//!     ("To", "+18005551212"),
+//! ]
+//! .into_iter()
+//! .collect();
+//!
+//! assert!(validator.validate(
+//!     "https://mycompany.com/myapp.php?foo=1&bar=2",
+//!     &params,
+//!     "RSOYDt4T1cUTdK1PDd93/VVr8B8=",
+//! ));
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::crypto::{base64, constant_time_eq, hex, hmac_sha1, sha256};
+use crate::webhook::FormParams;
+
+/// Checks that webhook requests were signed with an account's auth token
+#[derive(Clone)]
+pub struct RequestValidator {
+    auth_token: String,
+    trust_forwarded_headers: bool,
+}
+
+impl fmt::Debug for RequestValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestValidator")
+            .field("auth_token", &"<redacted>")
+            .field("trust_forwarded_headers", &self.trust_forwarded_headers)
+            .finish()
+    }
+}
+
+impl RequestValidator {
+    /// Create a validator for the account with `auth_token`
+    pub fn new(auth_token: impl Into<String>) -> Self {
+        Self {
+            auth_token: auth_token.into(),
+            trust_forwarded_headers: false,
+        }
+    }
+
+    /// Rebuild the signed URL from the `X-Forwarded-Proto` and
+    /// `X-Forwarded-Host` headers a proxy sets
+    ///
+    /// Off by default: anyone can send these headers, so only turn it on
+    /// when every request passes through a proxy that overwrites them. The
+    /// framework integrations use this setting; it does not affect the
+    /// `validate*` methods, which take the URL as given.
+    pub fn trust_forwarded_headers(mut self, trust: bool) -> Self {
+        self.trust_forwarded_headers = trust;
+        self
+    }
+
+    /// Whether the framework integrations read `X-Forwarded-*` headers
+    pub fn trusts_forwarded_headers(&self) -> bool {
+        self.trust_forwarded_headers
+    }
+
+    /// The signature Twilio sends for a request to `url` with the POST
+    /// parameters `params`
+    ///
+    /// Pass empty `params` for GET requests and JSON bodies.
+    pub fn compute_signature(&self, url: &str, params: &FormParams) -> String {
+        let mut pairs: Vec<(&str, &str)> = params.iter().collect();
+        pairs.sort_unstable();
+
+        let mut data = String::from(url);
+        for (name, value) in pairs {
+            data.push_str(name);
+            data.push_str(value);
+        }
+        base64(&hmac_sha1(self.auth_token.as_bytes(), data.as_bytes()))
+    }
+
+    /// Whether `signature` is valid for a request to `url` with the POST
+    /// parameters `params`
+    ///
+    /// `url` must be the URL Twilio requested, including the query string.
+    /// Like Twilio's own libraries, this also accepts the signature of the
+    /// URL with its port removed or with the scheme's default port added,
+    /// since proxies often change it.
+    pub fn validate(&self, url: &str, params: &FormParams, signature: &str) -> bool {
+        url_variants(url).iter().any(|url| {
+            constant_time_eq(
+                self.compute_signature(url, params).as_bytes(),
+                signature.as_bytes(),
+            )
+        })
+    }
+
+    /// Whether `signature` is valid for a request to `url` with the
+    /// form-encoded `body`
+    ///
+    /// A body that does not decode is never valid.
+    pub fn validate_form(&self, url: &str, body: &str, signature: &str) -> bool {
+        FormParams::parse(body)
+            .map(|params| self.validate(url, &params, signature))
+            .unwrap_or(false)
+    }
+
+    /// Whether `signature` is valid for a request to `url` with a
+    /// non-form `body`, such as JSON
+    ///
+    /// `url` must carry the `bodySHA256` query parameter, and it must match
+    /// the SHA-256 of `body`.
+    ///
+    /// # Example
+    /// ```
+    /// use twiml_rust::signature::RequestValidator;
+    ///
+    /// let validator = RequestValidator::new("12345");
+    /// let body = br#"{"property": "value", "boolean": true}"#;
+    /// let url = "https://mycompany.com/myapp.php?foo=1&bar=2&bodySHA256=\
+    ///            0a1ff7634d9ab3b95db5c9a2dfe9416e41502b283a80c7cf19632632f96e6620";
+    ///
+    /// assert!(validator.validate_body(url, body, "a9nBmqA0ju/hNViExpshrM61xv4="));
+    /// assert!(!validator.validate_body(url, b"{}", "a9nBmqA0ju/hNViExpshrM61xv4="));
+    /// ```
+    pub fn validate_body(&self, url: &str, body: &[u8], signature: &str) -> bool {
+        let expected = match body_sha256(url) {
+            Some(expected) => expected,
+            None => return false,
+        };
+        let actual = hex(&sha256(body));
+        constant_time_eq(actual.as_bytes(), expected.to_ascii_lowercase().as_bytes())
+            && self.validate(url, &FormParams::default(), signature)
+    }
+}
+
+/// The `bodySHA256` query parameter of `url`
+fn body_sha256(url: &str) -> Option<String> {
+    let query = url.split('#').next()?.split_once('?')?.1;
+    FormParams::parse(query)
+        .ok()?
+        .get("bodySHA256")
+        .map(str::to_string)
+}
+
+/// `url` as given, without its port, and with the scheme's default port
+fn url_variants(url: &str) -> Vec<Cow<'_, str>> {
+    let mut variants = vec![Cow::Borrowed(url)];
+
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return variants;
+    };
+    let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let (authority, path) = rest.split_at(authority_end);
+    // The host starts after any credentials; an IPv6 host is bracketed
+    let host_start = authority.rfind('@').map_or(0, |i| i + 1);
+    let host_end = authority[host_start..]
+        .rfind(']')
+        .map_or(host_start, |i| host_start + i);
+    let port = authority[host_end..].rfind(':').map(|i| host_end + i);
+
+    match port {
+        Some(colon) => {
+            variants.push(Cow::Owned(format!(
+                "{}://{}{}",
+                scheme,
+                &authority[..colon],
+                path
+            )));
+        }
+        None => {
+            let default_port = match scheme.to_ascii_lowercase().as_str() {
+                "https" => 443,
+                "http" => 80,
+                _ => return variants,
+            };
+            variants.push(Cow::Owned(format!(
+                "{}://{}:{}{}",
+                scheme, authority, default_port, path
+            )));
+        }
+    }
+    variants
+}
+
+/// The URL Twilio requested, rebuilt from the request a framework received
+///
+/// `header` looks up a request header. With `trust_forwarded_headers`, a
+/// proxy reports the public scheme and host in `X-Forwarded-Proto` and
+/// `X-Forwarded-Host`; otherwise, or without them, the request's own `scheme`
+/// (else `https`) and `Host` header (else `authority`) are used.
+#[cfg(any(feature = "actix", feature = "http"))]
+pub(crate) fn request_url<'a>(
+    header: impl Fn(&str) -> Option<&'a str>,
+    trust_forwarded_headers: bool,
+    scheme: Option<&'a str>,
+    authority: Option<&'a str>,
+    path_and_query: &str,
+) -> String {
+    let header = |name: &str| {
+        header(name)
+            // A proxy chain lists one value per hop; the first is the client's
+            .and_then(|value| value.split(',').next())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+    };
+    let forwarded = |name: &str| header(name).filter(|_| trust_forwarded_headers);
+    let scheme = forwarded("X-Forwarded-Proto").or(scheme).unwrap_or("https");
+    let host = forwarded("X-Forwarded-Host")
+        .or_else(|| header("Host"))
+        .or(authority)
+        .unwrap_or_default();
+    format!("{}://{}{}", scheme, host, path_and_query)
+}
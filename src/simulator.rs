@@ -0,0 +1,1023 @@
+//! Offline execution of voice responses, for testing IVR logic.
+//!
+//! [`Simulator`] interprets a [`VoiceResponse`] the way Twilio would: it
+//! steps through the verbs, lets a scripted [`Caller`] answer `<Gather>`,
+//! `<Record>` and `<Pay>`, and calls your handler with a synthesized webhook
+//! request wherever Twilio would request a URL (an `action`, a
+//! `<Redirect>`, or the document URL for a `<Gather>` without `action`).
+//! The handler answers with the next document, and the run ends on
+//! `<Hangup>`, `<Reject>`, the end of a document, or the caller hanging up.
+//!
+//! Verbs after one that hands control to another URL are never reached,
+//! just as on Twilio; the [`Transcript`] records what the caller heard and
+//! which URLs were requested.
+//!
+//! # Example
+//! ```
+//! use twiml_rust::simulator::{Caller, Outcome, Simulator};
+//! use twiml_rust::voice::Gather;
+//! use twiml_rust::webhook::GatherResult;
+//! use twiml_rust::VoiceResponse;
+//!
+//! let transcript = Simulator::new("https://example.com/voice")
+//!     .caller(Caller::new().press("2"))
+//!     .run(|request| match request.path() {
+//!         "/voice" => Some(
+//!             VoiceResponse::new().gather_with(
+//!                 Gather::new()
+//!                     .num_digits(1)
+//!                     .action("/menu")
+//!                     .add_say(twiml_rust::voice::Say::new("Press 1 for sales, 2 for billing")),
+//!             ),
+//!         ),
+//!         "/menu" => {
+//!             let result = GatherResult::from_params(request.params.clone()).ok()?;
+//!             match result.digits.as_deref() {
+//!                 Some("2") => Some(VoiceResponse::new().say("Billing").hangup()),
+//!                 _ => Some(VoiceResponse::new().redirect("/voice")),
+//!             }
+//!         }
+//!         _ => None,
+//!     });
+//!
+//! assert_eq!(
+//!     transcript.heard(),
+//!     ["Press 1 for sales, 2 for billing", "Billing"]
+//! );
+//! assert_eq!(
+//!     transcript.urls(),
+//!     ["https://example.com/voice", "https://example.com/menu"]
+//! );
+//! assert_eq!(transcript.outcome, Outcome::HungUp);
+//! ```
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::voice::{
+    Dial, DialNoun, Gather, GatherInput, GatherNoun, Pause, Pay, Play, Record, Say, SsmlElement,
+    VoiceResponse, VoiceVerb,
+};
+use crate::webhook::{CallStatus, FormParams};
+
+/// What the scripted caller does the next time the call waits for input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallerAction {
+    /// Press keypad keys, such as `"2"` or `"1234#"`
+    Press(String),
+    /// Speak, to a speech-enabled `<Gather>` or into a `<Record>`
+    Say(String),
+    /// Stay silent until the input times out
+    Silence,
+    /// Stay on the line this long, then hang up
+    ///
+    /// The time counts from the previous input, or from the start of the
+    /// call. Until then every input times out.
+    HangUpAfter(Duration),
+}
+
+/// A scripted caller
+///
+/// Each time the call waits for input, the caller takes the next action.
+/// A caller with no actions left hangs up.
+#[derive(Debug, Clone, Default)]
+pub struct Caller {
+    actions: VecDeque<CallerAction>,
+}
+
+impl Caller {
+    /// A caller with an empty script, who hangs up at the first prompt
+    ///
+    /// Add actions with [`press`](Caller::press), [`say`](Caller::say) and
+    /// the other builders; they are taken in the order they were added.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an action to the script
+    pub fn then(mut self, action: CallerAction) -> Self {
+        self.actions.push_back(action);
+        self
+    }
+
+    /// Press keypad keys
+    pub fn press(self, keys: impl Into<String>) -> Self {
+        self.then(CallerAction::Press(keys.into()))
+    }
+
+    /// Speak
+    pub fn say(self, speech: impl Into<String>) -> Self {
+        self.then(CallerAction::Say(speech.into()))
+    }
+
+    /// Let the input time out
+    pub fn silence(self) -> Self {
+        self.then(CallerAction::Silence)
+    }
+
+    /// Hang up right away
+    pub fn hang_up(self) -> Self {
+        self.hang_up_after(Duration::ZERO)
+    }
+
+    /// Stay on the line for `length`, then hang up
+    pub fn hang_up_after(self, length: Duration) -> Self {
+        self.then(CallerAction::HangUpAfter(length))
+    }
+}
+
+/// A request Twilio would have made to your application
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulatedRequest {
+    /// The absolute URL
+    pub url: String,
+    /// `GET` or `POST`
+    pub method: String,
+    /// The webhook parameters, as Twilio would send them
+    pub params: FormParams,
+}
+
+impl SimulatedRequest {
+    /// The path of `url`, without the query string
+    pub fn path(&self) -> &str {
+        let rest = self
+            .url
+            .split_once("://")
+            .map_or(&*self.url, |(_, rest)| rest);
+        let path = rest.find('/').map_or("/", |i| &rest[i..]);
+        path.split(['?', '#']).next().unwrap_or(path)
+    }
+}
+
+/// Something that happened during a simulated call
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// The caller heard the text of a `<Say>`
+    Said(String),
+    /// The caller heard the audio at this URL
+    Played(String),
+    /// `<Play digits>` sent these tones
+    PlayedDigits(String),
+    /// A `<Pause>` of this length
+    Paused(Duration),
+    /// The caller pressed keys
+    Pressed(String),
+    /// The caller spoke
+    Spoke(String),
+    /// `<Record>` recorded this much audio
+    Recorded(Duration),
+    /// `<Dial>` called these parties and ended with this status
+    Dialed {
+        /// Who was dialed, in document order: the bare number of
+        /// `<Dial>number</Dial>`, then one entry per noun. Numbers and SIP
+        /// URIs are given as is; clients, conferences, queues, SIMs,
+        /// applications and WhatsApp numbers as `client:alice`,
+        /// `conference:Room`, `queue:Support`, `sim:DE...`,
+        /// `application:AP...` and `whatsapp:+1555...`.
+        targets: Vec<String>,
+        /// The `DialCallStatus` reported to the `<Dial>` action, set with
+        /// [`Simulator::dial_status`]
+        status: CallStatus,
+    },
+    /// `<Pay>` finished with this `Result`
+    Paid(String),
+    /// A URL was requested
+    Requested(SimulatedRequest),
+    /// This many verbs after the previous one were never reached
+    Unreachable(usize),
+    /// A verb the simulator does not model was skipped
+    Skipped(&'static str),
+}
+
+/// How a simulated call ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The last document ran out of verbs
+    Completed,
+    /// `<Hangup>` ended the call
+    HungUp,
+    /// `<Reject>` declined the call
+    Rejected,
+    /// The caller hung up
+    CallerHungUp,
+    /// The handler had no document for this URL; Twilio would apologize
+    /// for an application error and hang up
+    ApplicationError(String),
+    /// The call made more requests than
+    /// [`Simulator::max_requests`] allows, as in a redirect loop
+    RequestLimit,
+}
+
+/// The record of a simulated call
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transcript {
+    /// Everything that happened, in order
+    pub events: Vec<Event>,
+    /// How the call ended
+    pub outcome: Outcome,
+    /// Simulated length of the call
+    pub duration: Duration,
+}
+
+impl Transcript {
+    /// The text of every `<Say>` the caller heard
+    pub fn heard(&self) -> Vec<&str> {
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Said(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Every request made, in order
+    pub fn requests(&self) -> impl Iterator<Item = &SimulatedRequest> {
+        self.events.iter().filter_map(|event| match event {
+            Event::Requested(request) => Some(request),
+            _ => None,
+        })
+    }
+
+    /// The URL of every request made, in order
+    pub fn urls(&self) -> Vec<&str> {
+        self.requests()
+            .map(|request| request.url.as_str())
+            .collect()
+    }
+}
+
+/// Runs a call against your application without Twilio
+///
+/// The timing is simulated: `<Say>` takes 0.4 seconds per word, `<Play>`
+/// the [`play_duration`](Self::play_duration), and inputs their `timeout`.
+/// A `loop="0"` `<Say>` or `<Play>` repeats until the caller hangs up or,
+/// inside `<Gather>`, answers. Speech is recognized verbatim with a
+/// confidence of 1.0. Relative URLs resolve against the document URL as in a
+/// browser.
+#[derive(Debug, Clone)]
+pub struct Simulator {
+    url: String,
+    from: String,
+    to: String,
+    caller: Caller,
+    dial_status: CallStatus,
+    play_duration: Duration,
+    max_requests: usize,
+}
+
+impl Simulator {
+    /// Simulate a call to the voice URL `url`
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            from: "+15005550006".to_string(),
+            to: "+15005550001".to_string(),
+            caller: Caller::new(),
+            dial_status: CallStatus::Completed,
+            play_duration: Duration::from_secs(5),
+            max_requests: 100,
+        }
+    }
+
+    /// The calling number, sent as `From`
+    pub fn from_number(mut self, number: impl Into<String>) -> Self {
+        self.from = number.into();
+        self
+    }
+
+    /// The called number, sent as `To`
+    pub fn to_number(mut self, number: impl Into<String>) -> Self {
+        self.to = number.into();
+        self
+    }
+
+    /// The caller's script
+    pub fn caller(mut self, caller: Caller) -> Self {
+        self.caller = caller;
+        self
+    }
+
+    /// How every `<Dial>` ends (`completed` by default)
+    ///
+    /// `no-answer` takes the `timeout` of the `<Dial>`.
+    pub fn dial_status(mut self, status: CallStatus) -> Self {
+        self.dial_status = status;
+        self
+    }
+
+    /// How long each `<Play>` of an audio URL lasts (5 seconds by default)
+    pub fn play_duration(mut self, length: Duration) -> Self {
+        self.play_duration = length;
+        self
+    }
+
+    /// Stop after this many requests (100 by default)
+    pub fn max_requests(mut self, limit: usize) -> Self {
+        self.max_requests = limit;
+        self
+    }
+
+    /// Request the voice URL from `handler` and run the call
+    ///
+    /// `handler` answers every request with the next document, or `None`
+    /// when it has none for the URL.
+    pub fn run<H>(&self, handler: H) -> Transcript
+    where
+        H: FnMut(&SimulatedRequest) -> Option<VoiceResponse>,
+    {
+        let mut call = Call::new(self, handler);
+        let request = call.request_to(&self.url, None, Vec::new());
+        let outcome = match call.fetch(request) {
+            Ok(document) => call.execute(document, self.url.clone()),
+            Err(outcome) => outcome,
+        };
+        call.finish(outcome)
+    }
+
+    /// Run the call from `response`, as if the voice URL had returned it
+    pub fn run_response<H>(&self, response: &VoiceResponse, handler: H) -> Transcript
+    where
+        H: FnMut(&SimulatedRequest) -> Option<VoiceResponse>,
+    {
+        let mut call = Call::new(self, handler);
+        let outcome = call.execute(response.clone(), self.url.clone());
+        call.finish(outcome)
+    }
+}
+
+/// What to do after a verb
+enum Flow {
+    /// Go on with the next verb
+    Next,
+    /// Leave the document for the response to this request
+    Request(SimulatedRequest),
+    /// End the call
+    End(Outcome),
+}
+
+/// The state of one simulated call
+struct Call<'a, H> {
+    simulator: &'a Simulator,
+    handler: H,
+    caller: VecDeque<CallerAction>,
+    events: Vec<Event>,
+    clock: Duration,
+    /// When the caller hangs up, once a `HangUpAfter` is due
+    hang_up_at: Option<Duration>,
+    requests: usize,
+}
+
+impl<'a, H> Call<'a, H>
+where
+    H: FnMut(&SimulatedRequest) -> Option<VoiceResponse>,
+{
+    fn new(simulator: &'a Simulator, handler: H) -> Self {
+        let mut call = Self {
+            simulator,
+            handler,
+            caller: simulator.caller.actions.clone(),
+            events: Vec::new(),
+            clock: Duration::ZERO,
+            hang_up_at: None,
+            requests: 0,
+        };
+        call.schedule_hang_up();
+        call
+    }
+
+    fn finish(self, outcome: Outcome) -> Transcript {
+        Transcript {
+            events: self.events,
+            outcome,
+            duration: self.clock,
+        }
+    }
+
+    /// Run documents until the call ends
+    fn execute(&mut self, mut document: VoiceResponse, mut url: String) -> Outcome {
+        loop {
+            let verbs = flatten(&document.verbs);
+            let mut next = None;
+            for (i, verb) in verbs.iter().enumerate() {
+                let flow = match self.step(verb, &url) {
+                    Ok(flow) => flow,
+                    Err(outcome) => return outcome,
+                };
+                if matches!(flow, Flow::Next) {
+                    continue;
+                }
+                let unreachable = verbs.len() - i - 1;
+                if unreachable > 0 {
+                    self.events.push(Event::Unreachable(unreachable));
+                }
+                match flow {
+                    Flow::Request(request) => next = Some(request),
+                    Flow::End(outcome) => return outcome,
+                    Flow::Next => unreachable!(),
+                }
+                break;
+            }
+
+            let Some(request) = next else {
+                return Outcome::Completed;
+            };
+            url = request.url.clone();
+            document = match self.fetch(request) {
+                Ok(document) => document,
+                Err(outcome) => return outcome,
+            };
+        }
+    }
+
+    /// Execute one verb; `Err` when the caller hangs up
+    fn step(&mut self, verb: &VoiceVerb, url: &str) -> Result<Flow, Outcome> {
+        self.check_line()?;
+        match verb {
+            VoiceVerb::Say(say) => self.say(say, false).map(|_| Flow::Next),
+            VoiceVerb::Play(play) => self.play(play, false).map(|_| Flow::Next),
+            VoiceVerb::Pause(pause) => self.pause(pause).map(|_| Flow::Next),
+            VoiceVerb::Gather(gather) => self.gather(gather, url),
+            VoiceVerb::Record(record) => self.record(record, url),
+            VoiceVerb::Dial(dial) => self.dial(dial, url),
+            VoiceVerb::Pay(pay) => self.pay(pay, url),
+            VoiceVerb::Redirect(redirect) => Ok(Flow::Request(self.request_to(
+                &resolve(url, &redirect.url),
+                redirect.attributes.method.as_deref(),
+                Vec::new(),
+            ))),
+            VoiceVerb::Hangup(_) => Ok(Flow::End(Outcome::HungUp)),
+            VoiceVerb::Reject(_) => Ok(Flow::End(Outcome::Rejected)),
+            VoiceVerb::Connect(_) => self.skip("Connect"),
+            VoiceVerb::Echo(_) => self.skip("Echo"),
+            VoiceVerb::Enqueue(_) => self.skip("Enqueue"),
+            VoiceVerb::Leave(_) => self.skip("Leave"),
+            VoiceVerb::Prompt(_) => self.skip("Prompt"),
+            VoiceVerb::Queue(_) => self.skip("Queue"),
+            VoiceVerb::Refer(_) => self.skip("Refer"),
+            VoiceVerb::Sms(_) => self.skip("Sms"),
+            VoiceVerb::Start(_) => self.skip("Start"),
+            VoiceVerb::Stop(_) => self.skip("Stop"),
+            VoiceVerb::Fragment(_) => unreachable!("fragments are flattened"),
+        }
+    }
+
+    fn skip(&mut self, verb: &'static str) -> Result<Flow, Outcome> {
+        self.events.push(Event::Skipped(verb));
+        Ok(Flow::Next)
+    }
+
+    fn say(&mut self, say: &Say, in_gather: bool) -> Result<(), Outcome> {
+        let text = say_text(&say.message, &say.ssml_elements);
+        self.repeat(say.attributes.loop_count, in_gather, |call| {
+            call.events.push(Event::Said(text.clone()));
+            call.elapse(speech_duration(&text))
+        })
+    }
+
+    /// Run `once` as often as the `loop` attribute `loop_count` asks
+    ///
+    /// `loop="0"` repeats until the caller hangs up or, inside `<Gather>`,
+    /// answers; a caller who has listened [`MAX_LOOPS`] times hangs up.
+    fn repeat(
+        &mut self,
+        loop_count: Option<u32>,
+        in_gather: bool,
+        mut once: impl FnMut(&mut Self) -> Result<(), Outcome>,
+    ) -> Result<(), Outcome> {
+        if loop_count != Some(0) {
+            for _ in 0..loop_count.unwrap_or(1) {
+                once(self)?;
+            }
+            return Ok(());
+        }
+        for _ in 0..MAX_LOOPS {
+            once(self)?;
+            let listening = matches!(
+                self.caller.front(),
+                Some(CallerAction::Silence | CallerAction::HangUpAfter(_))
+            );
+            if in_gather && !listening {
+                return Ok(());
+            }
+        }
+        Err(Outcome::CallerHungUp)
+    }
+
+    fn pause(&mut self, pause: &Pause) -> Result<(), Outcome> {
+        let length = Duration::from_secs(pause.attributes.length.unwrap_or(1).into());
+        self.events.push(Event::Paused(length));
+        self.elapse(length)
+    }
+
+    fn play(&mut self, play: &Play, in_gather: bool) -> Result<(), Outcome> {
+        if let Some(digits) = &play.attributes.digits {
+            self.events.push(Event::PlayedDigits(digits.clone()));
+            // Each `w` waits half a second
+            let waits = digits.chars().filter(|&c| c == 'w').count() as u32;
+            self.elapse(Duration::from_millis(500) * waits)?;
+        }
+        if let Some(url) = &play.url {
+            self.repeat(play.attributes.loop_count, in_gather, |call| {
+                call.events.push(Event::Played(url.clone()));
+                call.elapse(call.simulator.play_duration)
+            })?;
+        }
+        Ok(())
+    }
+
+    fn gather(&mut self, gather: &Gather, url: &str) -> Result<Flow, Outcome> {
+        let a = &gather.attributes;
+        for noun in &gather.nested {
+            self.check_line()?;
+            match noun {
+                GatherNoun::Say(say) => self.say(say, true)?,
+                GatherNoun::Play(play) => self.play(play, true)?,
+                GatherNoun::Pause(pause) => self.pause(pause)?,
+            }
+        }
+
+        let timeout = Duration::from_secs(a.timeout.unwrap_or(5).into());
+        let allows = |kind: GatherInput| match &a.input {
+            Some(inputs) => inputs.contains(&kind),
+            None => kind == GatherInput::Dtmf,
+        };
+        let mut params = Vec::new();
+        match self.next_input()? {
+            Some(CallerAction::Press(keys)) => {
+                self.events.push(Event::Pressed(keys.clone()));
+                if allows(GatherInput::Dtmf) {
+                    let finish_keys = a.finish_on_key.as_deref().unwrap_or("#");
+                    let mut digits = String::new();
+                    let mut finished_on = None;
+                    for key in keys.chars() {
+                        if finish_keys.contains(key) {
+                            finished_on = Some(key);
+                            break;
+                        }
+                        digits.push(key);
+                        if a.num_digits == Some(digits.chars().count() as u32) {
+                            break;
+                        }
+                    }
+                    let complete = finished_on.is_some()
+                        || a.num_digits == Some(digits.chars().count() as u32);
+                    if !complete {
+                        self.elapse(timeout)?;
+                    }
+                    if !digits.is_empty() {
+                        params.push(("Digits", digits));
+                        if let Some(key) = finished_on {
+                            params.push(("FinishedOnKey", key.to_string()));
+                        }
+                    }
+                } else {
+                    self.elapse(timeout)?;
+                }
+            }
+            Some(CallerAction::Say(speech)) => {
+                self.events.push(Event::Spoke(speech.clone()));
+                if allows(GatherInput::Speech) {
+                    params.push(("SpeechResult", speech));
+                    params.push(("Confidence", "1.0".to_string()));
+                } else {
+                    self.elapse(timeout)?;
+                }
+            }
+            _ => self.elapse(timeout)?,
+        }
+
+        if params.is_empty() && !a.action_on_empty_result.unwrap_or(false) {
+            return Ok(Flow::Next);
+        }
+        // Without `action`, Twilio submits to the current document's URL
+        let target = a
+            .action
+            .as_deref()
+            .map_or(url.to_string(), |action| resolve(url, action));
+        Ok(Flow::Request(self.request_to(
+            &target,
+            a.method.as_deref(),
+            params,
+        )))
+    }
+
+    fn record(&mut self, record: &Record, url: &str) -> Result<Flow, Outcome> {
+        let a = &record.attributes;
+        let timeout = Duration::from_secs(a.timeout.unwrap_or(5).into());
+        let max_length = Duration::from_secs(a.max_length.unwrap_or(3600).into());
+        let target = a
+            .action
+            .as_deref()
+            .map_or(url.to_string(), |action| resolve(url, action));
+
+        let speech = match self.next_input()? {
+            Some(CallerAction::Say(speech)) => speech,
+            Some(CallerAction::Press(keys)) => {
+                // A key before any audio ends an empty recording
+                self.events.push(Event::Pressed(keys));
+                return Ok(Flow::Next);
+            }
+            _ => {
+                // Twilio does not request `action` for an empty recording
+                self.elapse(timeout)?;
+                return Ok(Flow::Next);
+            }
+        };
+
+        self.events.push(Event::Spoke(speech.clone()));
+        let start = self.clock;
+        let length = speech_duration(&speech).min(max_length);
+        let hung_up =
+            self.elapse(length).is_err() || (length < max_length && self.elapse(timeout).is_err());
+        let recorded = (self.clock - start).min(length);
+        self.events.push(Event::Recorded(recorded));
+
+        let mut params = vec![
+            (
+                "RecordingUrl",
+                format!(
+                    "https://api.twilio.com/2010-04-01/Accounts/{}/Recordings/{}",
+                    ACCOUNT_SID,
+                    sid("RE", self.requests + 1)
+                ),
+            ),
+            ("RecordingSid", sid("RE", self.requests + 1)),
+            ("RecordingDuration", recorded.as_secs().to_string()),
+        ];
+        if hung_up {
+            // Twilio still posts the recording, but the call is over
+            params.push(("Digits", "hangup".to_string()));
+            let request = self.request_to(&target, a.method.as_deref(), params);
+            self.fetch(request).ok();
+            return Err(Outcome::CallerHungUp);
+        }
+        Ok(Flow::Request(self.request_to(
+            &target,
+            a.method.as_deref(),
+            params,
+        )))
+    }
+
+    fn dial(&mut self, dial: &Dial, url: &str) -> Result<Flow, Outcome> {
+        let a = &dial.attributes;
+        let mut targets: Vec<String> = dial.number.iter().cloned().collect();
+        targets.extend(dial.nested.iter().map(dial_target));
+        let status = self.simulator.dial_status.clone();
+        self.events.push(Event::Dialed {
+            targets,
+            status: status.clone(),
+        });
+        if status == CallStatus::NoAnswer {
+            self.elapse(Duration::from_secs(a.timeout.unwrap_or(30).into()))?;
+        }
+
+        match &a.action {
+            Some(action) => Ok(Flow::Request(self.request_to(
+                &resolve(url, action),
+                a.method.as_deref(),
+                vec![
+                    ("DialCallStatus", status.as_str().to_string()),
+                    ("DialCallSid", sid("CA", self.requests + 1)),
+                ],
+            ))),
+            None => Ok(Flow::Next),
+        }
+    }
+
+    fn pay(&mut self, pay: &Pay, url: &str) -> Result<Flow, Outcome> {
+        let a = &pay.attributes;
+        let timeout = Duration::from_secs(a.timeout.unwrap_or(5).into());
+        let mut params = Vec::new();
+        let card = match self.next_input()? {
+            Some(CallerAction::Press(keys)) => {
+                self.events.push(Event::Pressed(keys.clone()));
+                Some(
+                    keys.chars()
+                        .filter(char::is_ascii_digit)
+                        .collect::<String>(),
+                )
+            }
+            Some(CallerAction::Say(speech)) => {
+                self.events.push(Event::Spoke(speech));
+                None
+            }
+            _ => None,
+        };
+        let result = match card {
+            Some(card) if !card.is_empty() => {
+                let last_four = &card[card.len().saturating_sub(4)..];
+                params.push((
+                    "PaymentCardNumber",
+                    format!("{}{}", "x".repeat(card.len() - last_four.len()), last_four),
+                ));
+                "success"
+            }
+            _ => {
+                self.elapse(timeout * a.max_attempts.unwrap_or(1).max(1))?;
+                "too-many-failed-attempts"
+            }
+        };
+        self.events.push(Event::Paid(result.to_string()));
+        params.insert(0, ("Result", result.to_string()));
+
+        match &a.action {
+            Some(action) => Ok(Flow::Request(self.request_to(
+                &resolve(url, action),
+                a.method.as_deref(),
+                params,
+            ))),
+            None => Ok(Flow::Next),
+        }
+    }
+
+    /// A request to `url` with the call's parameters and `extra`
+    fn request_to(
+        &self,
+        url: &str,
+        method: Option<&str>,
+        extra: Vec<(&str, String)>,
+    ) -> SimulatedRequest {
+        let status = if self.requests == 0 {
+            CallStatus::Ringing
+        } else {
+            CallStatus::InProgress
+        };
+        let mut params = vec![
+            ("CallSid", sid("CA", 0)),
+            ("AccountSid", ACCOUNT_SID.to_string()),
+            ("From", self.simulator.from.clone()),
+            ("To", self.simulator.to.clone()),
+            ("CallStatus", status.as_str().to_string()),
+            ("Direction", "inbound".to_string()),
+            ("ApiVersion", "2010-04-01".to_string()),
+        ];
+        params.extend(extra);
+        SimulatedRequest {
+            url: url.to_string(),
+            method: method.unwrap_or("POST").to_ascii_uppercase(),
+            params: params.into_iter().collect(),
+        }
+    }
+
+    /// Hand `request` to the handler for the next document
+    fn fetch(&mut self, request: SimulatedRequest) -> Result<VoiceResponse, Outcome> {
+        if self.requests >= self.simulator.max_requests {
+            return Err(Outcome::RequestLimit);
+        }
+        self.requests += 1;
+        let document = (self.handler)(&request);
+        let url = request.url.clone();
+        self.events.push(Event::Requested(request));
+        document.ok_or(Outcome::ApplicationError(url))
+    }
+
+    /// The caller's next input, or `None` while waiting to hang up
+    fn next_input(&mut self) -> Result<Option<CallerAction>, Outcome> {
+        match self.caller.front() {
+            None => Err(Outcome::CallerHungUp),
+            Some(CallerAction::HangUpAfter(_)) => Ok(None),
+            Some(_) => {
+                let action = self.caller.pop_front();
+                self.schedule_hang_up();
+                Ok(action)
+            }
+        }
+    }
+
+    /// Start the countdown of a `HangUpAfter` that is next in the script
+    fn schedule_hang_up(&mut self) {
+        if let Some(CallerAction::HangUpAfter(after)) = self.caller.front() {
+            self.hang_up_at = Some(self.clock + *after);
+        }
+    }
+
+    /// `Err` once the caller has hung up
+    fn check_line(&self) -> Result<(), Outcome> {
+        match self.hang_up_at {
+            Some(at) if self.clock >= at => Err(Outcome::CallerHungUp),
+            _ => Ok(()),
+        }
+    }
+
+    /// Let `length` of the call pass, unless the caller hangs up first
+    fn elapse(&mut self, length: Duration) -> Result<(), Outcome> {
+        match self.hang_up_at {
+            Some(at) if self.clock + length > at => {
+                self.clock = at;
+                Err(Outcome::CallerHungUp)
+            }
+            _ => {
+                self.clock += length;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Repetitions of a `loop="0"` `<Say>` or `<Play>` after which the caller
+/// gives up and hangs up
+const MAX_LOOPS: u32 = 1000;
+
+const ACCOUNT_SID: &str = "AC00000000000000000000000000000000";
+
+/// A fake SID with the two-letter `prefix`
+fn sid(prefix: &str, n: usize) -> String {
+    format!("{}{:032x}", prefix, n)
+}
+
+/// The verbs of `verbs`, with fragments spliced in
+fn flatten(verbs: &[VoiceVerb]) -> Vec<&VoiceVerb> {
+    let mut flat = Vec::with_capacity(verbs.len());
+    for verb in verbs {
+        match verb {
+            VoiceVerb::Fragment(fragment) => flat.extend(flatten(&fragment.verbs)),
+            verb => flat.push(verb),
+        }
+    }
+    flat
+}
+
+/// What a `<Say>` speaks
+fn say_text(message: &str, ssml: &[SsmlElement]) -> String {
+    let mut parts = vec![message];
+    parts.extend(ssml.iter().filter_map(|element| match element {
+        SsmlElement::Break { .. } => None,
+        SsmlElement::Sub { alias, .. } => Some(alias.as_str()),
+        SsmlElement::Emphasis { text, .. }
+        | SsmlElement::Lang { text, .. }
+        | SsmlElement::P { text }
+        | SsmlElement::Phoneme { text, .. }
+        | SsmlElement::Prosody { text, .. }
+        | SsmlElement::S { text }
+        | SsmlElement::SayAs { text, .. }
+        | SsmlElement::W { text, .. }
+        | SsmlElement::AmazonEffect { text, .. }
+        | SsmlElement::AmazonDomain { text, .. }
+        | SsmlElement::Text { text } => Some(text.as_str()),
+    }));
+    parts
+        .into_iter()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Speaking time at 150 words per minute
+fn speech_duration(text: &str) -> Duration {
+    Duration::from_millis(400) * text.split_whitespace().count() as u32
+}
+
+/// How the transcript names a `<Dial>` noun
+fn dial_target(noun: &DialNoun) -> String {
+    match noun {
+        DialNoun::Number(number) => number.number.clone(),
+        DialNoun::Client(client) => format!("client:{}", client.identity),
+        DialNoun::Conference(conference) => format!("conference:{}", conference.name),
+        DialNoun::Queue(queue) => format!("queue:{}", queue.name),
+        DialNoun::Sip(sip) => sip.sip_url.clone(),
+        DialNoun::Sim(sim) => format!("sim:{}", sim.sim_sid),
+        DialNoun::Application(application) => format!(
+            "application:{}",
+            application.application_sid.as_deref().unwrap_or_default()
+        ),
+        DialNoun::WhatsApp(whatsapp) => format!("whatsapp:{}", whatsapp.phone_number),
+    }
+}
+
+/// `target` resolved against the document URL `base` as RFC 3986 (section
+/// 5.2) describes, without the fragment, which is never requested
+///
+/// A `base` without scheme and host, such as `/voice`, resolves to paths.
+fn resolve(base: &str, target: &str) -> String {
+    let base = split_url(base);
+    let target = split_url(target);
+
+    let (scheme, authority, path, query) = if target.scheme.is_some() {
+        (
+            target.scheme,
+            target.authority,
+            remove_dot_segments(target.path),
+            target.query,
+        )
+    } else if target.authority.is_some() {
+        (
+            base.scheme,
+            target.authority,
+            remove_dot_segments(target.path),
+            target.query,
+        )
+    } else if target.path.is_empty() {
+        (
+            base.scheme,
+            base.authority,
+            base.path.to_string(),
+            target.query.or(base.query),
+        )
+    } else if target.path.starts_with('/') {
+        (
+            base.scheme,
+            base.authority,
+            remove_dot_segments(target.path),
+            target.query,
+        )
+    } else {
+        let merged = if base.authority.is_some() && base.path.is_empty() {
+            format!("/{}", target.path)
+        } else {
+            let dir = base.path.rfind('/').map_or("", |i| &base.path[..=i]);
+            format!("{}{}", dir, target.path)
+        };
+        (
+            base.scheme,
+            base.authority,
+            remove_dot_segments(&merged),
+            target.query,
+        )
+    };
+
+    let mut url = String::new();
+    if let Some(scheme) = scheme {
+        url.push_str(scheme);
+        url.push(':');
+    }
+    if let Some(authority) = authority {
+        url.push_str("//");
+        url.push_str(authority);
+    }
+    url.push_str(&path);
+    if let Some(query) = query {
+        url.push('?');
+        url.push_str(query);
+    }
+    url
+}
+
+/// The parts of a URL or relative reference, without the fragment
+struct UrlParts<'a> {
+    scheme: Option<&'a str>,
+    authority: Option<&'a str>,
+    path: &'a str,
+    query: Option<&'a str>,
+}
+
+fn split_url(url: &str) -> UrlParts<'_> {
+    let url = url.split('#').next().unwrap_or(url);
+    let (rest, query) = match url.split_once('?') {
+        Some((rest, query)) => (rest, Some(query)),
+        None => (url, None),
+    };
+    // A scheme is letters, digits, `+`, `-` and `.` up to the first `:`,
+    // starting with a letter
+    let (scheme, rest) = match rest.split_once(':') {
+        Some((scheme, rest))
+            if scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.')) =>
+        {
+            (Some(scheme), rest)
+        }
+        _ => (None, rest),
+    };
+    let (authority, path) = match rest.strip_prefix("//") {
+        Some(rest) => {
+            let end = rest.find('/').unwrap_or(rest.len());
+            (Some(&rest[..end]), &rest[end..])
+        }
+        None => (None, rest),
+    };
+    UrlParts {
+        scheme,
+        authority,
+        path,
+        query,
+    }
+}
+
+/// `path` with its `.` and `..` segments applied
+fn remove_dot_segments(path: &str) -> String {
+    let segments: Vec<&str> = path.split('/').collect();
+    let mut output: Vec<&str> = Vec::with_capacity(segments.len());
+    for (i, segment) in segments.iter().enumerate() {
+        let last = i + 1 == segments.len();
+        match *segment {
+            "." | ".." => {
+                // Never above the root: a leading "" stands for it
+                if *segment == ".."
+                    && output
+                        .last()
+                        .is_some_and(|s| !s.is_empty() || output.len() > 1)
+                {
+                    output.pop();
+                }
+                if last {
+                    output.push("");
+                }
+            }
+            segment => output.push(segment),
+        }
+    }
+    output.join("/")
+}
@@ -4,6 +4,11 @@
 //! to Twilio's requirements and will be accepted by Twilio's servers.
 
 use crate::error::{Error, Result};
+use crate::schema;
+use crate::voice::SpeechModel;
+use crate::voice_catalog::Voice;
+use crate::xml_parser::AttributeValue;
+use crate::xml_tokenizer::{Attribute, Position, Spanned, Token, Tokenizer};
 
 /// Validation error details
 #[derive(Debug, Clone, PartialEq)]
@@ -14,6 +19,10 @@ pub struct ValidationError {
     pub message: String,
     /// Optional context (e.g., verb name, attribute name)
     pub context: Option<String>,
+    /// Line (1-based) in the validated XML where the problem was found
+    pub line: Option<usize>,
+    /// Column (1-based) in the validated XML where the problem was found
+    pub column: Option<usize>,
 }
 
 /// Types of validation errors
@@ -48,6 +57,8 @@ impl ValidationError {
             error_type,
             message: message.into(),
             context: None,
+            line: None,
+            column: None,
         }
     }
 
@@ -56,15 +67,30 @@ impl ValidationError {
         self.context = Some(context.into());
         self
     }
+
+    /// Add the line and column (both 1-based) the error was found at
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.line = Some(line);
+        self.column = Some(column);
+        self
+    }
+
+    fn at_position(self, position: Position) -> Self {
+        self.at(position.line, position.column)
+    }
 }
 
 impl std::fmt::Display for ValidationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some(context) = &self.context {
-            write!(f, "[{}] {}: {}", context, self.error_type, self.message)
+            write!(f, "[{}] {}: {}", context, self.error_type, self.message)?;
         } else {
-            write!(f, "{}: {}", self.error_type, self.message)
+            write!(f, "{}: {}", self.error_type, self.message)?;
         }
+        if let (Some(line), Some(column)) = (self.line, self.column) {
+            write!(f, " (line {}, column {})", line, column)?;
+        }
+        Ok(())
     }
 }
 
@@ -89,17 +115,25 @@ impl std::fmt::Display for ValidationErrorType {
 pub struct TwiMLValidator {
     /// Whether to perform strict validation
     strict: bool,
+    /// Whether documents must start with the XML declaration
+    require_declaration: bool,
 }
 
 impl TwiMLValidator {
     /// Create a new validator with default settings
     pub fn new() -> Self {
-        Self { strict: false }
+        Self {
+            strict: false,
+            require_declaration: true,
+        }
     }
 
     /// Create a new validator with strict validation enabled
     pub fn strict() -> Self {
-        Self { strict: true }
+        Self {
+            strict: true,
+            require_declaration: true,
+        }
     }
 
     /// Enable or disable strict validation
@@ -108,275 +142,666 @@ impl TwiMLValidator {
         self
     }
 
+    /// Require or allow a missing `<?xml ...?>` declaration
+    ///
+    /// Required by default. Pass the [`RenderOptions::xml_declaration`] the
+    /// document was rendered with, so output of [`RenderOptions::compact`]
+    /// validates.
+    ///
+    /// [`RenderOptions::xml_declaration`]: crate::render::RenderOptions::xml_declaration
+    /// [`RenderOptions::compact`]: crate::render::RenderOptions::compact
+    pub fn set_require_declaration(mut self, require_declaration: bool) -> Self {
+        self.require_declaration = require_declaration;
+        self
+    }
+
     /// Validate XML well-formedness
     pub fn validate_xml(&self, xml: &str) -> Result<()> {
-        // Check for basic XML structure
-        if !xml.contains("<?xml") {
-            return Err(Error::Validation("XML declaration missing".to_string()));
-        }
+        self.scan(xml)
+            .map(|_| ())
+            .map_err(|e| Error::Validation(e.to_string()))
+    }
 
-        if !xml.contains("<Response>") || !xml.contains("</Response>") {
-            return Err(Error::Validation(
-                "Response element missing or malformed".to_string(),
-            ));
-        }
+    /// Validate a complete TwiML response
+    pub fn validate(&self, xml: &str) -> Result<Vec<ValidationError>> {
+        // If XML is malformed, only that error is reported
+        Ok(self.scan(xml).unwrap_or_else(|e| vec![e]))
+    }
 
-        // Check for balanced tags (basic check)
-        let open_tags = xml.matches('<').count();
-        let close_tags = xml.matches('>').count();
-        if open_tags != close_tags {
-            return Err(Error::Validation("Unbalanced XML tags".to_string()));
-        }
+    /// Walk the token stream once, checking well-formedness and content.
+    ///
+    /// Returns the content errors, or the first well-formedness error.
+    fn scan(&self, xml: &str) -> std::result::Result<Vec<ValidationError>, ValidationError> {
+        let malformed = |message: String, position: Position| {
+            ValidationError::new(ValidationErrorType::MalformedXml, message).at_position(position)
+        };
 
-        // More sophisticated check: ensure all opening tags have closing tags
-        // This is a simple heuristic - count opening tags vs closing tags
-        let mut tag_stack = Vec::new();
-        let mut in_tag = false;
-        let mut tag_name = String::new();
-        let mut is_closing = false;
-        let mut is_self_closing = false;
-        let mut in_attributes = false; // Track if we're in the attributes section
-
-        for ch in xml.chars() {
-            if ch == '<' {
-                in_tag = true;
-                tag_name.clear();
-                is_closing = false;
-                is_self_closing = false;
-                in_attributes = false;
-            } else if ch == '>' && in_tag {
-                in_tag = false;
-
-                // Skip XML declaration, comments, and processing instructions
-                if tag_name.starts_with('?') || tag_name.starts_with('!') {
-                    continue;
+        let mut errors = Vec::new();
+        let mut stack: Vec<OpenElement> = Vec::new();
+        let mut seen_declaration = false;
+        let mut seen_root = false;
+        // The kind of response, told by the first verb that belongs to one
+        let mut kind: Option<(schema::ResponseKind, &str)> = None;
+
+        let mut tokens = Tokenizer::new(xml);
+        for token in tokens.by_ref() {
+            let Spanned { token, position } =
+                token.map_err(|e| malformed(e.message, e.position))?;
+
+            if !seen_declaration {
+                if !matches!(token, Token::Declaration(_)) && self.require_declaration {
+                    return Err(malformed("XML declaration missing".to_string(), position));
                 }
+                seen_declaration = true;
+            }
 
-                // Handle self-closing tags
-                if is_self_closing {
-                    continue;
+            match token {
+                Token::Declaration(_) | Token::ProcessingInstruction { .. } | Token::Comment(_) => {
                 }
-
-                // Extract just the tag name (before any space or attribute)
-                let tag = tag_name.split_whitespace().next().unwrap_or("").trim();
-
-                if is_closing {
-                    // Closing tag - should match the last opening tag
-                    if let Some(last) = tag_stack.pop() {
-                        if last != tag {
-                            return Err(Error::Validation(format!(
-                                "Mismatched closing tag: expected </{}>, found </{}>",
-                                last, tag
-                            )));
+                Token::Doctype(_) => {
+                    return Err(malformed(
+                        "Document type declarations are not allowed in TwiML".to_string(),
+                        position,
+                    ))
+                }
+                Token::StartTag {
+                    name,
+                    attributes,
+                    self_closing,
+                } => {
+                    if let Some(parent) = stack.last() {
+                        let nesting = validate_nesting(&stack, parent.name, name, position);
+                        if nesting.is_none() && stack.len() == 1 {
+                            errors.extend(validate_response_kind(&mut kind, name, position));
                         }
-                    } else {
-                        return Err(Error::Validation(format!(
-                            "Unexpected closing tag: </{}>",
-                            tag
-                        )));
+                        errors.extend(nesting);
                     }
-                } else if !tag.is_empty() {
-                    // Opening tag
-                    tag_stack.push(tag.to_string());
-                }
-            } else if in_tag {
-                if ch == '/' {
-                    if tag_name.is_empty() {
-                        // This is a closing tag like </Say>
-                        is_closing = true;
+                    match stack.last_mut() {
+                        Some(parent) => parent.has_children = true,
+                        None if seen_root => {
+                            return Err(malformed(
+                                format!("Unexpected element <{}> after </Response>", name),
+                                position,
+                            ))
+                        }
+                        None if name != "Response" => {
+                            return Err(malformed(
+                                format!("Root element must be <Response>, found <{}>", name),
+                                position,
+                            ))
+                        }
+                        None => seen_root = true,
+                    }
+
+                    errors.extend(self.validate_attributes(name, &attributes));
+                    errors.extend(self.validate_combinations(name, &attributes));
+
+                    let element = OpenElement {
+                        name,
+                        position,
+                        text: String::new(),
+                        has_children: false,
+                    };
+                    if self_closing {
+                        errors.extend(self.validate_content(&element));
                     } else {
-                        // This might be a self-closing tag like <Hangup />
-                        // or a / in the attributes (like in https://)
-                        // We'll mark it as self-closing and check later
-                        is_self_closing = true;
+                        stack.push(element);
                     }
-                } else if is_self_closing && ch != ' ' {
-                    // If we already marked as self-closing and we see a non-space character,
-                    // it means the / was in the middle of attributes (like https://),
-                    // so reset is_self_closing
-                    is_self_closing = false;
-                } else if ch == ' ' && !in_attributes {
-                    // Space marks the end of the tag name (start of attributes)
-                    in_attributes = true;
-                } else if !in_attributes && !is_self_closing {
-                    // Only add to tag_name if we're not in attributes yet and not self-closing
-                    tag_name.push(ch);
                 }
+                Token::EndTag { name } => match stack.pop() {
+                    Some(open) if open.name == name => {
+                        errors.extend(self.validate_content(&open));
+                    }
+                    Some(open) => {
+                        return Err(malformed(
+                            format!(
+                                "Mismatched closing tag: expected </{}>, found </{}>",
+                                open.name, name
+                            ),
+                            position,
+                        ))
+                    }
+                    None => {
+                        return Err(malformed(
+                            format!("Unexpected closing tag: </{}>", name),
+                            position,
+                        ))
+                    }
+                },
+                Token::Text(text) => match stack.last_mut() {
+                    Some(open) => open.text.push_str(&text),
+                    None if text.trim().is_empty() => {}
+                    None => {
+                        return Err(malformed(
+                            "Text is not allowed outside the <Response> element".to_string(),
+                            position,
+                        ))
+                    }
+                },
+                Token::CData(text) => match stack.last_mut() {
+                    Some(open) => open.text.push_str(text),
+                    None => {
+                        return Err(malformed(
+                            "Text is not allowed outside the <Response> element".to_string(),
+                            position,
+                        ))
+                    }
+                },
             }
         }
 
-        // Check if there are unclosed tags
-        if !tag_stack.is_empty() {
-            return Err(Error::Validation(format!(
-                "Unclosed tags: {}",
-                tag_stack.join(", ")
-            )));
+        let end = tokens.location();
+        if !seen_declaration && self.require_declaration {
+            return Err(malformed("XML declaration missing".to_string(), end));
         }
-
-        Ok(())
-    }
-
-    /// Validate a complete TwiML response
-    pub fn validate(&self, xml: &str) -> Result<Vec<ValidationError>> {
-        let mut errors = Vec::new();
-
-        // Validate XML well-formedness
-        if let Err(e) = self.validate_xml(xml) {
-            errors.push(ValidationError::new(
-                ValidationErrorType::MalformedXml,
-                e.to_string(),
+        if !stack.is_empty() {
+            let names: Vec<&str> = stack.iter().map(|e| e.name).collect();
+            return Err(malformed(
+                format!("Unclosed tags: {}", names.join(", ")),
+                end,
             ));
-            // If XML is malformed, return early
-            return Ok(errors);
         }
-
-        // Validate URLs in the TwiML
-        errors.extend(self.validate_urls(xml));
-
-        // Validate phone numbers in the TwiML
-        errors.extend(self.validate_phone_numbers(xml));
-
-        // Validate content lengths
-        errors.extend(self.validate_content_lengths(xml));
+        if !seen_root {
+            return Err(malformed("Response element missing".to_string(), end));
+        }
 
         Ok(errors)
     }
 
-    /// Validate URLs in TwiML
-    fn validate_urls(&self, xml: &str) -> Vec<ValidationError> {
+    /// Validate attribute values on a start tag
+    fn validate_attributes(&self, element: &str, attributes: &[Attribute]) -> Vec<ValidationError> {
+        attributes
+            .iter()
+            .flat_map(|attribute| {
+                self.check_attribute(element, attribute.name, &attribute.value)
+                    .into_iter()
+                    .map(|e| {
+                        e.with_context(attribute.name)
+                            .at_position(attribute.position)
+                    })
+            })
+            .collect()
+    }
+
+    /// Validate attributes that depend on each other on a start tag
+    fn validate_combinations(
+        &self,
+        element: &str,
+        attributes: &[Attribute],
+    ) -> Vec<ValidationError> {
+        let find = |name: &str| attributes.iter().find(|a| a.name == name);
+        let language = find("language").map(|a| a.value.as_str());
         let mut errors = Vec::new();
 
-        // Common URL attributes to check
-        let url_attributes = [
-            "action=",
-            "url=",
-            "statusCallback=",
-            "recordingStatusCallback=",
-            "transcribeCallback=",
-            "statusCallbackUrl=",
-            "fallbackUrl=",
-        ];
-
-        for attr in &url_attributes {
-            if let Some(start) = xml.find(attr) {
-                let after_attr = &xml[start + attr.len()..];
-                if let Some(quote_start) = after_attr.find('"') {
-                    let url_part = &after_attr[quote_start + 1..];
-                    if let Some(quote_end) = url_part.find('"') {
-                        let url = &url_part[..quote_end];
-
-                        // Basic URL validation
-                        if !url.is_empty()
-                            && !url.starts_with("http://")
-                            && !url.starts_with("https://")
-                            && !url.starts_with('/')
-                            && self.strict
-                        {
-                            errors.push(
-                                ValidationError::new(
-                                    ValidationErrorType::InvalidUrl,
-                                    format!(
-                                        "URL should start with http://, https://, or /: {}",
-                                        url
-                                    ),
-                                )
-                                .with_context(attr.trim_end_matches('=')),
-                            );
-                        }
-                    }
+        match element {
+            "Say" => {
+                if let Some(voice) = find("voice") {
+                    let error = self.check_voice(&Voice::from_attribute(&voice.value), language);
+                    errors.extend(error.map(|e| (voice, e)));
+                }
+            }
+            "Gather" => {
+                let model = find("speechModel");
+                let parsed = model.map(|m| SpeechModel::from_attribute(&m.value));
+                if let Some(model) = model {
+                    let error = self.check_speech_model(parsed.as_ref(), language);
+                    errors.extend(error.map(|e| (model, e)));
+                }
+                if let Some(enhanced) = find("enhanced") {
+                    let error =
+                        self.check_enhanced(Some(enhanced.value == "true"), parsed.as_ref());
+                    errors.extend(error.map(|e| (enhanced, e)));
                 }
             }
+            _ => {}
         }
 
         errors
+            .into_iter()
+            .map(|(attribute, e)| {
+                e.with_context(attribute.name)
+                    .at_position(attribute.position)
+            })
+            .collect()
     }
 
-    /// Validate phone numbers in TwiML
-    fn validate_phone_numbers(&self, xml: &str) -> Vec<ValidationError> {
+    /// Validate phone numbers and content lengths of a closed element
+    fn validate_content(&self, element: &OpenElement) -> Option<ValidationError> {
+        self.check_content(element.name, &element.text, element.has_children)
+            .map(|e| e.with_context(element.name).at_position(element.position))
+    }
+
+    /// Check one attribute value against its schema domain and, in strict
+    /// mode, the URL rules
+    fn check_attribute(&self, element: &str, name: &str, value: &str) -> Vec<ValidationError> {
         let mut errors = Vec::new();
 
-        // Check for Number elements
-        if xml.contains("<Number>") {
-            let parts: Vec<&str> = xml.split("<Number>").collect();
-            for (i, part) in parts.iter().enumerate().skip(1) {
-                if let Some(end) = part.find("</Number>") {
-                    let number = &part[..end];
-
-                    // Basic phone number validation
-                    if !number.is_empty()
-                        && !number.starts_with('+')
-                        && !number.starts_with("client:")
-                        && !number.starts_with("sip:")
-                        && self.strict
-                    {
-                        errors.push(
-                                ValidationError::new(
-                                    ValidationErrorType::InvalidPhoneNumber,
-                                    format!("Phone number should start with + or be a client/sip identifier: {}", number),
-                                )
-                                .with_context(format!("Number element #{}", i)),
-                            );
-                    }
-                }
+        if let Some(domain) = schema::attribute_domain(element, name) {
+            if let Err(reason) = domain.check(value) {
+                let error_type = if domain.is_enum() {
+                    ValidationErrorType::InvalidEnumValue
+                } else {
+                    ValidationErrorType::InvalidAttributeValue
+                };
+                errors.push(ValidationError::new(
+                    error_type,
+                    format!(
+                        "Invalid value '{}' for {} on <{}>: {}",
+                        value, name, element, reason
+                    ),
+                ));
             }
         }
 
+        if self.strict
+            && URL_ATTRIBUTES.contains(&name)
+            && !value.is_empty()
+            && !URL_PREFIXES.iter().any(|prefix| value.starts_with(prefix))
+        {
+            errors.push(ValidationError::new(
+                ValidationErrorType::InvalidUrl,
+                format!(
+                    "URL should start with http://, https://, wss://, or /: {}",
+                    value
+                ),
+            ));
+        }
+
         errors
     }
 
-    /// Validate content lengths
-    fn validate_content_lengths(&self, xml: &str) -> Vec<ValidationError> {
-        let mut errors = Vec::new();
+    /// Check that `voice` exists and speaks `language`
+    fn check_voice(&self, voice: &Voice, language: Option<&str>) -> Option<ValidationError> {
+        if !voice.is_available() {
+            return Some(ValidationError::new(
+                ValidationErrorType::UnsupportedCombination,
+                format!("Voice '{}' is not offered with that engine", voice),
+            ));
+        }
+        match language {
+            Some(language) if voice.supports_language(language) == Some(false) => {
+                Some(ValidationError::new(
+                    ValidationErrorType::UnsupportedCombination,
+                    format!("Voice '{}' does not speak language '{}'", voice, language),
+                ))
+            }
+            _ => None,
+        }
+    }
 
-        // Check Say content length (max 4096 characters for text, more for SSML)
-        if xml.contains("<Say>") {
-            let parts: Vec<&str> = xml.split("<Say>").collect();
-            for (i, part) in parts.iter().enumerate().skip(1) {
-                if let Some(end) = part.find("</Say>") {
-                    let content = &part[..end];
-
-                    // Basic length check (4096 for plain text)
-                    if content.len() > 4096 && !content.contains('<') {
-                        errors.push(
-                            ValidationError::new(
-                                ValidationErrorType::ContentTooLong,
-                                format!(
-                                    "Say content exceeds 4096 characters: {} characters",
-                                    content.len()
-                                ),
-                            )
-                            .with_context(format!("Say element #{}", i)),
-                        );
-                    }
-                }
+    /// Check that a `<Gather>` speech model recognizes `language`
+    fn check_speech_model(
+        &self,
+        model: Option<&SpeechModel>,
+        language: Option<&str>,
+    ) -> Option<ValidationError> {
+        // Twilio recognizes en-US when no language is given
+        let language = language.unwrap_or("en-US");
+        match model {
+            Some(model) if model.supports_language(language) == Some(false) => {
+                Some(ValidationError::new(
+                    ValidationErrorType::UnsupportedCombination,
+                    format!(
+                        "Speech model '{}' does not recognize language '{}'; supported: {}",
+                        model,
+                        language,
+                        model.languages().unwrap_or_default().join(", ")
+                    ),
+                ))
             }
+            _ => None,
+        }
+    }
+
+    /// Check that `enhanced="true"` is used with a model that supports it
+    fn check_enhanced(
+        &self,
+        enhanced: Option<bool>,
+        model: Option<&SpeechModel>,
+    ) -> Option<ValidationError> {
+        if enhanced != Some(true) || model.is_some_and(SpeechModel::supports_enhanced) {
+            return None;
         }
+        Some(ValidationError::new(
+            ValidationErrorType::UnsupportedCombination,
+            format!(
+                "enhanced=\"true\" requires speechModel=\"phone_call\", got {}; Twilio falls back to default recognition",
+                model.map_or("no speechModel".to_string(), |m| format!("'{}'", m))
+            ),
+        ))
+    }
 
-        // Check Message body length (max 1600 characters)
-        if xml.contains("<Body>") {
-            let parts: Vec<&str> = xml.split("<Body>").collect();
-            for (i, part) in parts.iter().enumerate().skip(1) {
-                if let Some(end) = part.find("</Body>") {
-                    let content = &part[..end];
-
-                    if content.len() > 1600 {
-                        errors.push(
-                            ValidationError::new(
-                                ValidationErrorType::ContentTooLong,
-                                format!(
-                                    "Message body exceeds 1600 characters: {} characters",
-                                    content.len()
-                                ),
-                            )
-                            .with_context(format!("Body element #{}", i)),
-                        );
-                    }
+    /// Check the text content of an element (phone numbers and lengths)
+    fn check_content(
+        &self,
+        element: &str,
+        text: &str,
+        has_children: bool,
+    ) -> Option<ValidationError> {
+        match element {
+            "Number" if self.strict => {
+                let number = text.trim();
+                if !number.is_empty()
+                    && !number.starts_with('+')
+                    && !number.starts_with("client:")
+                    && !number.starts_with("sip:")
+                {
+                    return Some(ValidationError::new(
+                        ValidationErrorType::InvalidPhoneNumber,
+                        format!(
+                            "Phone number should start with + or be a client/sip identifier: {}",
+                            number
+                        ),
+                    ));
+                }
+            }
+            // Max 4096 characters for plain text; SSML content is not limited here
+            "Say" if !has_children => {
+                let length = text.chars().count();
+                if length > 4096 {
+                    return Some(ValidationError::new(
+                        ValidationErrorType::ContentTooLong,
+                        format!("Say content exceeds 4096 characters: {} characters", length),
+                    ));
+                }
+            }
+            "Body" => {
+                let length = text.chars().count();
+                if length > 1600 {
+                    return Some(ValidationError::new(
+                        ValidationErrorType::ContentTooLong,
+                        format!(
+                            "Message body exceeds 1600 characters: {} characters",
+                            length
+                        ),
+                    ));
                 }
             }
+            _ => {}
         }
+        None
+    }
+}
 
-        errors
+/// Validation of the typed response models, without rendering XML
+///
+/// Walks the real fields of a response, so errors carry the path of the
+/// offending field as context (e.g. `verbs[2].Dial.nested[0].Number.url`)
+/// instead of a line and column. Nesting needs no checking here: the types
+/// only allow valid nesting.
+pub trait ValidateModel {
+    /// Validate the model with the settings of `validator`
+    fn validate_model_with(&self, validator: &TwiMLValidator) -> Vec<ValidationError>;
+
+    /// Validate the model
+    fn validate_model(&self) -> Vec<ValidationError> {
+        self.validate_model_with(&TwiMLValidator::new())
+    }
+
+    /// Validate the model with strict validation
+    fn validate_model_strict(&self) -> Vec<ValidationError> {
+        self.validate_model_with(&TwiMLValidator::strict())
+    }
+}
+
+/// Collects errors while a model is walked
+pub(crate) struct ModelChecker<'v> {
+    validator: &'v TwiMLValidator,
+    errors: Vec<ValidationError>,
+}
+
+impl<'v> ModelChecker<'v> {
+    pub(crate) fn new(validator: &'v TwiMLValidator) -> Self {
+        Self {
+            validator,
+            errors: Vec::new(),
+        }
     }
+
+    /// Check the fields of the TwiML `element` whose model sits at `path`
+    pub(crate) fn element(
+        &mut self,
+        path: impl Into<String>,
+        element: &'static str,
+    ) -> FieldChecker<'_, 'v> {
+        FieldChecker {
+            checker: self,
+            path: path.into(),
+            element,
+        }
+    }
+
+    pub(crate) fn finish(self) -> Vec<ValidationError> {
+        self.errors
+    }
+}
+
+/// A model field value the checker can look at
+pub(crate) trait FieldValue {
+    /// The value to check, or `None` for a `Custom` attribute value, which
+    /// the caller chose deliberately
+    fn checked(&self) -> Option<&str>;
+}
+
+impl FieldValue for str {
+    fn checked(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl FieldValue for String {
+    fn checked(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl<T: FieldValue + ?Sized> FieldValue for &T {
+    fn checked(&self) -> Option<&str> {
+        (**self).checked()
+    }
+}
+
+/// Checks the fields of one element of the model
+pub(crate) struct FieldChecker<'c, 'v> {
+    checker: &'c mut ModelChecker<'v>,
+    path: String,
+    element: &'static str,
+}
+
+impl FieldChecker<'_, '_> {
+    /// Check `field`, rendered as the TwiML attribute `attribute`
+    pub(crate) fn string(
+        &mut self,
+        field: &str,
+        attribute: &str,
+        value: Option<impl FieldValue>,
+    ) -> &mut Self {
+        if let Some(value) = value.as_ref().and_then(FieldValue::checked) {
+            let errors = self
+                .checker
+                .validator
+                .check_attribute(self.element, attribute, value);
+            self.push(field, errors);
+        }
+        self
+    }
+
+    /// Check a numeric `field`, rendered as the TwiML attribute `attribute`
+    pub(crate) fn number(&mut self, field: &str, attribute: &str, value: Option<u32>) -> &mut Self {
+        self.string(field, attribute, value.map(|v| v.to_string()).as_deref())
+    }
+
+    /// Check a list `field`, rendered space-separated as `attribute`
+    pub(crate) fn list<T: FieldValue>(
+        &mut self,
+        field: &str,
+        attribute: &str,
+        value: Option<&[T]>,
+    ) -> &mut Self {
+        let joined = value.map(|v| {
+            v.iter()
+                .filter_map(FieldValue::checked)
+                .collect::<Vec<_>>()
+                .join(" ")
+        });
+        self.string(field, attribute, joined)
+    }
+
+    /// Check a `voice` field against the `language` it is used with
+    pub(crate) fn voice(
+        &mut self,
+        field: &str,
+        voice: Option<&Voice>,
+        language: Option<&str>,
+    ) -> &mut Self {
+        if let Some(voice) = voice {
+            let errors = self.checker.validator.check_voice(voice, language);
+            self.push(field, errors);
+        }
+        self
+    }
+
+    /// Check a `<Gather>` speech model `field` against the `language` it is
+    /// used with
+    pub(crate) fn speech_model(
+        &mut self,
+        field: &str,
+        model: Option<&SpeechModel>,
+        language: Option<&str>,
+    ) -> &mut Self {
+        let error = self.checker.validator.check_speech_model(model, language);
+        self.push(field, error);
+        self
+    }
+
+    /// Check an `enhanced` `field` against the speech model it is used with
+    pub(crate) fn enhanced(
+        &mut self,
+        field: &str,
+        enhanced: Option<bool>,
+        model: Option<&SpeechModel>,
+    ) -> &mut Self {
+        let error = self.checker.validator.check_enhanced(enhanced, model);
+        self.push(field, error);
+        self
+    }
+
+    /// Check `field` holding the element's text content
+    pub(crate) fn content(&mut self, field: &str, text: &str, has_children: bool) -> &mut Self {
+        let errors = self
+            .checker
+            .validator
+            .check_content(self.element, text, has_children);
+        self.push(field, errors);
+        self
+    }
+
+    /// Report `field` as empty unless `present`, for values the element
+    /// cannot do without
+    pub(crate) fn required(&mut self, field: &str, present: bool, what: &str) -> &mut Self {
+        if !present {
+            let error = ValidationError::new(
+                ValidationErrorType::EmptyRequiredField,
+                format!("<{}> needs {}", self.element, what),
+            );
+            self.push(field, Some(error));
+        }
+        self
+    }
+
+    fn push(&mut self, field: &str, errors: impl IntoIterator<Item = ValidationError>) {
+        for error in errors {
+            let context = format!("{}.{}", self.path, field);
+            self.checker.errors.push(error.with_context(context));
+        }
+    }
+}
+
+/// Check that `name` may appear inside `parent` according to the schema
+fn validate_nesting(
+    stack: &[OpenElement],
+    parent: &str,
+    name: &str,
+    position: Position,
+) -> Option<ValidationError> {
+    if schema::is_allowed_child(parent, name) {
+        return None;
+    }
+
+    let mut path: Vec<&str> = stack.iter().map(|e| e.name).collect();
+    path.push(name);
+
+    let parents = schema::allowed_parents(name);
+    let message = if name == "Response" {
+        "<Response> is only allowed as the root element".to_string()
+    } else if parents.is_empty() {
+        format!("<{}> is not a TwiML element", name)
+    } else {
+        format!(
+            "<{}> is not allowed inside <{}> (allowed inside: {})",
+            name,
+            parent,
+            parents.join(", ")
+        )
+    };
+
+    Some(
+        ValidationError::new(ValidationErrorType::InvalidNesting, message)
+            .with_context(path.join("/"))
+            .at_position(position),
+    )
+}
+
+/// Check that the top-level verb `name` belongs to the same kind of response
+/// as the verbs before it
+fn validate_response_kind<'a>(
+    kind: &mut Option<(schema::ResponseKind, &'a str)>,
+    name: &'a str,
+    position: Position,
+) -> Option<ValidationError> {
+    match *kind {
+        Some((kind, _)) if kind.verbs().contains(&name) => None,
+        Some((kind, first)) => Some(
+            ValidationError::new(
+                ValidationErrorType::InvalidNesting,
+                format!(
+                    "<{}> is not allowed in a {} response (started by <{}>)",
+                    name,
+                    kind.as_str(),
+                    first
+                ),
+            )
+            .with_context(format!("Response/{}", name))
+            .at_position(position),
+        ),
+        None => {
+            *kind = schema::ResponseKind::of_verb(name).map(|kind| (kind, name));
+            None
+        }
+    }
+}
+
+/// Attributes whose values must be URLs
+const URL_ATTRIBUTES: &[&str] = &[
+    "action",
+    "url",
+    "statusCallback",
+    "recordingStatusCallback",
+    "transcribeCallback",
+    "statusCallbackUrl",
+    "fallbackUrl",
+];
+
+/// Accepted URL forms: absolute HTTP(S), WebSocket (for media streams) or relative
+const URL_PREFIXES: &[&str] = &["http://", "https://", "wss://", "ws://", "/"];
+
+/// An element whose start tag has been seen but not its end tag
+struct OpenElement<'a> {
+    name: &'a str,
+    position: Position,
+    /// Character data directly inside the element
+    text: String,
+    has_children: bool,
 }
 
 impl Default for TwiMLValidator {
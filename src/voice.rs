@@ -23,10 +23,10 @@
 //! ## Interactive Voice Response (IVR)
 //!
 //! ```rust
-//! use twiml_rust::{VoiceResponse, voice::{Gather, Say}, TwiML};
+//! use twiml_rust::{VoiceResponse, voice::{Gather, GatherInput, Say}, TwiML};
 //!
 //! let gather = Gather::new()
-//!     .input(vec!["dtmf".to_string(), "speech".to_string()])
+//!     .input(vec![GatherInput::Dtmf, GatherInput::Speech])
 //!     .action("https://example.com/process")
 //!     .timeout(10)
 //!     .add_say(Say::new("Press 1 for sales, 2 for support"));
@@ -55,344 +55,1246 @@
 //! println!("{}", response.to_xml());
 //! ```
 
-use crate::xml_escape::{escape_xml_attr, escape_xml_text};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::error::{Error, Result};
+use crate::render::{RenderOptions, SelfClosing};
+use crate::serde_support::serde_attribute_value;
+use crate::validation::{FieldValue, ModelChecker, TwiMLValidator, ValidateModel, ValidationError};
+use crate::xml_escape::{escape_xml_attr, escape_xml_text, write_xml_text};
+use crate::xml_parser::{child_path, parse_document, AttributeValue, Attributes, Element, Node};
 use crate::TwiML;
 
+use crate::voice_catalog::language_in;
+pub use crate::voice_catalog::{GoogleVoiceModel, PollyEngine, PollyVoice, Voice};
+
 // ===================================
-// Type Aliases
+// Attribute Values
 // ===================================
 
-pub type ApplicationEvent = &'static str; // "initiated" | "ringing" | "answered" | "completed"
-pub type ClientEvent = &'static str; // "initiated" | "ringing" | "answered" | "completed"
-pub type ConferenceBeep = &'static str; // "true" | "false" | "onEnter" | "onExit"
-pub type ConferenceEvent = &'static str; // "start" | "end" | "join" | "leave" | "mute" | "hold" | "modify" | "speaker" | "announcement"
-pub type ConferenceJitterBufferSize = &'static str; // "large" | "medium" | "small" | "off"
-pub type ConferenceRecord = &'static str; // "do-not-record" | "record-from-start"
-pub type ConferenceRecordingEvent = &'static str; // "in-progress" | "completed" | "absent"
-pub type ConferenceRegion = &'static str; // "us1" | "us2" | "ie1" | "sg1" | "br1" | "au1" | "jp1" | "de1"
-pub type ConferenceTrim = &'static str; // "trim-silence" | "do-not-trim"
-pub type ConversationEvent = &'static str; // "call-initiated" | "call-ringing" | "call-answered" | "call-completed"
-pub type ConversationRecord = &'static str; // "do-not-record" | "record-from-answer" | "record-from-ringing" | "record-from-answer-dual" | "record-from-ringing-dual" | "true" | "false"
-pub type ConversationRecordingEvent = &'static str; // "in-progress" | "completed" | "absent"
-pub type ConversationTrim = &'static str; // "trim-silence" | "do-not-trim"
-pub type DialEvents = &'static str; // "call-progress-event"
-pub type DialRecord = &'static str; // "do-not-record" | "record-from-answer" | "record-from-ringing" | "record-from-answer-dual" | "record-from-ringing-dual"
-pub type DialRecordingEvent = &'static str; // "in-progress" | "completed" | "absent"
-pub type DialRecordingTrack = &'static str; // "both" | "inbound" | "outbound"
-pub type DialRingTone = &'static str; // "at" | "au" | "bg" | "br" | "be" | "ch" | "cl" | "cn" | "cz" | "de" | "dk" | "ee" | "es" | "fi" | "fr" | "gr" | "hu" | "il" | "in" | "it" | "lt" | "jp" | "mx" | "my" | "nl" | "no" | "nz" | "ph" | "pl" | "pt" | "ru" | "se" | "sg" | "th" | "uk" | "us" | "us-old" | "tw" | "ve" | "za"
-pub type DialTrim = &'static str; // "trim-silence" | "do-not-trim"
-pub type GatherInput = &'static str; // "dtmf" | "speech"
+/// Declares an enum for an attribute with a fixed set of values.
+///
+/// Every enum gets `as_str`, a strict `FromStr` that rejects unknown values
+/// and a `Custom(String)` variant for values Twilio adds later. Documents
+/// loaded with `from_xml` keep unknown values in `Custom` so they survive a
+/// round trip.
+macro_rules! attribute_values {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident ($attribute:literal) {
+            $($variant:ident => $value:literal,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                #[doc = concat!("`", $value, "`")]
+                $variant,
+            )+
+            /// Any other value, rendered as-is
+            ///
+            /// Model validation takes it on trust; validating the rendered
+            /// XML still reports it.
+            Custom(String),
+        }
+
+        impl $name {
+            /// Every value with a variant of its own
+            pub const VALUES: &'static [&'static str] = &[$($value),+];
+
+            /// The TwiML attribute value
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)+
+                    Self::Custom(value) => value,
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self> {
+                match s {
+                    $($value => Ok(Self::$variant),)+
+                    _ => Err(Error::invalid_parameter(
+                        $attribute,
+                        format!(
+                            "expected one of: {}; got '{}' (use {}::Custom for other values)",
+                            Self::VALUES.join(", "),
+                            s,
+                            stringify!($name)
+                        ),
+                    )),
+                }
+            }
+        }
+
+        impl AttributeValue for $name {
+            fn from_attribute(value: &str) -> Self {
+                value
+                    .parse()
+                    .unwrap_or_else(|_| Self::Custom(value.to_string()))
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl FieldValue for $name {
+            fn checked(&self) -> Option<&str> {
+                match self {
+                    Self::Custom(_) => None,
+                    _ => Some(self.as_str()),
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        serde_attribute_value!($name);
+    };
+}
+
+attribute_values! {
+    /// HTTP method used for action and callback URLs
+    ///
+    /// The `method` builders take `impl Into<String>`, so either a
+    /// `HttpMethod` or a plain string works there.
+    pub enum HttpMethod ("method") {
+        Get => "GET",
+        Post => "POST",
+    }
+}
+
+impl From<HttpMethod> for String {
+    fn from(method: HttpMethod) -> Self {
+        method.as_str().to_string()
+    }
+}
+
+attribute_values! {
+    /// Call progress events for `statusCallbackEvent` on the `<Dial>` nouns
+    pub enum CallStatusEvent ("statusCallbackEvent") {
+        Initiated => "initiated",
+        Ringing => "ringing",
+        Answered => "answered",
+        Completed => "completed",
+    }
+}
+
+attribute_values! {
+    /// `beep` on `<Conference>`
+    pub enum ConferenceBeep ("beep") {
+        True => "true",
+        False => "false",
+        OnEnter => "onEnter",
+        OnExit => "onExit",
+    }
+}
+
+attribute_values! {
+    /// `statusCallbackEvent` on `<Conference>`
+    pub enum ConferenceEvent ("statusCallbackEvent") {
+        Start => "start",
+        End => "end",
+        Join => "join",
+        Leave => "leave",
+        Mute => "mute",
+        Hold => "hold",
+        Modify => "modify",
+        Speaker => "speaker",
+        Announcement => "announcement",
+    }
+}
+
+attribute_values! {
+    /// `jitterBufferSize` on `<Conference>`
+    pub enum ConferenceJitterBufferSize ("jitterBufferSize") {
+        Large => "large",
+        Medium => "medium",
+        Small => "small",
+        Off => "off",
+    }
+}
+
+attribute_values! {
+    /// `record` on `<Conference>`
+    pub enum ConferenceRecord ("record") {
+        DoNotRecord => "do-not-record",
+        RecordFromStart => "record-from-start",
+    }
+}
+
+attribute_values! {
+    /// `region` on `<Conference>`
+    pub enum ConferenceRegion ("region") {
+        Us1 => "us1",
+        Us2 => "us2",
+        Ie1 => "ie1",
+        Sg1 => "sg1",
+        Br1 => "br1",
+        Au1 => "au1",
+        Jp1 => "jp1",
+        De1 => "de1",
+    }
+}
+
+attribute_values! {
+    /// Call events reported for a conversation
+    pub enum ConversationEvent ("statusCallbackEvent") {
+        CallInitiated => "call-initiated",
+        CallRinging => "call-ringing",
+        CallAnswered => "call-answered",
+        CallCompleted => "call-completed",
+    }
+}
+
+attribute_values! {
+    /// Recording mode for a conversation
+    pub enum ConversationRecord ("record") {
+        DoNotRecord => "do-not-record",
+        RecordFromAnswer => "record-from-answer",
+        RecordFromRinging => "record-from-ringing",
+        RecordFromAnswerDual => "record-from-answer-dual",
+        RecordFromRingingDual => "record-from-ringing-dual",
+        True => "true",
+        False => "false",
+    }
+}
+
+attribute_values! {
+    /// `events` on `<Dial>`
+    pub enum DialEvents ("events") {
+        CallProgressEvent => "call-progress-event",
+    }
+}
+
+attribute_values! {
+    /// `record` on `<Dial>`
+    pub enum DialRecord ("record") {
+        DoNotRecord => "do-not-record",
+        RecordFromAnswer => "record-from-answer",
+        RecordFromRinging => "record-from-ringing",
+        RecordFromAnswerDual => "record-from-answer-dual",
+        RecordFromRingingDual => "record-from-ringing-dual",
+    }
+}
+
+attribute_values! {
+    /// `ringTone` on `<Dial>`, a country code
+    pub enum DialRingTone ("ringTone") {
+        At => "at",
+        Au => "au",
+        Bg => "bg",
+        Br => "br",
+        Be => "be",
+        Ch => "ch",
+        Cl => "cl",
+        Cn => "cn",
+        Cz => "cz",
+        De => "de",
+        Dk => "dk",
+        Ee => "ee",
+        Es => "es",
+        Fi => "fi",
+        Fr => "fr",
+        Gr => "gr",
+        Hu => "hu",
+        Il => "il",
+        In => "in",
+        It => "it",
+        Lt => "lt",
+        Jp => "jp",
+        Mx => "mx",
+        My => "my",
+        Nl => "nl",
+        No => "no",
+        Nz => "nz",
+        Ph => "ph",
+        Pl => "pl",
+        Pt => "pt",
+        Ru => "ru",
+        Se => "se",
+        Sg => "sg",
+        Th => "th",
+        Uk => "uk",
+        Us => "us",
+        UsOld => "us-old",
+        Tw => "tw",
+        Ve => "ve",
+        Za => "za",
+    }
+}
+
+attribute_values! {
+    /// `input` on `<Gather>`
+    pub enum GatherInput ("input") {
+        Dtmf => "dtmf",
+        Speech => "speech",
+    }
+}
+
+attribute_values! {
+    /// `speechModel` on `<Gather>`
+    pub enum SpeechModel ("speechModel") {
+        Default => "default",
+        NumbersAndCommands => "numbers_and_commands",
+        PhoneCall => "phone_call",
+        ExperimentalConversations => "experimental_conversations",
+        ExperimentalUtterances => "experimental_utterances",
+        DeepgramNova2 => "deepgram_nova-2",
+        DeepgramNova3 => "deepgram_nova-3",
+        GoogleV2Long => "googlev2_long",
+        GoogleV2Short => "googlev2_short",
+        GoogleV2Telephony => "googlev2_telephony",
+        GoogleV2TelephonyShort => "googlev2_telephony_short",
+    }
+}
+
+/// Languages of the enhanced `phone_call` model
+const PHONE_CALL_LANGUAGES: &[&str] = &[
+    "en-AU", "en-CA", "en-GB", "en-IN", "en-US", "es-ES", "es-US", "fr-CA", "fr-FR", "de-DE",
+    "it-IT", "ja-JP", "ko-KR", "pt-BR", "ru-RU",
+];
+
+/// Languages of `numbers_and_commands`
+const NUMBERS_AND_COMMANDS_LANGUAGES: &[&str] = &[
+    "da-DK", "de-DE", "en-AU", "en-CA", "en-GB", "en-IE", "en-IN", "en-NZ", "en-PH", "en-SG",
+    "en-US", "en-ZA", "es-ES", "es-MX", "es-US", "fi-FI", "fr-CA", "fr-FR", "hi-IN", "it-IT",
+    "ja-JP", "ko-KR", "nb-NO", "nl-NL", "pl-PL", "pt-BR", "pt-PT", "ru-RU", "sv-SE", "tr-TR",
+];
+
+/// Languages of the Google STT V2 `long` and `short` models, and of the
+/// experimental models built on them
+const GOOGLE_V2_LANGUAGES: &[&str] = &[
+    "af-ZA",
+    "am-ET",
+    "ar-AE",
+    "ar-EG",
+    "ar-SA",
+    "bg-BG",
+    "bn-BD",
+    "ca-ES",
+    "cmn-Hans-CN",
+    "cmn-Hant-TW",
+    "cs-CZ",
+    "da-DK",
+    "de-DE",
+    "el-GR",
+    "en-AU",
+    "en-CA",
+    "en-GB",
+    "en-IE",
+    "en-IN",
+    "en-NZ",
+    "en-PH",
+    "en-SG",
+    "en-US",
+    "en-ZA",
+    "es-ES",
+    "es-MX",
+    "es-US",
+    "et-EE",
+    "eu-ES",
+    "fa-IR",
+    "fi-FI",
+    "fil-PH",
+    "fr-CA",
+    "fr-FR",
+    "gl-ES",
+    "gu-IN",
+    "he-IL",
+    "hi-IN",
+    "hr-HR",
+    "hu-HU",
+    "hy-AM",
+    "id-ID",
+    "is-IS",
+    "it-IT",
+    "ja-JP",
+    "ka-GE",
+    "kk-KZ",
+    "km-KH",
+    "kn-IN",
+    "ko-KR",
+    "lo-LA",
+    "lt-LT",
+    "lv-LV",
+    "mk-MK",
+    "ml-IN",
+    "mn-MN",
+    "mr-IN",
+    "ms-MY",
+    "my-MM",
+    "nb-NO",
+    "ne-NP",
+    "nl-NL",
+    "pl-PL",
+    "pt-BR",
+    "pt-PT",
+    "ro-RO",
+    "ru-RU",
+    "sk-SK",
+    "sl-SI",
+    "sq-AL",
+    "sr-RS",
+    "sv-SE",
+    "sw-KE",
+    "ta-IN",
+    "te-IN",
+    "th-TH",
+    "tr-TR",
+    "uk-UA",
+    "ur-PK",
+    "uz-UZ",
+    "vi-VN",
+    "yue-Hant-HK",
+    "zu-ZA",
+];
+
+/// Languages of the Google STT V2 `telephony` and `telephony_short` models
+const GOOGLE_V2_TELEPHONY_LANGUAGES: &[&str] = &[
+    "ar-EG",
+    "ar-SA",
+    "cmn-Hans-CN",
+    "cs-CZ",
+    "da-DK",
+    "de-DE",
+    "en-AU",
+    "en-GB",
+    "en-IN",
+    "en-US",
+    "es-ES",
+    "es-US",
+    "fi-FI",
+    "fil-PH",
+    "fr-CA",
+    "fr-FR",
+    "hi-IN",
+    "id-ID",
+    "it-IT",
+    "ja-JP",
+    "ko-KR",
+    "nb-NO",
+    "nl-NL",
+    "pl-PL",
+    "pt-BR",
+    "pt-PT",
+    "ro-RO",
+    "ru-RU",
+    "sv-SE",
+    "th-TH",
+    "tr-TR",
+    "uk-UA",
+    "vi-VN",
+];
+
+/// Languages of Deepgram Nova-2; a bare language covers all its regions
+const DEEPGRAM_NOVA_2_LANGUAGES: &[&str] = &[
+    "bg", "ca", "cs", "da", "de", "el", "en", "es", "et", "fi", "fr", "hi", "hu", "id", "it", "ja",
+    "ko", "lt", "lv", "ms", "nl", "no", "pl", "pt", "ro", "ru", "sk", "sv", "th", "tr", "uk", "vi",
+    "zh",
+];
+
+/// Languages of Deepgram Nova-3
+const DEEPGRAM_NOVA_3_LANGUAGES: &[&str] = &["en", "en-US", "en-AU", "en-GB", "en-IN", "en-NZ"];
+
+impl SpeechModel {
+    /// Languages the model recognizes, or `None` if it is not restricted
+    /// (`default` takes every language Twilio supports) or unknown, for
+    /// `Custom`
+    pub fn languages(&self) -> Option<&'static [&'static str]> {
+        match self {
+            Self::Default | Self::Custom(_) => None,
+            Self::NumbersAndCommands => Some(NUMBERS_AND_COMMANDS_LANGUAGES),
+            Self::PhoneCall => Some(PHONE_CALL_LANGUAGES),
+            Self::ExperimentalConversations
+            | Self::ExperimentalUtterances
+            | Self::GoogleV2Long
+            | Self::GoogleV2Short => Some(GOOGLE_V2_LANGUAGES),
+            Self::GoogleV2Telephony | Self::GoogleV2TelephonyShort => {
+                Some(GOOGLE_V2_TELEPHONY_LANGUAGES)
+            }
+            Self::DeepgramNova2 => Some(DEEPGRAM_NOVA_2_LANGUAGES),
+            Self::DeepgramNova3 => Some(DEEPGRAM_NOVA_3_LANGUAGES),
+        }
+    }
+
+    /// Whether the model recognizes `language`, or `None` if unknown
+    pub fn supports_language(&self, language: &str) -> Option<bool> {
+        self.languages()
+            .map(|languages| language_in(languages, language))
+    }
+
+    /// Whether `enhanced="true"` has any effect with this model
+    pub fn supports_enhanced(&self) -> bool {
+        matches!(self, Self::PhoneCall)
+    }
+}
+
+attribute_values! {
+    /// `bankAccountType` on `<Pay>`
+    pub enum PayBankAccountType ("bankAccountType") {
+        ConsumerChecking => "consumer-checking",
+        ConsumerSavings => "consumer-savings",
+        CommercialChecking => "commercial-checking",
+        CommercialSavings => "commercial-savings",
+    }
+}
+
+attribute_values! {
+    /// `input` on `<Pay>`
+    pub enum PayInput ("input") {
+        Dtmf => "dtmf",
+    }
+}
+
+attribute_values! {
+    /// `language` on `<Pay>`
+    pub enum PayLanguage ("language") {
+        DeDe => "de-DE",
+        EnAu => "en-AU",
+        EnCa => "en-CA",
+        EnGb => "en-GB",
+        EnIn => "en-IN",
+        EnIe => "en-IE",
+        EnNz => "en-NZ",
+        EnPh => "en-PH",
+        EnZa => "en-ZA",
+        EnUs => "en-US",
+        EsEs => "es-ES",
+        EsUs => "es-US",
+        FrCa => "fr-CA",
+        FrFr => "fr-FR",
+        ItIt => "it-IT",
+    }
+}
+
+attribute_values! {
+    /// `paymentMethod` on `<Pay>`
+    pub enum PayPaymentMethod ("paymentMethod") {
+        AchDebit => "ach-debit",
+        CreditCard => "credit-card",
+    }
+}
+
+attribute_values! {
+    /// `tokenType` on `<Pay>`
+    pub enum PayTokenType ("tokenType") {
+        OneTime => "one-time",
+        Reusable => "reusable",
+        PaymentMethod => "payment-method",
+    }
+}
+
+attribute_values! {
+    /// Card brands for `validCardTypes` on `<Pay>` and `cardType` on `<Prompt>`
+    pub enum CardType ("cardType") {
+        Visa => "visa",
+        Mastercard => "mastercard",
+        Amex => "amex",
+        Maestro => "maestro",
+        Discover => "discover",
+        Optima => "optima",
+        Jcb => "jcb",
+        DinersClub => "diners-club",
+        Enroute => "enroute",
+    }
+}
+
+attribute_values! {
+    /// `errorType` on `<Prompt>`
+    pub enum PromptErrorType ("errorType") {
+        Timeout => "timeout",
+        InvalidCardNumber => "invalid-card-number",
+        InvalidCardType => "invalid-card-type",
+        InvalidDate => "invalid-date",
+        InvalidSecurityCode => "invalid-security-code",
+        InternalError => "internal-error",
+        InputMatchingFailed => "input-matching-failed",
+    }
+}
+
+attribute_values! {
+    /// `for` on `<Prompt>`
+    pub enum PromptFor ("for") {
+        PaymentCardNumber => "payment-card-number",
+        ExpirationDate => "expiration-date",
+        SecurityCode => "security-code",
+        PostalCode => "postal-code",
+        PaymentProcessing => "payment-processing",
+        BankAccountNumber => "bank-account-number",
+        BankRoutingNumber => "bank-routing-number",
+    }
+}
+
+attribute_values! {
+    /// Recording status events for `recordingStatusCallbackEvent`
+    pub enum RecordingEvent ("recordingStatusCallbackEvent") {
+        InProgress => "in-progress",
+        Completed => "completed",
+        Absent => "absent",
+    }
+}
+
+attribute_values! {
+    /// Silence trimming for `trim` on `<Dial>`, `<Conference>`, `<Record>`
+    /// and `<Recording>`
+    pub enum Trim ("trim") {
+        TrimSilence => "trim-silence",
+        DoNotTrim => "do-not-trim",
+    }
+}
+
+attribute_values! {
+    /// Which legs of the call to record, for `recordingTrack` on `<Dial>` and
+    /// `<Record>` and `track` on `<Recording>`
+    pub enum RecordingTrack ("recordingTrack") {
+        Inbound => "inbound",
+        Outbound => "outbound",
+        Both => "both",
+    }
+}
+
+attribute_values! {
+    /// `recordingChannels` on `<Record>` and `channels` on `<Recording>`
+    pub enum RecordingChannels ("recordingChannels") {
+        Mono => "mono",
+        Dual => "dual",
+    }
+}
+
+attribute_values! {
+    /// `reason` on `<Reject>`
+    pub enum RejectReason ("reason") {
+        Rejected => "rejected",
+        Busy => "busy",
+    }
+}
+
+attribute_values! {
+    /// Media track for `track` on `<Stream>`, `<Siprec>` and `<Transcription>`
+    pub enum MediaTrack ("track") {
+        InboundTrack => "inbound_track",
+        OutboundTrack => "outbound_track",
+        BothTracks => "both_tracks",
+    }
+}
+
+attribute_values! {
+    /// `strength` on SSML `<break>`
+    pub enum SsmlBreakStrength ("strength") {
+        None => "none",
+        XWeak => "x-weak",
+        Weak => "weak",
+        Medium => "medium",
+        Strong => "strong",
+        XStrong => "x-strong",
+    }
+}
+
+attribute_values! {
+    /// `level` on SSML `<emphasis>`
+    pub enum SsmlEmphasisLevel ("level") {
+        Strong => "strong",
+        Moderate => "moderate",
+        Reduced => "reduced",
+    }
+}
+
+attribute_values! {
+    /// `alphabet` on SSML `<phoneme>`
+    pub enum SsmlPhonemeAlphabet ("alphabet") {
+        Ipa => "ipa",
+        XSampa => "x-sampa",
+        XAmazonJyutping => "x-amazon-jyutping",
+        XAmazonPinyin => "x-amazon-pinyin",
+        XAmazonPronKana => "x-amazon-pron-kana",
+        XAmazonYomigana => "x-amazon-yomigana",
+    }
+}
+
+attribute_values! {
+    /// `format` on SSML `<say-as>`
+    pub enum SsmlSayAsFormat ("format") {
+        Mdy => "mdy",
+        Dmy => "dmy",
+        Ymd => "ymd",
+        Md => "md",
+        Dm => "dm",
+        Ym => "ym",
+        My => "my",
+        D => "d",
+        M => "m",
+        Y => "y",
+        Yyyymmdd => "yyyymmdd",
+    }
+}
+
+attribute_values! {
+    /// `interpret-as` on SSML `<say-as>`
+    pub enum SsmlSayAsInterpretAs ("interpret-as") {
+        Characters => "characters",
+        SpellOut => "spell-out",
+        Cardinal => "cardinal",
+        Number => "number",
+        Ordinal => "ordinal",
+        Digits => "digits",
+        Fraction => "fraction",
+        Unit => "unit",
+        Date => "date",
+        Time => "time",
+        Address => "address",
+        Expletive => "expletive",
+        Telephone => "telephone",
+    }
+}
+
+// Per-element names for the shared value sets above
+pub type ApplicationEvent = CallStatusEvent;
+pub type ClientEvent = CallStatusEvent;
+pub type NumberEvent = CallStatusEvent;
+pub type SipEvent = CallStatusEvent;
+pub type WhatsAppEvent = CallStatusEvent;
+pub type ConferenceRecordingEvent = RecordingEvent;
+pub type ConversationRecordingEvent = RecordingEvent;
+pub type DialRecordingEvent = RecordingEvent;
+pub type RecordRecordingEvent = RecordingEvent;
+pub type ConferenceTrim = Trim;
+pub type ConversationTrim = Trim;
+pub type DialTrim = Trim;
+pub type RecordTrim = Trim;
+pub type RecordingTrim = Trim;
+pub type DialRecordingTrack = RecordingTrack;
+pub type PayValidCardTypes = CardType;
+pub type PromptCardType = CardType;
+pub type SiprecTrack = MediaTrack;
+pub type StreamTrack = MediaTrack;
+pub type TranscriptionTrack = MediaTrack;
+pub type PayStatusCallbackMethod = HttpMethod;
+pub type RecordingRecordingStatusCallbackMethod = HttpMethod;
+pub type SiprecStatusCallbackMethod = HttpMethod;
+pub type StreamStatusCallbackMethod = HttpMethod;
+pub type TranscriptionStatusCallbackMethod = HttpMethod;
+
+// Open-ended values, kept as strings
 pub type GatherLanguage = &'static str; // "af-ZA" | "am-ET" | "ar-AE" | ... (many language codes)
-pub type NumberEvent = &'static str; // "initiated" | "ringing" | "answered" | "completed"
-pub type PayBankAccountType = &'static str; // "consumer-checking" | "consumer-savings" | "commercial-checking" | "commercial-savings"
-pub type PayInput = &'static str; // "dtmf"
-pub type PayLanguage = &'static str; // "de-DE" | "en-AU" | "en-CA" | "en-GB" | "en-IN" | "en-IE" | "en-NZ" | "en-PH" | "en-ZA" | "en-US" | "es-ES" | "es-US" | "fr-CA" | "fr-FR" | "it-IT"
-pub type PayPaymentMethod = &'static str; // "ach-debit" | "credit-card"
-pub type PayStatusCallbackMethod = &'static str; // "GET" | "POST"
-pub type PayTokenType = &'static str; // "one-time" | "reusable" | "payment-method"
-pub type PayValidCardTypes = &'static str; // "visa" | "mastercard" | "amex" | "maestro" | "discover" | "optima" | "jcb" | "diners-club" | "enroute"
-pub type PromptCardType = &'static str; // "visa" | "mastercard" | "amex" | "maestro" | "discover" | "optima" | "jcb" | "diners-club" | "enroute"
-pub type PromptErrorType = &'static str; // "timeout" | "invalid-card-number" | "invalid-card-type" | "invalid-date" | "invalid-security-code" | "internal-error" | "input-matching-failed"
-pub type PromptFor = &'static str; // "payment-card-number" | "expiration-date" | "security-code" | "postal-code" | "payment-processing" | "bank-account-number" | "bank-routing-number"
-pub type RecordRecordingEvent = &'static str; // "in-progress" | "completed" | "absent"
-pub type RecordTrim = &'static str; // "trim-silence" | "do-not-trim"
-pub type RecordingChannels = &'static str; // "mono" | "dual"
-pub type RecordingEvent = &'static str; // "in-progress" | "completed" | "absent"
-pub type RecordingRecordingStatusCallbackMethod = &'static str; // "GET" | "POST"
-pub type RecordingTrack = &'static str; // "inbound" | "outbound" | "both"
-pub type RecordingTrim = &'static str; // "trim-silence" | "do-not-trim"
-pub type RejectReason = &'static str; // "rejected" | "busy"
 pub type SayLanguage = &'static str; // "af-ZA" | "am-ET" | "ar-AE" | ... (many language codes)
-pub type SayVoice = &'static str; // "man" | "woman" | "alice" | "Google.*" | "Polly.*" | ... (many voices)
-pub type SipEvent = &'static str; // "initiated" | "ringing" | "answered" | "completed"
-pub type SiprecStatusCallbackMethod = &'static str; // "GET" | "POST"
-pub type SiprecTrack = &'static str; // "inbound_track" | "outbound_track" | "both_tracks"
-pub type SsmlBreakStrength = &'static str; // "none" | "x-weak" | "weak" | "medium" | "strong" | "x-strong"
-pub type SsmlEmphasisLevel = &'static str; // "strong" | "moderate" | "reduced"
 pub type SsmlLangXmlLang = &'static str; // "arb" | "ar-AE" | "ca-ES" | ... (many language codes)
-pub type SsmlPhonemeAlphabet = &'static str; // "ipa" | "x-sampa" | "x-amazon-jyutping" | "x-amazon-pinyin" | "x-amazon-pron-kana" | "x-amazon-yomigana"
-pub type SsmlSayAsFormat = &'static str; // "mdy" | "dmy" | "ymd" | "md" | "dm" | "ym" | "my" | "d" | "m" | "y" | "yyyymmdd"
-pub type SsmlSayAsInterpretAs = &'static str; // "characters" | "spell-out" | "cardinal" | "number" | "ordinal" | "digits" | "fraction" | "unit" | "date" | "time" | "address" | "expletive" | "telephone"
-pub type StreamStatusCallbackMethod = &'static str; // "GET" | "POST"
-pub type StreamTrack = &'static str; // "inbound_track" | "outbound_track" | "both_tracks"
-pub type TranscriptionStatusCallbackMethod = &'static str; // "GET" | "POST"
-pub type TranscriptionTrack = &'static str; // "inbound_track" | "outbound_track" | "both_tracks"
-pub type WhatsAppEvent = &'static str; // "initiated" | "ringing" | "answered" | "completed"
 
 // ================================================
 // Attribute Structs
 // ================================================
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct ConnectAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub action: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct DialAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub action: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub answer_on_bridge: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub caller_id: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub call_reason: Option<String>,
-    pub events: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub events: Option<DialEvents>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub hangup_on_star: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
-    pub record: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub record: Option<DialRecord>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub recording_status_callback: Option<String>,
-    pub recording_status_callback_event: Option<Vec<String>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub recording_status_callback_event: Option<Vec<RecordingEvent>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub recording_status_callback_method: Option<String>,
-    pub recording_track: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub recording_track: Option<RecordingTrack>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub refer_method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub refer_url: Option<String>,
-    pub ring_tone: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub ring_tone: Option<DialRingTone>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub sequential: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub time_limit: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timeout: Option<u32>,
-    pub trim: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub trim: Option<Trim>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct EnqueueAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub action: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub max_queue_size: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub wait_url: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub wait_url_method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub workflow_sid: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct GatherAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub action: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub action_on_empty_result: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub barge_in: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub debug: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub dtmf_detection: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub enhanced: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub finish_on_key: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub hints: Option<String>,
-    pub input: Option<Vec<String>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub input: Option<Vec<GatherInput>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub language: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub max_speech_time: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub num_digits: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub partial_result_callback: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub partial_result_callback_method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub profanity_filter: Option<bool>,
-    pub speech_model: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub speech_model: Option<SpeechModel>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub speech_timeout: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timeout: Option<u32>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct PauseAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub length: Option<u32>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct PayAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub action: Option<String>,
-    pub bank_account_type: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub bank_account_type: Option<PayBankAccountType>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub charge_amount: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub currency: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub description: Option<String>,
-    pub input: Option<String>,
-    pub language: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub input: Option<PayInput>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub language: Option<PayLanguage>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub max_attempts: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub min_postal_code_length: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub payment_connector: Option<String>,
-    pub payment_method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub payment_method: Option<PayPaymentMethod>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub postal_code: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub security_code: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback_method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timeout: Option<u32>,
-    pub token_type: Option<String>,
-    pub valid_card_types: Option<Vec<String>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub token_type: Option<PayTokenType>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub valid_card_types: Option<Vec<CardType>>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct PlayAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub digits: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "loop", skip_serializing_if = "Option::is_none")
+    )]
     pub loop_count: Option<u32>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct PromptAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub attempt: Option<Vec<u32>>,
-    pub card_type: Option<Vec<String>>,
-    pub error_type: Option<Vec<String>>,
-    pub for_attr: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub card_type: Option<Vec<CardType>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub error_type: Option<Vec<PromptErrorType>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "for", skip_serializing_if = "Option::is_none")
+    )]
+    pub for_attr: Option<PromptFor>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub require_matching_inputs: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct QueueAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub post_work_activity_sid: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub reservation_sid: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub url: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct RecordAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub action: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub finish_on_key: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub max_length: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub play_beep: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub recording_status_callback: Option<String>,
-    pub recording_status_callback_event: Option<Vec<String>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub recording_status_callback_event: Option<Vec<RecordingEvent>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub recording_status_callback_method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timeout: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub transcribe: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub transcribe_callback: Option<String>,
-    pub trim: Option<String>,
-    pub recording_channels: Option<String>, // "mono" | "dual"
-    pub recording_track: Option<String>,    // "inbound" | "outbound" | "both"
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub trim: Option<Trim>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub recording_channels: Option<RecordingChannels>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub recording_track: Option<RecordingTrack>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct RedirectAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct ReferAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub action: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct RejectAttributes {
-    pub reason: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub reason: Option<RejectReason>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct SayAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub language: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "loop", skip_serializing_if = "Option::is_none")
+    )]
     pub loop_count: Option<u32>,
-    pub voice: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub voice: Option<Voice>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct SmsAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub action: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub from: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub to: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct StartAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub action: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct StreamAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub connector_name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback_method: Option<String>,
-    pub track: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub track: Option<MediaTrack>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub url: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct SiprecAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub connector_name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback_method: Option<String>,
-    pub track: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub track: Option<MediaTrack>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct TranscriptionAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub enable_automatic_punctuation: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub hints: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub inbound_track_label: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub intelligence_service: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub language_code: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub outbound_track_label: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub partial_results: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub profanity_filter: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub speech_model: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback_method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback_url: Option<String>,
-    pub track: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub track: Option<MediaTrack>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub transcription_engine: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct ConfigAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub value: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct ParameterAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub value: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct RecordingAttributes {
-    pub channels: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub channels: Option<RecordingChannels>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub recording_status_callback: Option<String>,
-    pub recording_status_callback_event: Option<Vec<String>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub recording_status_callback_event: Option<Vec<RecordingEvent>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub recording_status_callback_method: Option<String>,
-    pub track: Option<String>,
-    pub trim: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub track: Option<RecordingTrack>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub trim: Option<Trim>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct SsmlBreakAttributes {
-    pub strength: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub strength: Option<SsmlBreakStrength>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub time: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct SsmlEmphasisAttributes {
-    pub level: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub level: Option<SsmlEmphasisLevel>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct SsmlLangAttributes {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "xml:lang", skip_serializing_if = "Option::is_none")
+    )]
     pub xml_lang: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct SsmlPhonemeAttributes {
-    pub alphabet: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub alphabet: Option<SsmlPhonemeAlphabet>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub ph: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct SsmlProsodyAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub pitch: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub rate: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub volume: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct SsmlSayAsAttributes {
-    pub format: Option<String>,
-    pub interpret_as: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub format: Option<SsmlSayAsFormat>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "interpret-as", skip_serializing_if = "Option::is_none")
+    )]
+    pub interpret_as: Option<SsmlSayAsInterpretAs>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct SsmlSubAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub alias: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct SsmlWAttributes {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub role: Option<String>,
 }
 
@@ -402,15 +1304,25 @@ pub struct SsmlWAttributes {
 
 /// `<Response>` TwiML for Voice
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct VoiceResponse {
-    verbs: Vec<VoiceVerb>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_verbs"))]
+    pub(crate) verbs: Vec<VoiceVerb>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     comments_before: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     comments: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    comments_between: Vec<(usize, String)>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     comments_after: Vec<String>,
 }
 
 /// Top-level TwiML verbs for voice calls
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum VoiceVerb {
     Connect(Connect),
     Dial(Dial),
@@ -432,6 +1344,8 @@ pub enum VoiceVerb {
     Sms(Sms),
     Start(Start),
     Stop(Stop),
+    /// Verbs pre-rendered with [`VoiceResponse::to_fragment`]
+    Fragment(RenderedFragment),
 }
 
 // ============================================================================
@@ -439,8 +1353,11 @@ pub enum VoiceVerb {
 // ============================================================================
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct Connect {
     pub attributes: ConnectAttributes,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     pub nested: Vec<ConnectNoun>,
 }
 
@@ -521,9 +1438,17 @@ impl Connect {
 // ============================================================================
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Dial {
+    #[cfg_attr(feature = "serde", serde(default))]
     pub attributes: DialAttributes,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub number: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub nested: Vec<DialNoun>,
 }
 
@@ -611,6 +1536,7 @@ impl Default for Dial {
 // ============================================================================
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Echo;
 
 impl Echo {
@@ -624,9 +1550,14 @@ impl Echo {
 // ============================================================================
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Enqueue {
+    #[cfg_attr(feature = "serde", serde(default))]
     pub attributes: EnqueueAttributes,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub task: Option<Task>,
 }
 
@@ -680,8 +1611,15 @@ impl Default for Enqueue {
 // ============================================================================
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Gather {
+    #[cfg_attr(feature = "serde", serde(default))]
     pub attributes: GatherAttributes,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub nested: Vec<GatherNoun>,
 }
 
@@ -700,7 +1638,7 @@ impl Gather {
         }
     }
 
-    pub fn input(mut self, input: Vec<String>) -> Self {
+    pub fn input(mut self, input: Vec<GatherInput>) -> Self {
         self.attributes.input = Some(input);
         self
     }
@@ -785,8 +1723,8 @@ impl Gather {
         self
     }
 
-    pub fn speech_model(mut self, speech_model: impl Into<String>) -> Self {
-        self.attributes.speech_model = Some(speech_model.into());
+    pub fn speech_model(mut self, speech_model: SpeechModel) -> Self {
+        self.attributes.speech_model = Some(speech_model);
         self
     }
 
@@ -822,6 +1760,7 @@ impl Default for Gather {
 // ============================================================================
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hangup;
 
 impl Hangup {
@@ -835,6 +1774,7 @@ impl Hangup {
 // ============================================================================
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Leave;
 
 impl Leave {
@@ -848,7 +1788,10 @@ impl Leave {
 // ============================================================================
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Pause {
+    #[cfg_attr(feature = "serde", serde(default))]
     pub attributes: PauseAttributes,
 }
 
@@ -865,9 +1808,20 @@ impl Pause {
 // ============================================================================
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Pay {
+    #[cfg_attr(feature = "serde", serde(default))]
     pub attributes: PayAttributes,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub prompts: Vec<Prompt>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub parameters: Vec<Parameter>,
 }
 
@@ -880,8 +1834,8 @@ impl Pay {
         }
     }
 
-    pub fn input(mut self, input: impl Into<String>) -> Self {
-        self.attributes.input = Some(input.into());
+    pub fn input(mut self, input: PayInput) -> Self {
+        self.attributes.input = Some(input);
         self
     }
 
@@ -905,8 +1859,8 @@ impl Pay {
         self
     }
 
-    pub fn payment_method(mut self, payment_method: impl Into<String>) -> Self {
-        self.attributes.payment_method = Some(payment_method.into());
+    pub fn payment_method(mut self, payment_method: PayPaymentMethod) -> Self {
+        self.attributes.payment_method = Some(payment_method);
         self
     }
 }
@@ -922,12 +1876,18 @@ impl Default for Pay {
 // ============================================================================
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Play {
+    #[cfg_attr(feature = "serde", serde(default))]
     pub attributes: PlayAttributes,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub url: Option<String>,
 }
 
 impl Play {
+    #[allow(clippy::new_without_default)]
+    #[deprecated(note = "renders an empty `<Play>`; use `Play::with_url` or `Play::with_digits`")]
     pub fn new() -> Self {
         Self {
             attributes: PlayAttributes::default(),
@@ -935,6 +1895,25 @@ impl Play {
         }
     }
 
+    /// `<Play>` of the media at `url`
+    pub fn with_url(url: impl Into<String>) -> Self {
+        Self {
+            attributes: PlayAttributes::default(),
+            url: Some(url.into()),
+        }
+    }
+
+    /// `<Play>` of DTMF `digits`, with no media
+    pub fn with_digits(digits: impl Into<String>) -> Self {
+        Self {
+            attributes: PlayAttributes {
+                digits: Some(digits.into()),
+                ..PlayAttributes::default()
+            },
+            url: None,
+        }
+    }
+
     pub fn url(mut self, url: impl Into<String>) -> Self {
         self.url = Some(url.into());
         self
@@ -951,25 +1930,32 @@ impl Play {
     }
 }
 
-impl Default for Play {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 // ============================================================================
 // Prompt Verb
 // ============================================================================
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Prompt {
+    #[cfg_attr(feature = "serde", serde(default))]
     pub attributes: PromptAttributes,
 }
 
 impl Prompt {
+    #[allow(clippy::new_without_default)]
+    #[deprecated(note = "a `<Prompt>` needs `for`; use `Prompt::with_for`")]
     pub fn new() -> Self {
+        Self::with_attributes(PromptAttributes::default())
+    }
+
+    /// `<Prompt>` for the `<Pay>` step `for_attr`
+    pub fn with_for(for_attr: PromptFor) -> Self {
         Self {
-            attributes: PromptAttributes::default(),
+            attributes: PromptAttributes {
+                for_attr: Some(for_attr),
+                ..PromptAttributes::default()
+            },
         }
     }
 
@@ -977,8 +1963,8 @@ impl Prompt {
         Self { attributes }
     }
 
-    pub fn for_attr(mut self, for_attr: impl Into<String>) -> Self {
-        self.attributes.for_attr = Some(for_attr.into());
+    pub fn for_attr(mut self, for_attr: PromptFor) -> Self {
+        self.attributes.for_attr = Some(for_attr);
         self
     }
 
@@ -987,29 +1973,26 @@ impl Prompt {
         self
     }
 
-    pub fn card_type(mut self, card_type: Vec<String>) -> Self {
+    pub fn card_type(mut self, card_type: Vec<CardType>) -> Self {
         self.attributes.card_type = Some(card_type);
         self
     }
 
-    pub fn error_type(mut self, error_type: Vec<String>) -> Self {
+    pub fn error_type(mut self, error_type: Vec<PromptErrorType>) -> Self {
         self.attributes.error_type = Some(error_type);
         self
     }
 }
 
-impl Default for Prompt {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 // ============================================================================
 // Queue Verb - Join a queue
 // ============================================================================
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Queue {
+    #[cfg_attr(feature = "serde", serde(default))]
     pub attributes: QueueAttributes,
     pub name: String,
 }
@@ -1025,7 +2008,10 @@ impl Queue {
 // ============================================================================
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Record {
+    #[cfg_attr(feature = "serde", serde(default))]
     pub attributes: RecordAttributes,
 }
 
@@ -1071,13 +2057,13 @@ impl Record {
         self
     }
 
-    pub fn recording_channels(mut self, channels: impl Into<String>) -> Self {
-        self.attributes.recording_channels = Some(channels.into());
+    pub fn recording_channels(mut self, channels: RecordingChannels) -> Self {
+        self.attributes.recording_channels = Some(channels);
         self
     }
 
-    pub fn recording_track(mut self, track: impl Into<String>) -> Self {
-        self.attributes.recording_track = Some(track.into());
+    pub fn recording_track(mut self, track: RecordingTrack) -> Self {
+        self.attributes.recording_track = Some(track);
         self
     }
 
@@ -1086,8 +2072,8 @@ impl Record {
         self
     }
 
-    pub fn trim(mut self, trim: impl Into<String>) -> Self {
-        self.attributes.trim = Some(trim.into());
+    pub fn trim(mut self, trim: Trim) -> Self {
+        self.attributes.trim = Some(trim);
         self
     }
 
@@ -1101,7 +2087,7 @@ impl Record {
         self
     }
 
-    pub fn recording_status_callback_event(mut self, events: Vec<String>) -> Self {
+    pub fn recording_status_callback_event(mut self, events: Vec<RecordingEvent>) -> Self {
         self.attributes.recording_status_callback_event = Some(events);
         self
     }
@@ -1118,7 +2104,10 @@ impl Default for Record {
 // ============================================================================
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Redirect {
+    #[cfg_attr(feature = "serde", serde(default))]
     pub attributes: RedirectAttributes,
     pub url: String,
 }
@@ -1134,8 +2123,12 @@ impl Redirect {
 // ============================================================================
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Refer {
+    #[cfg_attr(feature = "serde", serde(default))]
     pub attributes: ReferAttributes,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub refer_sip: Option<ReferSip>,
 }
 
@@ -1174,7 +2167,10 @@ impl Default for Refer {
 // ============================================================================
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Reject {
+    #[cfg_attr(feature = "serde", serde(default))]
     pub attributes: RejectAttributes,
 }
 
@@ -1185,8 +2181,8 @@ impl Reject {
         }
     }
 
-    pub fn reason(mut self, reason: impl Into<String>) -> Self {
-        self.attributes.reason = Some(reason.into());
+    pub fn reason(mut self, reason: RejectReason) -> Self {
+        self.attributes.reason = Some(reason);
         self
     }
 }
@@ -1202,9 +2198,16 @@ impl Default for Reject {
 // ============================================================================
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Say {
+    #[cfg_attr(feature = "serde", serde(default))]
     pub attributes: SayAttributes,
     pub message: String,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub ssml_elements: Vec<SsmlElement>,
 }
 
@@ -1217,8 +2220,8 @@ impl Say {
         }
     }
 
-    pub fn voice(mut self, voice: impl Into<String>) -> Self {
-        self.attributes.voice = Some(voice.into());
+    pub fn voice(mut self, voice: Voice) -> Self {
+        self.attributes.voice = Some(voice);
         self
     }
 
@@ -1232,13 +2235,17 @@ impl Say {
         self
     }
 
-    pub fn add_break(mut self, strength: Option<String>, time: Option<String>) -> Self {
+    pub fn add_break(mut self, strength: Option<SsmlBreakStrength>, time: Option<String>) -> Self {
         self.ssml_elements
             .push(SsmlElement::Break { strength, time });
         self
     }
 
-    pub fn add_emphasis(mut self, level: Option<String>, text: impl Into<String>) -> Self {
+    pub fn add_emphasis(
+        mut self,
+        level: Option<SsmlEmphasisLevel>,
+        text: impl Into<String>,
+    ) -> Self {
         self.ssml_elements.push(SsmlElement::Emphasis {
             level,
             text: text.into(),
@@ -1286,7 +2293,7 @@ impl Say {
         mut self,
         ph: impl Into<String>,
         text: impl Into<String>,
-        alphabet: Option<String>,
+        alphabet: Option<SsmlPhonemeAlphabet>,
     ) -> Self {
         self.ssml_elements.push(SsmlElement::Phoneme {
             alphabet,
@@ -1298,12 +2305,12 @@ impl Say {
 
     pub fn add_say_as(
         mut self,
-        interpret_as: impl Into<String>,
+        interpret_as: SsmlSayAsInterpretAs,
         text: impl Into<String>,
-        format: Option<String>,
+        format: Option<SsmlSayAsFormat>,
     ) -> Self {
         self.ssml_elements.push(SsmlElement::SayAs {
-            interpret_as: interpret_as.into(),
+            interpret_as,
             format,
             text: text.into(),
         });
@@ -1341,6 +2348,12 @@ impl Say {
         });
         self
     }
+
+    pub fn add_text(mut self, text: impl Into<String>) -> Self {
+        self.ssml_elements
+            .push(SsmlElement::Text { text: text.into() });
+        self
+    }
 }
 
 // ============================================================================
@@ -1348,7 +2361,10 @@ impl Say {
 // ============================================================================
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Sms {
+    #[cfg_attr(feature = "serde", serde(default))]
     pub attributes: SmsAttributes,
     pub message: String,
 }
@@ -1367,8 +2383,15 @@ impl Sms {
 // ============================================================================
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Start {
+    #[cfg_attr(feature = "serde", serde(default))]
     pub attributes: StartAttributes,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub nested: Vec<StartNoun>,
 }
 
@@ -1421,6 +2444,7 @@ impl Default for Start {
 // ============================================================================
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Stop;
 
 impl Stop {
@@ -1434,20 +2458,55 @@ impl Stop {
 // ============================================================================
 
 // Stream - Media streaming
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Stream {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub connector_name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub url: Option<String>,
-    pub track: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub track: Option<MediaTrack>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback_method: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub parameters: Vec<Parameter>,
 }
 
 impl Stream {
+    #[allow(clippy::new_without_default)]
+    #[deprecated(note = "a `<Stream>` needs a `url`; use `Stream::with_url`")]
     pub fn new() -> Self {
-        Self::default()
+        Self {
+            name: None,
+            connector_name: None,
+            url: None,
+            track: None,
+            status_callback: None,
+            status_callback_method: None,
+            parameters: Vec::new(),
+        }
+    }
+
+    /// `<Stream>` of the call's audio to the WebSocket at `url`
+    pub fn with_url(url: impl Into<String>) -> Self {
+        Self {
+            name: None,
+            connector_name: None,
+            url: Some(url.into()),
+            track: None,
+            status_callback: None,
+            status_callback_method: None,
+            parameters: Vec::new(),
+        }
     }
 
     pub fn name(mut self, name: impl Into<String>) -> Self {
@@ -1465,8 +2524,8 @@ impl Stream {
         self
     }
 
-    pub fn track(mut self, track: impl Into<String>) -> Self {
-        self.track = Some(track.into());
+    pub fn track(mut self, track: MediaTrack) -> Self {
+        self.track = Some(track);
         self
     }
 
@@ -1483,8 +2542,12 @@ impl Stream {
 
 // Room - Video room
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct Room {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub participant_identity: Option<String>,
 }
 
@@ -1504,7 +2567,10 @@ impl Room {
 
 // Conversation - Conversation API
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct Conversation {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub service_instance_sid: Option<String>,
 }
 
@@ -1521,9 +2587,14 @@ impl Conversation {
 
 // VirtualAgent - AI Virtual Agent
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct VirtualAgent {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub connector_name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub language: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     pub parameters: Vec<Parameter>,
 }
 
@@ -1548,7 +2619,10 @@ impl VirtualAgent {
 
 // Autopilot - Autopilot Assistant
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct Autopilot {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub name: Option<String>,
 }
 
@@ -1562,7 +2636,10 @@ impl Autopilot {
 
 // AiSession - AI Session
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct AiSession {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub assistant_sid: Option<String>,
 }
 
@@ -1576,8 +2653,12 @@ impl AiSession {
 
 // ConversationRelaySession - Conversation Relay Session
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct ConversationRelaySession {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub connector: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub session_configuration: Option<String>,
 }
 
@@ -1599,7 +2680,10 @@ impl ConversationRelaySession {
 
 // Assistant - Assistant
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct Assistant {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub sid: Option<String>,
 }
 
@@ -1613,21 +2697,38 @@ impl Assistant {
 
 // ConversationRelay - Conversation Relay
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct ConversationRelay {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub url: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub welcome_greeting: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub voice: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub language: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub dtmf_detection: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub interruptible: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub interruption_sensitivity: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub speech_model: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub profanity_filter: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub transcription_enabled: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback_method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub max_duration: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     pub languages: Vec<Language>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     pub parameters: Vec<Parameter>,
 }
 
@@ -1716,15 +2817,32 @@ impl ConversationRelay {
 }
 
 // Parameter - Generic parameter for various verbs
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Parameter {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub value: Option<String>,
 }
 
 impl Parameter {
+    #[allow(clippy::new_without_default)]
+    #[deprecated(note = "a `<Parameter>` needs a name and value; use `Parameter::with_value`")]
     pub fn new() -> Self {
-        Self::default()
+        Self {
+            name: None,
+            value: None,
+        }
+    }
+
+    /// `<Parameter>` passing `value` under `name`
+    pub fn with_value(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            value: Some(value.into()),
+        }
     }
 
     pub fn name(mut self, name: impl Into<String>) -> Self {
@@ -1740,6 +2858,8 @@ impl Parameter {
 
 // ReferSip - SIP reference for Refer verb
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ReferSip {
     pub sip_url: String,
 }
@@ -1754,6 +2874,8 @@ impl ReferSip {
 
 // Transcription - Live transcription
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct Transcription {
     pub attributes: TranscriptionAttributes,
 }
@@ -1770,8 +2892,8 @@ impl Transcription {
         self
     }
 
-    pub fn track(mut self, track: impl Into<String>) -> Self {
-        self.attributes.track = Some(track.into());
+    pub fn track(mut self, track: MediaTrack) -> Self {
+        self.attributes.track = Some(track);
         self
     }
 
@@ -1838,13 +2960,21 @@ impl Transcription {
 
 // Recording - Recording configuration
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct Recording {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub recording_status_callback: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub recording_status_callback_method: Option<String>,
-    pub recording_status_callback_event: Option<String>,
-    pub trim: Option<String>,
-    pub track: Option<String>,
-    pub channels: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub recording_status_callback_event: Option<Vec<RecordingEvent>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub trim: Option<Trim>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub track: Option<RecordingTrack>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub channels: Option<RecordingChannels>,
 }
 
 impl Recording {
@@ -1862,33 +2992,38 @@ impl Recording {
         self
     }
 
-    pub fn recording_status_callback_event(mut self, event: impl Into<String>) -> Self {
-        self.recording_status_callback_event = Some(event.into());
+    pub fn recording_status_callback_event(mut self, events: Vec<RecordingEvent>) -> Self {
+        self.recording_status_callback_event = Some(events);
         self
     }
 
-    pub fn trim(mut self, trim: impl Into<String>) -> Self {
-        self.trim = Some(trim.into());
+    pub fn trim(mut self, trim: Trim) -> Self {
+        self.trim = Some(trim);
         self
     }
 
-    pub fn track(mut self, track: impl Into<String>) -> Self {
-        self.track = Some(track.into());
+    pub fn track(mut self, track: RecordingTrack) -> Self {
+        self.track = Some(track);
         self
     }
 
-    pub fn channels(mut self, channels: impl Into<String>) -> Self {
-        self.channels = Some(channels.into());
+    pub fn channels(mut self, channels: RecordingChannels) -> Self {
+        self.channels = Some(channels);
         self
     }
 }
 
 // Siprec - SIPREC recording
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct Siprec {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub connector_name: Option<String>,
-    pub track: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub track: Option<MediaTrack>,
 }
 
 impl Siprec {
@@ -1906,15 +3041,18 @@ impl Siprec {
         self
     }
 
-    pub fn track(mut self, track: impl Into<String>) -> Self {
-        self.track = Some(track.into());
+    pub fn track(mut self, track: MediaTrack) -> Self {
+        self.track = Some(track);
         self
     }
 }
 
 // Task - Task for Autopilot
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct Task {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub task_sid: Option<String>,
 }
 
@@ -1931,6 +3069,8 @@ impl Task {
 
 // Identity - Identity for Client
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Identity {
     pub identity: String,
 }
@@ -1945,9 +3085,17 @@ impl Identity {
 
 // Language - Language for transcription and ConversationRelay
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct Language {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "code", skip_serializing_if = "Option::is_none")
+    )]
     pub language_code: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub tts_provider: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub stt_provider: Option<String>,
 }
 
@@ -1973,10 +3121,16 @@ impl Language {
 
 // Application - Application for Dial
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct Application {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub application_sid: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub customer_id: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub copy_parent_to: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     pub parameters: Vec<Parameter>,
 }
 
@@ -2008,6 +3162,8 @@ impl Application {
 
 // ApplicationSid - Application SID
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ApplicationSid {
     pub sid: String,
 }
@@ -2020,8 +3176,12 @@ impl ApplicationSid {
 
 // Config - Configuration for various services
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct Config {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub value: Option<String>,
 }
 
@@ -2043,16 +3203,22 @@ impl Config {
 
 /// SSML elements for enhanced speech synthesis
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all_fields = "camelCase"))]
 pub enum SsmlElement {
     Break {
-        strength: Option<String>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        strength: Option<SsmlBreakStrength>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         time: Option<String>,
     },
     Emphasis {
-        level: Option<String>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        level: Option<SsmlEmphasisLevel>,
         text: String,
     },
     Lang {
+        #[cfg_attr(feature = "serde", serde(rename = "xml:lang"))]
         xml_lang: String,
         text: String,
     },
@@ -2060,13 +3226,17 @@ pub enum SsmlElement {
         text: String,
     },
     Phoneme {
-        alphabet: Option<String>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        alphabet: Option<SsmlPhonemeAlphabet>,
         ph: String,
         text: String,
     },
     Prosody {
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         pitch: Option<String>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         rate: Option<String>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         volume: Option<String>,
         text: String,
     },
@@ -2074,8 +3244,10 @@ pub enum SsmlElement {
         text: String,
     },
     SayAs {
-        interpret_as: String,
-        format: Option<String>,
+        #[cfg_attr(feature = "serde", serde(rename = "interpret-as"))]
+        interpret_as: SsmlSayAsInterpretAs,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        format: Option<SsmlSayAsFormat>,
         text: String,
     },
     Sub {
@@ -2083,6 +3255,7 @@ pub enum SsmlElement {
         text: String,
     },
     W {
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         role: Option<String>,
         text: String,
     },
@@ -2094,10 +3267,15 @@ pub enum SsmlElement {
         name: String,
         text: String,
     },
+    /// Plain text between SSML elements
+    Text {
+        text: String,
+    },
 }
 
 /// Nouns that can be nested within Dial
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DialNoun {
     Number(DialNumber),
     Client(DialClient),
@@ -2111,6 +3289,7 @@ pub enum DialNoun {
 
 /// Nouns that can be nested within Gather
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GatherNoun {
     Say(Say),
     Play(Play),
@@ -2119,6 +3298,7 @@ pub enum GatherNoun {
 
 /// Nouns that can be nested within Connect
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConnectNoun {
     Stream(Stream),
     Room(Room),
@@ -2133,6 +3313,7 @@ pub enum ConnectNoun {
 
 /// Nouns that can be nested within Start
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StartNoun {
     Stream(Stream),
     Siprec(Siprec),
@@ -2145,22 +3326,39 @@ pub enum StartNoun {
 // ============================================================================
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct DialNumber {
     pub number: String,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub send_digits: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub url: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback: Option<String>,
-    pub status_callback_event: Option<Vec<String>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub status_callback_event: Option<Vec<CallStatusEvent>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback_method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub call_reason: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub byoc: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub machine_detection: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub machine_detection_timeout: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub machine_detection_speech_threshold: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub machine_detection_speech_end_threshold: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub machine_detection_silence_timeout: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub amd_status_callback: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub amd_status_callback_method: Option<String>,
 }
 
@@ -2206,7 +3404,7 @@ impl DialNumber {
         self
     }
 
-    pub fn status_callback_event(mut self, events: Vec<String>) -> Self {
+    pub fn status_callback_event(mut self, events: Vec<CallStatusEvent>) -> Self {
         self.status_callback_event = Some(events);
         self
     }
@@ -2263,13 +3461,21 @@ impl DialNumber {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct DialClient {
     pub identity: String,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub url: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
-    pub status_callback_event: Option<Vec<String>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub status_callback_event: Option<Vec<CallStatusEvent>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback_method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub client_notification_url: Option<String>,
 }
 
@@ -2296,7 +3502,7 @@ impl DialClient {
         self
     }
 
-    pub fn status_callback_event(mut self, events: Vec<String>) -> Self {
+    pub fn status_callback_event(mut self, events: Vec<CallStatusEvent>) -> Self {
         self.status_callback_event = Some(events);
         self
     }
@@ -2318,30 +3524,55 @@ impl DialClient {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct DialConference {
     pub name: String,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub muted: Option<bool>,
-    pub beep: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub beep: Option<ConferenceBeep>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub start_conference_on_enter: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub end_conference_on_exit: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub wait_url: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub wait_method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub max_participants: Option<u32>,
-    pub record: Option<String>,
-    pub region: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub record: Option<ConferenceRecord>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub region: Option<ConferenceRegion>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub coach: Option<String>,
-    pub trim: Option<String>,
-    pub status_callback_event: Option<Vec<String>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub trim: Option<Trim>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub status_callback_event: Option<Vec<ConferenceEvent>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback_method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub recording_status_callback: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub recording_status_callback_method: Option<String>,
-    pub recording_status_callback_event: Option<Vec<String>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub recording_status_callback_event: Option<Vec<RecordingEvent>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub event_callback_url: Option<String>,
-    pub jitter_buffer_size: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub jitter_buffer_size: Option<ConferenceJitterBufferSize>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub participant_label: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub call_sid_to_coach: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub beep_on_customer_entrance: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub coaching: Option<bool>,
 }
 
@@ -2380,8 +3611,8 @@ impl DialConference {
         self
     }
 
-    pub fn beep(mut self, beep: impl Into<String>) -> Self {
-        self.beep = Some(beep.into());
+    pub fn beep(mut self, beep: ConferenceBeep) -> Self {
+        self.beep = Some(beep);
         self
     }
 
@@ -2400,8 +3631,8 @@ impl DialConference {
         self
     }
 
-    pub fn record(mut self, record: impl Into<String>) -> Self {
-        self.record = Some(record.into());
+    pub fn record(mut self, record: ConferenceRecord) -> Self {
+        self.record = Some(record);
         self
     }
 
@@ -2415,8 +3646,8 @@ impl DialConference {
         self
     }
 
-    pub fn region(mut self, region: impl Into<String>) -> Self {
-        self.region = Some(region.into());
+    pub fn region(mut self, region: ConferenceRegion) -> Self {
+        self.region = Some(region);
         self
     }
 
@@ -2425,12 +3656,12 @@ impl DialConference {
         self
     }
 
-    pub fn trim(mut self, trim: impl Into<String>) -> Self {
-        self.trim = Some(trim.into());
+    pub fn trim(mut self, trim: Trim) -> Self {
+        self.trim = Some(trim);
         self
     }
 
-    pub fn status_callback_event(mut self, events: Vec<String>) -> Self {
+    pub fn status_callback_event(mut self, events: Vec<ConferenceEvent>) -> Self {
         self.status_callback_event = Some(events);
         self
     }
@@ -2455,7 +3686,7 @@ impl DialConference {
         self
     }
 
-    pub fn recording_status_callback_event(mut self, events: Vec<String>) -> Self {
+    pub fn recording_status_callback_event(mut self, events: Vec<RecordingEvent>) -> Self {
         self.recording_status_callback_event = Some(events);
         self
     }
@@ -2465,8 +3696,8 @@ impl DialConference {
         self
     }
 
-    pub fn jitter_buffer_size(mut self, size: impl Into<String>) -> Self {
-        self.jitter_buffer_size = Some(size.into());
+    pub fn jitter_buffer_size(mut self, size: ConferenceJitterBufferSize) -> Self {
+        self.jitter_buffer_size = Some(size);
         self
     }
 
@@ -2492,11 +3723,17 @@ impl DialConference {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct DialQueue {
     pub name: String,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub url: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub reservation_sid: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub post_work_activity_sid: Option<String>,
 }
 
@@ -2523,16 +3760,27 @@ impl DialQueue {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct DialSip {
     pub sip_url: String,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub url: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub username: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub password: Option<String>,
-    pub status_callback_event: Option<Vec<String>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub status_callback_event: Option<Vec<CallStatusEvent>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback_method: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub codecs: Option<Vec<String>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub custom_headers: Option<Vec<(String, String)>>,
 }
 
@@ -2572,7 +3820,7 @@ impl DialSip {
         self
     }
 
-    pub fn status_callback_event(mut self, events: Vec<String>) -> Self {
+    pub fn status_callback_event(mut self, events: Vec<CallStatusEvent>) -> Self {
         self.status_callback_event = Some(events);
         self
     }
@@ -2617,6 +3865,8 @@ impl DialSip {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct DialSim {
     pub sim_sid: String,
 }
@@ -2630,10 +3880,16 @@ impl DialSim {
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct DialApplication {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub application_sid: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub customer_id: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub copy_parent_to: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     pub parameters: Vec<Parameter>,
 }
 
@@ -2664,12 +3920,19 @@ impl DialApplication {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct DialWhatsApp {
     pub phone_number: String,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub url: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub method: Option<String>,
-    pub status_callback_event: Option<Vec<String>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub status_callback_event: Option<Vec<CallStatusEvent>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub status_callback_method: Option<String>,
 }
 
@@ -2711,6 +3974,16 @@ impl VoiceResponse {
         self
     }
 
+    /// Comment between verbs, after the verbs added so far
+    ///
+    /// # Arguments
+    /// * `comment` - XML Comment
+    pub fn comment_here(mut self, comment: impl Into<String>) -> Self {
+        self.comments_between
+            .push((self.verbs.len(), comment.into()));
+        self
+    }
+
     /// Comments after `<Response>`
     ///
     /// # Arguments
@@ -2782,6 +4055,16 @@ impl VoiceResponse {
         self
     }
 
+    /// Splice in verbs pre-rendered with [`VoiceResponse::to_fragment`] or
+    /// [`VoiceVerb::to_fragment`]
+    ///
+    /// # Arguments
+    /// * `fragment` - Pre-rendered verbs
+    pub fn fragment(mut self, fragment: RenderedFragment) -> Self {
+        self.verbs.push(VoiceVerb::Fragment(fragment));
+        self
+    }
+
     /// `<Gather>` TwiML Verb
     ///
     /// # Arguments
@@ -2829,7 +4112,7 @@ impl VoiceResponse {
     /// # Arguments
     /// * `url` - Media URL
     pub fn play(mut self, url: impl Into<String>) -> Self {
-        let play = Play::new().url(url);
+        let play = Play::with_url(url);
         self.verbs.push(VoiceVerb::Play(play));
         self
     }
@@ -2978,6 +4261,12 @@ impl VoiceResponse {
         self
     }
 
+    /// Add a Redirect verb with a pre-configured Redirect object
+    pub fn redirect_with(mut self, redirect: Redirect) -> Self {
+        self.verbs.push(VoiceVerb::Redirect(redirect));
+        self
+    }
+
     /// Add a Refer verb with a pre-configured Refer object
     pub fn refer_with(mut self, refer: Refer) -> Self {
         self.verbs.push(VoiceVerb::Refer(refer));
@@ -3024,1512 +4313,3112 @@ impl VoiceResponse {
     }
 }
 
-impl TwiML for VoiceResponse {
-    fn to_xml(&self) -> String {
-        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+/// Space-separated attribute value for a list of enumerated values
+fn join_values<T: AsRef<str>>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|v| v.as_ref())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
 
-        // Add comments before Response
-        for comment in &self.comments_before {
-            xml.push_str(&format!("<!-- {} -->\n", escape_xml_text(comment)));
+/// Write the message and SSML children of a `<Say>`
+fn write_say_content<W: fmt::Write + ?Sized>(
+    xml: &mut W,
+    say: &Say,
+    options: &RenderOptions,
+) -> fmt::Result {
+    // Add message
+    write_xml_text(xml, &say.message)?;
+
+    // Add SSML elements
+    for ssml in &say.ssml_elements {
+        match ssml {
+            SsmlElement::Break { strength, time } => {
+                xml.write_str("<break")?;
+                if let Some(s) = strength {
+                    write!(xml, " strength=\"{}\"", escape_xml_attr(s.as_str()))?;
+                }
+                if let Some(t) = time {
+                    write!(xml, " time=\"{}\"", escape_xml_attr(t))?;
+                }
+                options.write_self_close(xml)?;
+            }
+            SsmlElement::Emphasis { level, text } => {
+                xml.write_str("<emphasis")?;
+                if let Some(l) = level {
+                    write!(xml, " level=\"{}\"", escape_xml_attr(l.as_str()))?;
+                }
+                write!(xml, ">{}</emphasis>", escape_xml_text(text))?;
+            }
+            SsmlElement::Prosody {
+                pitch,
+                rate,
+                volume,
+                text,
+            } => {
+                xml.write_str("<prosody")?;
+                if let Some(p) = pitch {
+                    write!(xml, " pitch=\"{}\"", escape_xml_attr(p))?;
+                }
+                if let Some(r) = rate {
+                    write!(xml, " rate=\"{}\"", escape_xml_attr(r))?;
+                }
+                if let Some(v) = volume {
+                    write!(xml, " volume=\"{}\"", escape_xml_attr(v))?;
+                }
+                write!(xml, ">{}</prosody>", escape_xml_text(text))?;
+            }
+            SsmlElement::SayAs {
+                interpret_as,
+                format,
+                text,
+            } => {
+                write!(
+                    xml,
+                    "<say-as interpret-as=\"{}\"",
+                    escape_xml_attr(interpret_as.as_str())
+                )?;
+                if let Some(f) = format {
+                    write!(xml, " format=\"{}\"", escape_xml_attr(f.as_str()))?;
+                }
+                write!(xml, ">{}</say-as>", escape_xml_text(text))?;
+            }
+            SsmlElement::Sub { alias, text } => {
+                write!(
+                    xml,
+                    "<sub alias=\"{}\">{}</sub>",
+                    escape_xml_attr(alias),
+                    escape_xml_text(text)
+                )?;
+            }
+            SsmlElement::P { text } => {
+                write!(xml, "<p>{}</p>", escape_xml_text(text))?;
+            }
+            SsmlElement::S { text } => {
+                write!(xml, "<s>{}</s>", escape_xml_text(text))?;
+            }
+            SsmlElement::Lang { xml_lang, text } => {
+                write!(
+                    xml,
+                    "<lang xml:lang=\"{}\">{}</lang>",
+                    escape_xml_attr(xml_lang),
+                    escape_xml_text(text)
+                )?;
+            }
+            SsmlElement::Phoneme { alphabet, ph, text } => {
+                xml.write_str("<phoneme")?;
+                if let Some(a) = alphabet {
+                    write!(xml, " alphabet=\"{}\"", escape_xml_attr(a.as_str()))?;
+                }
+                write!(
+                    xml,
+                    " ph=\"{}\">{}</phoneme>",
+                    escape_xml_attr(ph),
+                    escape_xml_text(text)
+                )?;
+            }
+            SsmlElement::W { role, text } => {
+                xml.write_str("<w")?;
+                if let Some(r) = role {
+                    write!(xml, " role=\"{}\"", escape_xml_attr(r))?;
+                }
+                write!(xml, ">{}</w>", escape_xml_text(text))?;
+            }
+            SsmlElement::AmazonEffect { name, text } => {
+                write!(
+                    xml,
+                    "<amazon:effect name=\"{}\">{}</amazon:effect>",
+                    escape_xml_attr(name),
+                    escape_xml_text(text)
+                )?;
+            }
+            SsmlElement::AmazonDomain { name, text } => {
+                write!(
+                    xml,
+                    "<amazon:domain name=\"{}\">{}</amazon:domain>",
+                    escape_xml_attr(name),
+                    escape_xml_text(text)
+                )?;
+            }
+            SsmlElement::Text { text } => {
+                write_xml_text(xml, text)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Write the comments that follow the first `position` verbs of `<Response>`
+fn write_comments_between<W: fmt::Write + ?Sized>(
+    xml: &mut W,
+    comments: &[(usize, String)],
+    position: usize,
+    options: &RenderOptions,
+) -> fmt::Result {
+    for (_, comment) in comments.iter().filter(|(at, _)| *at == position) {
+        options.write_indent(xml, 1)?;
+        write!(xml, "<!-- {} -->", escape_xml_text(comment))?;
+        options.write_newline(xml)?;
+    }
+    Ok(())
+}
+
+/// Write one top-level verb, indented as a child of `<Response>`
+fn write_verb<W: fmt::Write + ?Sized>(
+    xml: &mut W,
+    verb: &VoiceVerb,
+    options: &RenderOptions,
+) -> fmt::Result {
+    match verb {
+        VoiceVerb::Fragment(fragment) => {
+            if fragment.options == *options {
+                xml.write_str(&fragment.xml)?;
+            } else {
+                for verb in fragment.verbs.iter() {
+                    write_verb(xml, verb, options)?;
+                }
+            }
         }
+        VoiceVerb::Say(say) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Say")?;
+            if let Some(v) = &say.attributes.voice {
+                write!(xml, " voice=\"{}\"", escape_xml_attr(&v.to_string()))?;
+            }
+            if let Some(l) = &say.attributes.language {
+                write!(xml, " language=\"{}\"", escape_xml_attr(l))?;
+            }
+            if let Some(lc) = say.attributes.loop_count {
+                write!(xml, " loop=\"{}\"", lc)?;
+            }
+            xml.write_char('>')?;
 
-        xml.push_str("<Response>\n");
+            write_say_content(xml, say, options)?;
 
-        // Add comments inside Response
-        for comment in &self.comments {
-            xml.push_str(&format!("  <!-- {} -->\n", escape_xml_text(comment)));
+            xml.write_str("</Say>")?;
+            options.write_newline(xml)?;
         }
+        VoiceVerb::Play(play) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Play")?;
+            if let Some(d) = &play.attributes.digits {
+                write!(xml, " digits=\"{}\"", escape_xml_attr(d))?;
+            }
+            if let Some(lc) = play.attributes.loop_count {
+                write!(xml, " loop=\"{}\"", lc)?;
+            }
+            xml.write_char('>')?;
+            if let Some(url) = &play.url {
+                write_xml_text(xml, url)?;
+            }
+            xml.write_str("</Play>")?;
+            options.write_newline(xml)?;
+        }
+        VoiceVerb::Pause(pause) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Pause")?;
+            if let Some(len) = pause.attributes.length {
+                write!(xml, " length=\"{}\"", len)?;
+            }
+            options.write_self_close(xml)?;
+            options.write_newline(xml)?;
+        }
+        VoiceVerb::Dial(dial) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Dial")?;
+            if let Some(a) = &dial.attributes.action {
+                write!(xml, " action=\"{}\"", escape_xml_attr(a))?;
+            }
+            if let Some(m) = &dial.attributes.method {
+                write!(xml, " method=\"{}\"", escape_xml_attr(m))?;
+            }
+            if let Some(t) = dial.attributes.timeout {
+                write!(xml, " timeout=\"{}\"", t)?;
+            }
+            if let Some(h) = dial.attributes.hangup_on_star {
+                write!(xml, " hangupOnStar=\"{}\"", h)?;
+            }
+            if let Some(tl) = dial.attributes.time_limit {
+                write!(xml, " timeLimit=\"{}\"", tl)?;
+            }
+            if let Some(c) = &dial.attributes.caller_id {
+                write!(xml, " callerId=\"{}\"", escape_xml_attr(c))?;
+            }
+            if let Some(cr) = &dial.attributes.call_reason {
+                write!(xml, " callReason=\"{}\"", escape_xml_attr(cr))?;
+            }
+            if let Some(r) = &dial.attributes.record {
+                write!(xml, " record=\"{}\"", escape_xml_attr(r.as_str()))?;
+            }
+            if let Some(tr) = &dial.attributes.trim {
+                write!(xml, " trim=\"{}\"", escape_xml_attr(tr.as_str()))?;
+            }
+            if let Some(rsc) = &dial.attributes.recording_status_callback {
+                write!(xml, " recordingStatusCallback=\"{}\"", escape_xml_attr(rsc))?;
+            }
+            if let Some(aob) = dial.attributes.answer_on_bridge {
+                write!(xml, " answerOnBridge=\"{}\"", aob)?;
+            }
+            if let Some(rt) = &dial.attributes.ring_tone {
+                write!(xml, " ringTone=\"{}\"", escape_xml_attr(rt.as_str()))?;
+            }
+            if let Some(e) = &dial.attributes.events {
+                write!(xml, " events=\"{}\"", escape_xml_attr(e.as_str()))?;
+            }
+            if let Some(rm) = &dial.attributes.refer_method {
+                write!(xml, " referMethod=\"{}\"", escape_xml_attr(rm))?;
+            }
+            if let Some(ru) = &dial.attributes.refer_url {
+                write!(xml, " referUrl=\"{}\"", escape_xml_attr(ru))?;
+            }
+            if let Some(seq) = dial.attributes.sequential {
+                write!(xml, " sequential=\"{}\"", seq)?;
+            }
+            if let Some(rt) = &dial.attributes.recording_track {
+                write!(xml, " recordingTrack=\"{}\"", escape_xml_attr(rt.as_str()))?;
+            }
+            if let Some(rsce) = &dial.attributes.recording_status_callback_event {
+                let events = join_values(rsce);
+                write!(
+                    xml,
+                    " recordingStatusCallbackEvent=\"{}\"",
+                    escape_xml_attr(&events)
+                )?;
+            }
+            if let Some(rscm) = &dial.attributes.recording_status_callback_method {
+                write!(
+                    xml,
+                    " recordingStatusCallbackMethod=\"{}\"",
+                    escape_xml_attr(rscm)
+                )?;
+            }
+            xml.write_char('>')?;
 
-        for verb in &self.verbs {
-            match verb {
-                VoiceVerb::Say(say) => {
-                    xml.push_str("  <Say");
-                    if let Some(v) = &say.attributes.voice {
-                        xml.push_str(&format!(" voice=\"{}\"", escape_xml_attr(v)));
-                    }
-                    if let Some(l) = &say.attributes.language {
-                        xml.push_str(&format!(" language=\"{}\"", escape_xml_attr(l)));
-                    }
-                    if let Some(lc) = say.attributes.loop_count {
-                        xml.push_str(&format!(" loop=\"{}\"", lc));
+            // Add plain number if specified
+            if let Some(num) = &dial.number {
+                write_xml_text(xml, num)?;
+            }
+
+            // Add nested nouns
+            for noun in &dial.nested {
+                match noun {
+                    DialNoun::Number(n) => {
+                        options.write_line(xml, 2)?;
+                        xml.write_str("<Number")?;
+                        if let Some(sd) = &n.send_digits {
+                            write!(xml, " sendDigits=\"{}\"", escape_xml_attr(sd))?;
+                        }
+                        if let Some(u) = &n.url {
+                            write!(xml, " url=\"{}\"", escape_xml_attr(u))?;
+                        }
+                        if let Some(m) = &n.method {
+                            write!(xml, " method=\"{}\"", escape_xml_attr(m))?;
+                        }
+                        if let Some(sc) = &n.status_callback {
+                            write!(xml, " statusCallback=\"{}\"", escape_xml_attr(sc))?;
+                        }
+                        if let Some(sce) = &n.status_callback_event {
+                            let events = join_values(sce);
+                            write!(xml, " statusCallbackEvent=\"{}\"", escape_xml_attr(&events))?;
+                        }
+                        if let Some(scm) = &n.status_callback_method {
+                            write!(xml, " statusCallbackMethod=\"{}\"", escape_xml_attr(scm))?;
+                        }
+                        if let Some(cr) = &n.call_reason {
+                            write!(xml, " callReason=\"{}\"", escape_xml_attr(cr))?;
+                        }
+                        if let Some(byoc) = &n.byoc {
+                            write!(xml, " byoc=\"{}\"", escape_xml_attr(byoc))?;
+                        }
+                        if let Some(md) = &n.machine_detection {
+                            write!(xml, " machineDetection=\"{}\"", escape_xml_attr(md))?;
+                        }
+                        if let Some(mdt) = n.machine_detection_timeout {
+                            write!(xml, " machineDetectionTimeout=\"{}\"", mdt)?;
+                        }
+                        if let Some(mdst) = n.machine_detection_speech_threshold {
+                            write!(xml, " machineDetectionSpeechThreshold=\"{}\"", mdst)?;
+                        }
+                        if let Some(mdset) = n.machine_detection_speech_end_threshold {
+                            write!(xml, " machineDetectionSpeechEndThreshold=\"{}\"", mdset)?;
+                        }
+                        if let Some(mdsto) = n.machine_detection_silence_timeout {
+                            write!(xml, " machineDetectionSilenceTimeout=\"{}\"", mdsto)?;
+                        }
+                        if let Some(asc) = &n.amd_status_callback {
+                            write!(xml, " amdStatusCallback=\"{}\"", escape_xml_attr(asc))?;
+                        }
+                        if let Some(ascm) = &n.amd_status_callback_method {
+                            write!(
+                                xml,
+                                " amdStatusCallbackMethod=\"{}\"",
+                                escape_xml_attr(ascm)
+                            )?;
+                        }
+                        write!(xml, ">{}</Number>", escape_xml_text(&n.number))?;
                     }
-                    xml.push('>');
-
-                    // Add message
-                    xml.push_str(&escape_xml_text(&say.message));
-
-                    // Add SSML elements
-                    for ssml in &say.ssml_elements {
-                        match ssml {
-                            SsmlElement::Break { strength, time } => {
-                                xml.push_str("<break");
-                                if let Some(s) = strength {
-                                    xml.push_str(&format!(" strength=\"{}\"", escape_xml_attr(s)));
-                                }
-                                if let Some(t) = time {
-                                    xml.push_str(&format!(" time=\"{}\"", escape_xml_attr(t)));
-                                }
-                                xml.push_str(" />");
-                            }
-                            SsmlElement::Emphasis { level, text } => {
-                                xml.push_str("<emphasis");
-                                if let Some(l) = level {
-                                    xml.push_str(&format!(" level=\"{}\"", escape_xml_attr(l)));
-                                }
-                                xml.push_str(&format!(">{}</emphasis>", escape_xml_text(text)));
+                    DialNoun::Client(c) => {
+                        options.write_line(xml, 2)?;
+                        xml.write_str("<Client")?;
+                        if let Some(u) = &c.url {
+                            write!(xml, " url=\"{}\"", escape_xml_attr(u))?;
+                        }
+                        if let Some(m) = &c.method {
+                            write!(xml, " method=\"{}\"", escape_xml_attr(m))?;
+                        }
+                        if let Some(sce) = &c.status_callback_event {
+                            write!(
+                                xml,
+                                " statusCallbackEvent=\"{}\"",
+                                escape_xml_attr(&join_values(sce))
+                            )?;
+                        }
+                        if let Some(sc) = &c.status_callback {
+                            write!(xml, " statusCallback=\"{}\"", escape_xml_attr(sc))?;
+                        }
+                        if let Some(scm) = &c.status_callback_method {
+                            write!(xml, " statusCallbackMethod=\"{}\"", escape_xml_attr(scm))?;
+                        }
+                        if let Some(cnu) = &c.client_notification_url {
+                            write!(xml, " clientNotificationUrl=\"{}\"", escape_xml_attr(cnu))?;
+                        }
+                        write!(xml, ">{}</Client>", escape_xml_text(&c.identity))?;
+                    }
+                    DialNoun::Conference(conf) => {
+                        options.write_line(xml, 2)?;
+                        xml.write_str("<Conference")?;
+                        if let Some(m) = conf.muted {
+                            write!(xml, " muted=\"{}\"", m)?;
+                        }
+                        if let Some(b) = &conf.beep {
+                            write!(xml, " beep=\"{}\"", escape_xml_attr(b.as_str()))?;
+                        }
+                        if let Some(s) = conf.start_conference_on_enter {
+                            write!(xml, " startConferenceOnEnter=\"{}\"", s)?;
+                        }
+                        if let Some(e) = conf.end_conference_on_exit {
+                            write!(xml, " endConferenceOnExit=\"{}\"", e)?;
+                        }
+                        if let Some(w) = &conf.wait_url {
+                            write!(xml, " waitUrl=\"{}\"", escape_xml_attr(w))?;
+                        }
+                        if let Some(wm) = &conf.wait_method {
+                            write!(xml, " waitMethod=\"{}\"", escape_xml_attr(wm))?;
+                        }
+                        if let Some(mp) = conf.max_participants {
+                            write!(xml, " maxParticipants=\"{}\"", mp)?;
+                        }
+                        if let Some(r) = &conf.record {
+                            write!(xml, " record=\"{}\"", escape_xml_attr(r.as_str()))?;
+                        }
+                        if let Some(reg) = &conf.region {
+                            write!(xml, " region=\"{}\"", escape_xml_attr(reg.as_str()))?;
+                        }
+                        if let Some(c) = &conf.coach {
+                            write!(xml, " coach=\"{}\"", escape_xml_attr(c))?;
+                        }
+                        if let Some(t) = &conf.trim {
+                            write!(xml, " trim=\"{}\"", escape_xml_attr(t.as_str()))?;
+                        }
+                        if let Some(sce) = &conf.status_callback_event {
+                            let events = join_values(sce);
+                            write!(xml, " statusCallbackEvent=\"{}\"", escape_xml_attr(&events))?;
+                        }
+                        if let Some(sc) = &conf.status_callback {
+                            write!(xml, " statusCallback=\"{}\"", escape_xml_attr(sc))?;
+                        }
+                        if let Some(scm) = &conf.status_callback_method {
+                            write!(xml, " statusCallbackMethod=\"{}\"", escape_xml_attr(scm))?;
+                        }
+                        if let Some(rsc) = &conf.recording_status_callback {
+                            write!(xml, " recordingStatusCallback=\"{}\"", escape_xml_attr(rsc))?;
+                        }
+                        if let Some(rscm) = &conf.recording_status_callback_method {
+                            write!(
+                                xml,
+                                " recordingStatusCallbackMethod=\"{}\"",
+                                escape_xml_attr(rscm)
+                            )?;
+                        }
+                        if let Some(rsce) = &conf.recording_status_callback_event {
+                            let events = join_values(rsce);
+                            write!(
+                                xml,
+                                " recordingStatusCallbackEvent=\"{}\"",
+                                escape_xml_attr(&events)
+                            )?;
+                        }
+                        if let Some(ecu) = &conf.event_callback_url {
+                            write!(xml, " eventCallbackUrl=\"{}\"", escape_xml_attr(ecu))?;
+                        }
+                        if let Some(jbs) = &conf.jitter_buffer_size {
+                            write!(
+                                xml,
+                                " jitterBufferSize=\"{}\"",
+                                escape_xml_attr(jbs.as_str())
+                            )?;
+                        }
+                        if let Some(pl) = &conf.participant_label {
+                            write!(xml, " participantLabel=\"{}\"", escape_xml_attr(pl))?;
+                        }
+                        if let Some(cstc) = &conf.call_sid_to_coach {
+                            write!(xml, " callSidToCoach=\"{}\"", escape_xml_attr(cstc))?;
+                        }
+                        if let Some(boce) = conf.beep_on_customer_entrance {
+                            write!(xml, " beepOnCustomerEntrance=\"{}\"", boce)?;
+                        }
+                        if let Some(coaching) = conf.coaching {
+                            write!(xml, " coaching=\"{}\"", coaching)?;
+                        }
+                        write!(xml, ">{}</Conference>", escape_xml_text(&conf.name))?;
+                    }
+                    DialNoun::Queue(q) => {
+                        options.write_line(xml, 2)?;
+                        xml.write_str("<Queue")?;
+                        if let Some(u) = &q.url {
+                            write!(xml, " url=\"{}\"", escape_xml_attr(u))?;
+                        }
+                        if let Some(m) = &q.method {
+                            write!(xml, " method=\"{}\"", escape_xml_attr(m))?;
+                        }
+                        if let Some(rs) = &q.reservation_sid {
+                            write!(xml, " reservationSid=\"{}\"", escape_xml_attr(rs))?;
+                        }
+                        if let Some(pwas) = &q.post_work_activity_sid {
+                            write!(xml, " postWorkActivitySid=\"{}\"", escape_xml_attr(pwas))?;
+                        }
+                        write!(xml, ">{}</Queue>", escape_xml_text(&q.name))?;
+                    }
+                    DialNoun::Sip(s) => {
+                        options.write_line(xml, 2)?;
+                        xml.write_str("<Sip")?;
+                        if let Some(u) = &s.url {
+                            write!(xml, " url=\"{}\"", escape_xml_attr(u))?;
+                        }
+                        if let Some(m) = &s.method {
+                            write!(xml, " method=\"{}\"", escape_xml_attr(m))?;
+                        }
+                        if let Some(user) = &s.username {
+                            write!(xml, " username=\"{}\"", escape_xml_attr(user))?;
+                        }
+                        if let Some(pass) = &s.password {
+                            write!(xml, " password=\"{}\"", escape_xml_attr(pass))?;
+                        }
+                        if let Some(sce) = &s.status_callback_event {
+                            let events = join_values(sce);
+                            write!(xml, " statusCallbackEvent=\"{}\"", escape_xml_attr(&events))?;
+                        }
+                        if let Some(sc) = &s.status_callback {
+                            write!(xml, " statusCallback=\"{}\"", escape_xml_attr(sc))?;
+                        }
+                        if let Some(scm) = &s.status_callback_method {
+                            write!(xml, " statusCallbackMethod=\"{}\"", escape_xml_attr(scm))?;
+                        }
+                        if let Some(codecs) = &s.codecs {
+                            let codec_list = codecs.join(",");
+                            write!(xml, " codecs=\"{}\"", escape_xml_attr(&codec_list))?;
+                        }
+                        xml.write_char('>')?;
+
+                        // Add custom SIP headers as child elements
+                        if let Some(headers) = &s.custom_headers {
+                            for (name, value) in headers {
+                                options.write_line(xml, 3)?;
+                                write!(
+                                    xml,
+                                    "<SipHeader name=\"{}\" value=\"{}\"",
+                                    escape_xml_attr(name),
+                                    escape_xml_attr(value)
+                                )?;
+                                options.write_self_close_as(xml, SelfClosing::Compact)?;
                             }
-                            SsmlElement::Prosody {
-                                pitch,
-                                rate,
-                                volume,
-                                text,
-                            } => {
-                                xml.push_str("<prosody");
-                                if let Some(p) = pitch {
-                                    xml.push_str(&format!(" pitch=\"{}\"", escape_xml_attr(p)));
-                                }
-                                if let Some(r) = rate {
-                                    xml.push_str(&format!(" rate=\"{}\"", escape_xml_attr(r)));
-                                }
-                                if let Some(v) = volume {
-                                    xml.push_str(&format!(" volume=\"{}\"", escape_xml_attr(v)));
+                            options.write_line(xml, 3)?;
+                        }
+
+                        write!(xml, "{}</Sip>", escape_xml_text(&s.sip_url))?;
+                    }
+                    DialNoun::Sim(sim) => {
+                        options.write_line(xml, 2)?;
+                        write!(xml, "<Sim>{}</Sim>", escape_xml_text(&sim.sim_sid))?;
+                    }
+                    DialNoun::Application(app) => {
+                        let has_nested = !app.parameters.is_empty();
+
+                        options.write_line(xml, 2)?;
+                        xml.write_str("<Application")?;
+                        if let Some(sid) = &app.application_sid {
+                            write!(xml, " sid=\"{}\"", escape_xml_attr(sid))?;
+                        }
+                        if let Some(cid) = &app.customer_id {
+                            write!(xml, " customerId=\"{}\"", escape_xml_attr(cid))?;
+                        }
+                        if let Some(cpt) = &app.copy_parent_to {
+                            write!(xml, " copyParentTo=\"{}\"", escape_xml_attr(cpt))?;
+                        }
+
+                        if has_nested {
+                            xml.write_char('>')?;
+                            for param in &app.parameters {
+                                options.write_line(xml, 3)?;
+                                xml.write_str("<Parameter")?;
+                                if let Some(name) = &param.name {
+                                    write!(xml, " name=\"{}\"", escape_xml_attr(name))?;
                                 }
-                                xml.push_str(&format!(">{}</prosody>", escape_xml_text(text)));
-                            }
-                            SsmlElement::SayAs {
-                                interpret_as,
-                                format,
-                                text,
-                            } => {
-                                xml.push_str(&format!(
-                                    "<say-as interpret-as=\"{}\"",
-                                    escape_xml_attr(interpret_as)
-                                ));
-                                if let Some(f) = format {
-                                    xml.push_str(&format!(" format=\"{}\"", escape_xml_attr(f)));
+                                if let Some(value) = &param.value {
+                                    write!(xml, " value=\"{}\"", escape_xml_attr(value))?;
                                 }
-                                xml.push_str(&format!(">{}</say-as>", escape_xml_text(text)));
+                                options.write_self_close(xml)?;
                             }
-                            SsmlElement::Sub { alias, text } => {
-                                xml.push_str(&format!(
-                                    "<sub alias=\"{}\">{}</sub>",
-                                    escape_xml_attr(alias),
-                                    escape_xml_text(text)
-                                ));
-                            }
-                            SsmlElement::P { text } => {
-                                xml.push_str(&format!("<p>{}</p>", escape_xml_text(text)));
+                            options.write_line(xml, 2)?;
+                            xml.write_str("</Application>")?;
+                        } else {
+                            options.write_self_close(xml)?;
+                        }
+                    }
+                    DialNoun::WhatsApp(wa) => {
+                        options.write_line(xml, 2)?;
+                        xml.write_str("<WhatsApp")?;
+                        if let Some(u) = &wa.url {
+                            write!(xml, " url=\"{}\"", escape_xml_attr(u))?;
+                        }
+                        if let Some(m) = &wa.method {
+                            write!(xml, " method=\"{}\"", escape_xml_attr(m))?;
+                        }
+                        write!(xml, ">{}</WhatsApp>", escape_xml_attr(&wa.phone_number))?;
+                    }
+                }
+            }
+
+            if !dial.nested.is_empty() {
+                options.write_line(xml, 1)?;
+            }
+            xml.write_str("</Dial>")?;
+            options.write_newline(xml)?;
+        }
+        VoiceVerb::Hangup(_) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Hangup")?;
+            options.write_self_close(xml)?;
+            options.write_newline(xml)?;
+        }
+        VoiceVerb::Redirect(redirect) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Redirect")?;
+            if let Some(m) = &redirect.attributes.method {
+                write!(xml, " method=\"{}\"", escape_xml_attr(m))?;
+            }
+            write!(xml, ">{}</Redirect>", escape_xml_text(&redirect.url))?;
+            options.write_newline(xml)?;
+        }
+        VoiceVerb::Reject(reject) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Reject")?;
+            if let Some(r) = &reject.attributes.reason {
+                write!(xml, " reason=\"{}\"", escape_xml_attr(r.as_str()))?;
+            }
+            options.write_self_close(xml)?;
+            options.write_newline(xml)?;
+        }
+        VoiceVerb::Gather(gather) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Gather")?;
+            if let Some(i) = &gather.attributes.input {
+                write!(xml, " input=\"{}\"", escape_xml_attr(&join_values(i)))?;
+            }
+            if let Some(a) = &gather.attributes.action {
+                write!(xml, " action=\"{}\"", escape_xml_attr(a))?;
+            }
+            if let Some(m) = &gather.attributes.method {
+                write!(xml, " method=\"{}\"", escape_xml_attr(m))?;
+            }
+            if let Some(t) = gather.attributes.timeout {
+                write!(xml, " timeout=\"{}\"", t)?;
+            }
+            if let Some(f) = &gather.attributes.finish_on_key {
+                write!(xml, " finishOnKey=\"{}\"", escape_xml_attr(f))?;
+            }
+            if let Some(n) = gather.attributes.num_digits {
+                write!(xml, " numDigits=\"{}\"", n)?;
+            }
+            if let Some(p) = &gather.attributes.partial_result_callback {
+                write!(xml, " partialResultCallback=\"{}\"", escape_xml_attr(p))?;
+            }
+            if let Some(l) = &gather.attributes.language {
+                write!(xml, " language=\"{}\"", escape_xml_attr(l))?;
+            }
+            if let Some(h) = &gather.attributes.hints {
+                write!(xml, " hints=\"{}\"", escape_xml_attr(h))?;
+            }
+            if let Some(b) = gather.attributes.barge_in {
+                write!(xml, " bargeIn=\"{}\"", b)?;
+            }
+            if let Some(st) = &gather.attributes.speech_timeout {
+                write!(xml, " speechTimeout=\"{}\"", escape_xml_attr(st))?;
+            }
+            if let Some(aer) = gather.attributes.action_on_empty_result {
+                write!(xml, " actionOnEmptyResult=\"{}\"", aer)?;
+            }
+            if let Some(d) = gather.attributes.debug {
+                write!(xml, " debug=\"{}\"", d)?;
+            }
+            if let Some(dd) = gather.attributes.dtmf_detection {
+                write!(xml, " dtmfDetection=\"{}\"", dd)?;
+            }
+            if let Some(e) = gather.attributes.enhanced {
+                write!(xml, " enhanced=\"{}\"", e)?;
+            }
+            if let Some(mst) = gather.attributes.max_speech_time {
+                write!(xml, " maxSpeechTime=\"{}\"", mst)?;
+            }
+            if let Some(prcm) = &gather.attributes.partial_result_callback_method {
+                write!(
+                    xml,
+                    " partialResultCallbackMethod=\"{}\"",
+                    escape_xml_attr(prcm)
+                )?;
+            }
+            if let Some(pf) = gather.attributes.profanity_filter {
+                write!(xml, " profanityFilter=\"{}\"", pf)?;
+            }
+            if let Some(sm) = &gather.attributes.speech_model {
+                write!(xml, " speechModel=\"{}\"", escape_xml_attr(sm.as_str()))?;
+            }
+
+            if gather.nested.is_empty() {
+                options.write_self_close(xml)?;
+                options.write_newline(xml)?;
+            } else {
+                xml.write_str(">")?;
+                options.write_newline(xml)?;
+                for noun in &gather.nested {
+                    match noun {
+                        GatherNoun::Say(say) => {
+                            options.write_indent(xml, 2)?;
+                            xml.write_str("<Say")?;
+                            if let Some(v) = &say.attributes.voice {
+                                write!(xml, " voice=\"{}\"", escape_xml_attr(&v.to_string()))?;
                             }
-                            SsmlElement::S { text } => {
-                                xml.push_str(&format!("<s>{}</s>", escape_xml_text(text)));
+                            if let Some(l) = &say.attributes.language {
+                                write!(xml, " language=\"{}\"", escape_xml_attr(l))?;
                             }
-                            SsmlElement::Lang { xml_lang, text } => {
-                                xml.push_str(&format!(
-                                    "<lang xml:lang=\"{}\">{}</lang>",
-                                    escape_xml_attr(xml_lang),
-                                    escape_xml_text(text)
-                                ));
+                            if let Some(lc) = say.attributes.loop_count {
+                                write!(xml, " loop=\"{}\"", lc)?;
                             }
-                            SsmlElement::Phoneme { alphabet, ph, text } => {
-                                xml.push_str("<phoneme");
-                                if let Some(a) = alphabet {
-                                    xml.push_str(&format!(" alphabet=\"{}\"", escape_xml_attr(a)));
-                                }
-                                xml.push_str(&format!(
-                                    " ph=\"{}\">{}</phoneme>",
-                                    escape_xml_attr(ph),
-                                    escape_xml_text(text)
-                                ));
+                            xml.write_char('>')?;
+                            write_say_content(xml, say, options)?;
+                            xml.write_str("</Say>")?;
+                            options.write_newline(xml)?;
+                        }
+                        GatherNoun::Play(play) => {
+                            options.write_indent(xml, 2)?;
+                            xml.write_str("<Play")?;
+                            if let Some(d) = &play.attributes.digits {
+                                write!(xml, " digits=\"{}\"", escape_xml_attr(d))?;
                             }
-                            SsmlElement::W { role, text } => {
-                                xml.push_str("<w");
-                                if let Some(r) = role {
-                                    xml.push_str(&format!(" role=\"{}\"", escape_xml_attr(r)));
-                                }
-                                xml.push_str(&format!(">{}</w>", escape_xml_text(text)));
+                            if let Some(lc) = play.attributes.loop_count {
+                                write!(xml, " loop=\"{}\"", lc)?;
                             }
-                            SsmlElement::AmazonEffect { name, text } => {
-                                xml.push_str(&format!(
-                                    "<amazon:effect name=\"{}\">{}</amazon:effect>",
-                                    escape_xml_attr(name),
-                                    escape_xml_text(text)
-                                ));
+                            xml.write_char('>')?;
+                            if let Some(url) = &play.url {
+                                write_xml_text(xml, url)?;
                             }
-                            SsmlElement::AmazonDomain { name, text } => {
-                                xml.push_str(&format!(
-                                    "<amazon:domain name=\"{}\">{}</amazon:domain>",
-                                    escape_xml_attr(name),
-                                    escape_xml_text(text)
-                                ));
+                            xml.write_str("</Play>")?;
+                            options.write_newline(xml)?;
+                        }
+                        GatherNoun::Pause(pause) => {
+                            options.write_indent(xml, 2)?;
+                            xml.write_str("<Pause")?;
+                            if let Some(len) = pause.attributes.length {
+                                write!(xml, " length=\"{}\"", len)?;
                             }
+                            options.write_self_close(xml)?;
+                            options.write_newline(xml)?;
                         }
                     }
-
-                    xml.push_str("</Say>\n");
-                }
-                VoiceVerb::Play(play) => {
-                    xml.push_str("  <Play");
-                    if let Some(d) = &play.attributes.digits {
-                        xml.push_str(&format!(" digits=\"{}\"", escape_xml_attr(d)));
-                    }
-                    if let Some(lc) = play.attributes.loop_count {
-                        xml.push_str(&format!(" loop=\"{}\"", lc));
-                    }
-                    xml.push('>');
-                    if let Some(url) = &play.url {
-                        xml.push_str(&escape_xml_text(url));
-                    }
-                    xml.push_str("</Play>\n");
-                }
-                VoiceVerb::Pause(pause) => {
-                    xml.push_str("  <Pause");
-                    if let Some(len) = pause.attributes.length {
-                        xml.push_str(&format!(" length=\"{}\"", len));
-                    }
-                    xml.push_str(" />\n");
                 }
-                VoiceVerb::Dial(dial) => {
-                    xml.push_str("  <Dial");
-                    if let Some(a) = &dial.attributes.action {
-                        xml.push_str(&format!(" action=\"{}\"", escape_xml_attr(a)));
-                    }
-                    if let Some(m) = &dial.attributes.method {
-                        xml.push_str(&format!(" method=\"{}\"", escape_xml_attr(m)));
-                    }
-                    if let Some(t) = dial.attributes.timeout {
-                        xml.push_str(&format!(" timeout=\"{}\"", t));
-                    }
-                    if let Some(h) = dial.attributes.hangup_on_star {
-                        xml.push_str(&format!(" hangupOnStar=\"{}\"", h));
-                    }
-                    if let Some(tl) = dial.attributes.time_limit {
-                        xml.push_str(&format!(" timeLimit=\"{}\"", tl));
-                    }
-                    if let Some(c) = &dial.attributes.caller_id {
-                        xml.push_str(&format!(" callerId=\"{}\"", escape_xml_attr(c)));
-                    }
-                    if let Some(cr) = &dial.attributes.call_reason {
-                        xml.push_str(&format!(" callReason=\"{}\"", escape_xml_attr(cr)));
-                    }
-                    if let Some(r) = &dial.attributes.record {
-                        xml.push_str(&format!(" record=\"{}\"", escape_xml_attr(r)));
-                    }
-                    if let Some(tr) = &dial.attributes.trim {
-                        xml.push_str(&format!(" trim=\"{}\"", escape_xml_attr(tr)));
-                    }
-                    if let Some(rsc) = &dial.attributes.recording_status_callback {
-                        xml.push_str(&format!(
-                            " recordingStatusCallback=\"{}\"",
-                            escape_xml_attr(rsc)
-                        ));
-                    }
-                    if let Some(aob) = dial.attributes.answer_on_bridge {
-                        xml.push_str(&format!(" answerOnBridge=\"{}\"", aob));
-                    }
-                    if let Some(rt) = &dial.attributes.ring_tone {
-                        xml.push_str(&format!(" ringTone=\"{}\"", escape_xml_attr(rt)));
-                    }
-                    if let Some(e) = &dial.attributes.events {
-                        xml.push_str(&format!(" events=\"{}\"", escape_xml_attr(e)));
-                    }
-                    if let Some(rm) = &dial.attributes.refer_method {
-                        xml.push_str(&format!(" referMethod=\"{}\"", escape_xml_attr(rm)));
-                    }
-                    if let Some(ru) = &dial.attributes.refer_url {
-                        xml.push_str(&format!(" referUrl=\"{}\"", escape_xml_attr(ru)));
-                    }
-                    if let Some(seq) = dial.attributes.sequential {
-                        xml.push_str(&format!(" sequential=\"{}\"", seq));
-                    }
-                    if let Some(rt) = &dial.attributes.recording_track {
-                        xml.push_str(&format!(" recordingTrack=\"{}\"", escape_xml_attr(rt)));
-                    }
-                    if let Some(rsce) = &dial.attributes.recording_status_callback_event {
-                        let events = rsce.join(" ");
-                        xml.push_str(&format!(
-                            " recordingStatusCallbackEvent=\"{}\"",
-                            escape_xml_attr(&events)
-                        ));
-                    }
-                    if let Some(rscm) = &dial.attributes.recording_status_callback_method {
-                        xml.push_str(&format!(
-                            " recordingStatusCallbackMethod=\"{}\"",
-                            escape_xml_attr(rscm)
-                        ));
-                    }
-                    xml.push('>');
-
-                    // Add plain number if specified
-                    if let Some(num) = &dial.number {
-                        xml.push_str(num);
-                    }
+                options.write_indent(xml, 1)?;
+                xml.write_str("</Gather>")?;
+                options.write_newline(xml)?;
+            }
+        }
+        VoiceVerb::Record(record) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Record")?;
+            if let Some(a) = &record.attributes.action {
+                write!(xml, " action=\"{}\"", escape_xml_attr(a))?;
+            }
+            if let Some(m) = &record.attributes.method {
+                write!(xml, " method=\"{}\"", escape_xml_attr(m))?;
+            }
+            if let Some(t) = record.attributes.timeout {
+                write!(xml, " timeout=\"{}\"", t)?;
+            }
+            if let Some(f) = &record.attributes.finish_on_key {
+                write!(xml, " finishOnKey=\"{}\"", escape_xml_attr(f))?;
+            }
+            if let Some(ml) = record.attributes.max_length {
+                write!(xml, " maxLength=\"{}\"", ml)?;
+            }
+            if let Some(pb) = record.attributes.play_beep {
+                write!(xml, " playBeep=\"{}\"", pb)?;
+            }
+            if let Some(tr) = &record.attributes.trim {
+                write!(xml, " trim=\"{}\"", escape_xml_attr(tr.as_str()))?;
+            }
+            if let Some(rsc) = &record.attributes.recording_status_callback {
+                write!(xml, " recordingStatusCallback=\"{}\"", escape_xml_attr(rsc))?;
+            }
+            if let Some(rsce) = &record.attributes.recording_status_callback_event {
+                let events = join_values(rsce);
+                write!(
+                    xml,
+                    " recordingStatusCallbackEvent=\"{}\"",
+                    escape_xml_attr(&events)
+                )?;
+            }
+            if let Some(rscm) = &record.attributes.recording_status_callback_method {
+                write!(
+                    xml,
+                    " recordingStatusCallbackMethod=\"{}\"",
+                    escape_xml_attr(rscm)
+                )?;
+            }
+            if let Some(t) = record.attributes.transcribe {
+                write!(xml, " transcribe=\"{}\"", t)?;
+            }
+            if let Some(tc) = &record.attributes.transcribe_callback {
+                write!(xml, " transcribeCallback=\"{}\"", escape_xml_attr(tc))?;
+            }
+            if let Some(rc) = &record.attributes.recording_channels {
+                write!(
+                    xml,
+                    " recordingChannels=\"{}\"",
+                    escape_xml_attr(rc.as_str())
+                )?;
+            }
+            if let Some(rt) = &record.attributes.recording_track {
+                write!(xml, " recordingTrack=\"{}\"", escape_xml_attr(rt.as_str()))?;
+            }
+            options.write_self_close(xml)?;
+            options.write_newline(xml)?;
+        }
+        VoiceVerb::Connect(connect) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Connect")?;
+            if let Some(a) = &connect.attributes.action {
+                write!(xml, " action=\"{}\"", escape_xml_attr(a))?;
+            }
+            if let Some(m) = &connect.attributes.method {
+                write!(xml, " method=\"{}\"", escape_xml_attr(m))?;
+            }
 
-                    // Add nested nouns
-                    for noun in &dial.nested {
-                        match noun {
-                            DialNoun::Number(n) => {
-                                xml.push_str("\n    <Number");
-                                if let Some(sd) = &n.send_digits {
-                                    xml.push_str(&format!(
-                                        " sendDigits=\"{}\"",
-                                        escape_xml_attr(sd)
-                                    ));
-                                }
-                                if let Some(u) = &n.url {
-                                    xml.push_str(&format!(" url=\"{}\"", escape_xml_attr(u)));
-                                }
-                                if let Some(m) = &n.method {
-                                    xml.push_str(&format!(" method=\"{}\"", escape_xml_attr(m)));
-                                }
-                                if let Some(sc) = &n.status_callback {
-                                    xml.push_str(&format!(
-                                        " statusCallback=\"{}\"",
-                                        escape_xml_attr(sc)
-                                    ));
-                                }
-                                if let Some(sce) = &n.status_callback_event {
-                                    let events = sce.join(" ");
-                                    xml.push_str(&format!(
-                                        " statusCallbackEvent=\"{}\"",
-                                        escape_xml_attr(&events)
-                                    ));
-                                }
-                                if let Some(scm) = &n.status_callback_method {
-                                    xml.push_str(&format!(
-                                        " statusCallbackMethod=\"{}\"",
-                                        escape_xml_attr(scm)
-                                    ));
-                                }
-                                if let Some(cr) = &n.call_reason {
-                                    xml.push_str(&format!(
-                                        " callReason=\"{}\"",
-                                        escape_xml_attr(cr)
-                                    ));
-                                }
-                                if let Some(byoc) = &n.byoc {
-                                    xml.push_str(&format!(" byoc=\"{}\"", escape_xml_attr(byoc)));
-                                }
-                                if let Some(md) = &n.machine_detection {
-                                    xml.push_str(&format!(
-                                        " machineDetection=\"{}\"",
-                                        escape_xml_attr(md)
-                                    ));
-                                }
-                                if let Some(mdt) = n.machine_detection_timeout {
-                                    xml.push_str(&format!(" machineDetectionTimeout=\"{}\"", mdt));
-                                }
-                                if let Some(mdst) = n.machine_detection_speech_threshold {
-                                    xml.push_str(&format!(
-                                        " machineDetectionSpeechThreshold=\"{}\"",
-                                        mdst
-                                    ));
-                                }
-                                if let Some(mdset) = n.machine_detection_speech_end_threshold {
-                                    xml.push_str(&format!(
-                                        " machineDetectionSpeechEndThreshold=\"{}\"",
-                                        mdset
-                                    ));
-                                }
-                                if let Some(mdsto) = n.machine_detection_silence_timeout {
-                                    xml.push_str(&format!(
-                                        " machineDetectionSilenceTimeout=\"{}\"",
-                                        mdsto
-                                    ));
-                                }
-                                if let Some(asc) = &n.amd_status_callback {
-                                    xml.push_str(&format!(
-                                        " amdStatusCallback=\"{}\"",
-                                        escape_xml_attr(asc)
-                                    ));
-                                }
-                                if let Some(ascm) = &n.amd_status_callback_method {
-                                    xml.push_str(&format!(
-                                        " amdStatusCallbackMethod=\"{}\"",
-                                        escape_xml_attr(ascm)
-                                    ));
-                                }
-                                xml.push_str(&format!(">{}</Number>", escape_xml_text(&n.number)));
+            if connect.nested.is_empty() {
+                options.write_self_close(xml)?;
+                options.write_newline(xml)?;
+            } else {
+                xml.write_char('>')?;
+
+                for noun in &connect.nested {
+                    match noun {
+                        ConnectNoun::Stream(stream) => {
+                            options.write_line(xml, 2)?;
+                            xml.write_str("<Stream")?;
+                            if let Some(n) = &stream.name {
+                                write!(xml, " name=\"{}\"", escape_xml_attr(n))?;
                             }
-                            DialNoun::Client(c) => {
-                                xml.push_str("\n    <Client");
-                                if let Some(u) = &c.url {
-                                    xml.push_str(&format!(" url=\"{}\"", escape_xml_attr(u)));
-                                }
-                                if let Some(m) = &c.method {
-                                    xml.push_str(&format!(" method=\"{}\"", escape_xml_attr(m)));
-                                }
-                                if let Some(sce) = &c.status_callback_event {
-                                    xml.push_str(&format!(
-                                        " statusCallbackEvent=\"{}\"",
-                                        escape_xml_attr(&sce.join(" "))
-                                    ));
-                                }
-                                if let Some(sc) = &c.status_callback {
-                                    xml.push_str(&format!(
-                                        " statusCallback=\"{}\"",
-                                        escape_xml_attr(sc)
-                                    ));
-                                }
-                                if let Some(scm) = &c.status_callback_method {
-                                    xml.push_str(&format!(
-                                        " statusCallbackMethod=\"{}\"",
-                                        escape_xml_attr(scm)
-                                    ));
-                                }
-                                if let Some(cnu) = &c.client_notification_url {
-                                    xml.push_str(&format!(
-                                        " clientNotificationUrl=\"{}\"",
-                                        escape_xml_attr(cnu)
-                                    ));
-                                }
-                                xml.push_str(&format!(
-                                    ">{}</Client>",
-                                    escape_xml_text(&c.identity)
-                                ));
+                            if let Some(cn) = &stream.connector_name {
+                                write!(xml, " connectorName=\"{}\"", escape_xml_attr(cn))?;
                             }
-                            DialNoun::Conference(conf) => {
-                                xml.push_str("\n    <Conference");
-                                if let Some(m) = conf.muted {
-                                    xml.push_str(&format!(" muted=\"{}\"", m));
-                                }
-                                if let Some(b) = &conf.beep {
-                                    xml.push_str(&format!(" beep=\"{}\"", escape_xml_attr(b)));
-                                }
-                                if let Some(s) = conf.start_conference_on_enter {
-                                    xml.push_str(&format!(" startConferenceOnEnter=\"{}\"", s));
-                                }
-                                if let Some(e) = conf.end_conference_on_exit {
-                                    xml.push_str(&format!(" endConferenceOnExit=\"{}\"", e));
-                                }
-                                if let Some(w) = &conf.wait_url {
-                                    xml.push_str(&format!(" waitUrl=\"{}\"", escape_xml_attr(w)));
-                                }
-                                if let Some(wm) = &conf.wait_method {
-                                    xml.push_str(&format!(
-                                        " waitMethod=\"{}\"",
-                                        escape_xml_attr(wm)
-                                    ));
-                                }
-                                if let Some(mp) = conf.max_participants {
-                                    xml.push_str(&format!(" maxParticipants=\"{}\"", mp));
-                                }
-                                if let Some(r) = &conf.record {
-                                    xml.push_str(&format!(" record=\"{}\"", escape_xml_attr(r)));
-                                }
-                                if let Some(reg) = &conf.region {
-                                    xml.push_str(&format!(" region=\"{}\"", escape_xml_attr(reg)));
-                                }
-                                if let Some(c) = &conf.coach {
-                                    xml.push_str(&format!(" coach=\"{}\"", escape_xml_attr(c)));
-                                }
-                                if let Some(t) = &conf.trim {
-                                    xml.push_str(&format!(" trim=\"{}\"", escape_xml_attr(t)));
-                                }
-                                if let Some(sce) = &conf.status_callback_event {
-                                    let events = sce.join(" ");
-                                    xml.push_str(&format!(
-                                        " statusCallbackEvent=\"{}\"",
-                                        escape_xml_attr(&events)
-                                    ));
-                                }
-                                if let Some(sc) = &conf.status_callback {
-                                    xml.push_str(&format!(
-                                        " statusCallback=\"{}\"",
-                                        escape_xml_attr(sc)
-                                    ));
-                                }
-                                if let Some(scm) = &conf.status_callback_method {
-                                    xml.push_str(&format!(
-                                        " statusCallbackMethod=\"{}\"",
-                                        escape_xml_attr(scm)
-                                    ));
-                                }
-                                if let Some(rsc) = &conf.recording_status_callback {
-                                    xml.push_str(&format!(
-                                        " recordingStatusCallback=\"{}\"",
-                                        escape_xml_attr(rsc)
-                                    ));
-                                }
-                                if let Some(rscm) = &conf.recording_status_callback_method {
-                                    xml.push_str(&format!(
-                                        " recordingStatusCallbackMethod=\"{}\"",
-                                        escape_xml_attr(rscm)
-                                    ));
-                                }
-                                if let Some(rsce) = &conf.recording_status_callback_event {
-                                    let events = rsce.join(" ");
-                                    xml.push_str(&format!(
-                                        " recordingStatusCallbackEvent=\"{}\"",
-                                        escape_xml_attr(&events)
-                                    ));
-                                }
-                                if let Some(ecu) = &conf.event_callback_url {
-                                    xml.push_str(&format!(
-                                        " eventCallbackUrl=\"{}\"",
-                                        escape_xml_attr(ecu)
-                                    ));
-                                }
-                                if let Some(jbs) = &conf.jitter_buffer_size {
-                                    xml.push_str(&format!(
-                                        " jitterBufferSize=\"{}\"",
-                                        escape_xml_attr(jbs)
-                                    ));
-                                }
-                                if let Some(pl) = &conf.participant_label {
-                                    xml.push_str(&format!(
-                                        " participantLabel=\"{}\"",
-                                        escape_xml_attr(pl)
-                                    ));
-                                }
-                                if let Some(cstc) = &conf.call_sid_to_coach {
-                                    xml.push_str(&format!(
-                                        " callSidToCoach=\"{}\"",
-                                        escape_xml_attr(cstc)
-                                    ));
-                                }
-                                if let Some(boce) = conf.beep_on_customer_entrance {
-                                    xml.push_str(&format!(" beepOnCustomerEntrance=\"{}\"", boce));
-                                }
-                                if let Some(coaching) = conf.coaching {
-                                    xml.push_str(&format!(" coaching=\"{}\"", coaching));
-                                }
-                                xml.push_str(&format!(
-                                    ">{}</Conference>",
-                                    escape_xml_text(&conf.name)
-                                ));
+                            if let Some(u) = &stream.url {
+                                write!(xml, " url=\"{}\"", escape_xml_attr(u))?;
                             }
-                            DialNoun::Queue(q) => {
-                                xml.push_str("\n    <Queue");
-                                if let Some(u) = &q.url {
-                                    xml.push_str(&format!(" url=\"{}\"", escape_xml_attr(u)));
-                                }
-                                if let Some(m) = &q.method {
-                                    xml.push_str(&format!(" method=\"{}\"", escape_xml_attr(m)));
-                                }
-                                xml.push_str(&format!(">{}</Queue>", escape_xml_text(&q.name)));
+                            if let Some(t) = &stream.track {
+                                write!(xml, " track=\"{}\"", escape_xml_attr(t.as_str()))?;
                             }
-                            DialNoun::Sip(s) => {
-                                xml.push_str("\n    <Sip");
-                                if let Some(u) = &s.url {
-                                    xml.push_str(&format!(" url=\"{}\"", escape_xml_attr(u)));
-                                }
-                                if let Some(m) = &s.method {
-                                    xml.push_str(&format!(" method=\"{}\"", escape_xml_attr(m)));
-                                }
-                                if let Some(user) = &s.username {
-                                    xml.push_str(&format!(
-                                        " username=\"{}\"",
-                                        escape_xml_attr(user)
-                                    ));
-                                }
-                                if let Some(pass) = &s.password {
-                                    xml.push_str(&format!(
-                                        " password=\"{}\"",
-                                        escape_xml_attr(pass)
-                                    ));
-                                }
-                                if let Some(sce) = &s.status_callback_event {
-                                    let events = sce.join(" ");
-                                    xml.push_str(&format!(
-                                        " statusCallbackEvent=\"{}\"",
-                                        escape_xml_attr(&events)
-                                    ));
-                                }
-                                if let Some(sc) = &s.status_callback {
-                                    xml.push_str(&format!(
-                                        " statusCallback=\"{}\"",
-                                        escape_xml_attr(sc)
-                                    ));
-                                }
-                                if let Some(scm) = &s.status_callback_method {
-                                    xml.push_str(&format!(
-                                        " statusCallbackMethod=\"{}\"",
-                                        escape_xml_attr(scm)
-                                    ));
-                                }
-                                if let Some(codecs) = &s.codecs {
-                                    let codec_list = codecs.join(",");
-                                    xml.push_str(&format!(
-                                        " codecs=\"{}\"",
-                                        escape_xml_attr(&codec_list)
-                                    ));
+                            if let Some(sc) = &stream.status_callback {
+                                write!(xml, " statusCallback=\"{}\"", escape_xml_attr(sc))?;
+                            }
+                            if let Some(scm) = &stream.status_callback_method {
+                                write!(xml, " statusCallbackMethod=\"{}\"", escape_xml_attr(scm))?;
+                            }
+                            if stream.parameters.is_empty() {
+                                options.write_self_close(xml)?;
+                            } else {
+                                xml.write_char('>')?;
+                                for param in &stream.parameters {
+                                    options.write_line(xml, 3)?;
+                                    xml.write_str("<Parameter")?;
+                                    if let Some(n) = &param.name {
+                                        write!(xml, " name=\"{}\"", escape_xml_attr(n))?;
+                                    }
+                                    if let Some(v) = &param.value {
+                                        write!(xml, " value=\"{}\"", escape_xml_attr(v))?;
+                                    }
+                                    options.write_self_close(xml)?;
                                 }
-                                xml.push('>');
-
-                                // Add custom SIP headers as child elements
-                                if let Some(headers) = &s.custom_headers {
-                                    for (name, value) in headers {
-                                        xml.push_str(&format!(
-                                            "\n      <SipHeader name=\"{}\" value=\"{}\"/>",
-                                            escape_xml_attr(name),
-                                            escape_xml_attr(value)
-                                        ));
+                                options.write_line(xml, 2)?;
+                                xml.write_str("</Stream>")?;
+                            }
+                        }
+                        ConnectNoun::Room(room) => {
+                            options.write_line(xml, 2)?;
+                            xml.write_str("<Room")?;
+                            if let Some(pi) = &room.participant_identity {
+                                write!(xml, " participantIdentity=\"{}\"", escape_xml_attr(pi))?;
+                            }
+                            xml.write_char('>')?;
+                            if let Some(n) = &room.name {
+                                xml.write_str(&escape_xml_attr(n))?;
+                            }
+                            xml.write_str("</Room>")?;
+                        }
+                        ConnectNoun::Conversation(conv) => {
+                            options.write_line(xml, 2)?;
+                            xml.write_str("<Conversation")?;
+                            if let Some(sid) = &conv.service_instance_sid {
+                                write!(xml, " serviceInstanceSid=\"{}\"", escape_xml_attr(sid))?;
+                            }
+                            options.write_self_close(xml)?;
+                        }
+                        ConnectNoun::VirtualAgent(va) => {
+                            options.write_line(xml, 2)?;
+                            xml.write_str("<VirtualAgent")?;
+                            if let Some(cn) = &va.connector_name {
+                                write!(xml, " connectorName=\"{}\"", escape_xml_attr(cn))?;
+                            }
+                            if let Some(l) = &va.language {
+                                write!(xml, " language=\"{}\"", escape_xml_attr(l))?;
+                            }
+                            if va.parameters.is_empty() {
+                                options.write_self_close(xml)?;
+                            } else {
+                                xml.write_char('>')?;
+                                for param in &va.parameters {
+                                    options.write_line(xml, 3)?;
+                                    xml.write_str("<Parameter")?;
+                                    if let Some(n) = &param.name {
+                                        write!(xml, " name=\"{}\"", escape_xml_attr(n))?;
+                                    }
+                                    if let Some(v) = &param.value {
+                                        write!(xml, " value=\"{}\"", escape_xml_attr(v))?;
                                     }
-                                    xml.push_str("\n      ");
+                                    options.write_self_close(xml)?;
                                 }
+                                options.write_line(xml, 2)?;
+                                xml.write_str("</VirtualAgent>")?;
+                            }
+                        }
+                        ConnectNoun::Autopilot(ap) => {
+                            options.write_line(xml, 2)?;
+                            xml.write_str("<Autopilot")?;
+                            xml.write_char('>')?;
+                            if let Some(n) = &ap.name {
+                                xml.write_str(&escape_xml_attr(n))?;
+                            }
+                            xml.write_str("</Autopilot>")?;
+                        }
+                        ConnectNoun::AiSession(ai) => {
+                            options.write_line(xml, 2)?;
+                            xml.write_str("<AiSession")?;
+                            if let Some(sid) = &ai.assistant_sid {
+                                write!(xml, " assistantSid=\"{}\"", escape_xml_attr(sid))?;
+                            }
+                            options.write_self_close(xml)?;
+                        }
+                        ConnectNoun::ConversationRelaySession(crs) => {
+                            options.write_line(xml, 2)?;
+                            xml.write_str("<ConversationRelaySession")?;
+                            if let Some(c) = &crs.connector {
+                                write!(xml, " connector=\"{}\"", escape_xml_attr(c))?;
+                            }
+                            if let Some(sc) = &crs.session_configuration {
+                                write!(xml, " sessionConfiguration=\"{}\"", escape_xml_attr(sc))?;
+                            }
+                            options.write_self_close(xml)?;
+                        }
+                        ConnectNoun::Assistant(asst) => {
+                            options.write_line(xml, 2)?;
+                            xml.write_str("<Assistant")?;
+                            if let Some(sid) = &asst.sid {
+                                write!(xml, " sid=\"{}\"", escape_xml_attr(sid))?;
+                            }
+                            options.write_self_close(xml)?;
+                        }
+                        ConnectNoun::ConversationRelay(cr) => {
+                            let has_nested = !cr.languages.is_empty() || !cr.parameters.is_empty();
 
-                                xml.push_str(&format!("{}</Sip>", escape_xml_text(&s.sip_url)));
+                            options.write_line(xml, 2)?;
+                            xml.write_str("<ConversationRelay")?;
+                            if let Some(u) = &cr.url {
+                                write!(xml, " url=\"{}\"", escape_xml_attr(u))?;
+                            }
+                            if let Some(g) = &cr.welcome_greeting {
+                                write!(xml, " welcomeGreeting=\"{}\"", escape_xml_attr(g))?;
+                            }
+                            if let Some(v) = &cr.voice {
+                                write!(xml, " voice=\"{}\"", escape_xml_attr(v))?;
+                            }
+                            if let Some(l) = &cr.language {
+                                write!(xml, " language=\"{}\"", escape_xml_attr(l))?;
+                            }
+                            if let Some(d) = cr.dtmf_detection {
+                                write!(xml, " dtmfDetection=\"{}\"", d)?;
+                            }
+                            if let Some(i) = cr.interruptible {
+                                write!(xml, " interruptible=\"{}\"", i)?;
+                            }
+                            if let Some(s) = &cr.interruption_sensitivity {
+                                write!(xml, " interruptionSensitivity=\"{}\"", escape_xml_attr(s))?;
+                            }
+                            if let Some(m) = &cr.speech_model {
+                                write!(xml, " speechModel=\"{}\"", escape_xml_attr(m))?;
                             }
-                            DialNoun::Sim(sim) => {
-                                xml.push_str(&format!(
-                                    "\n    <Sim>{}</Sim>",
-                                    escape_xml_text(&sim.sim_sid)
-                                ));
+                            if let Some(p) = cr.profanity_filter {
+                                write!(xml, " profanityFilter=\"{}\"", p)?;
+                            }
+                            if let Some(t) = cr.transcription_enabled {
+                                write!(xml, " transcriptionEnabled=\"{}\"", t)?;
+                            }
+                            if let Some(sc) = &cr.status_callback {
+                                write!(xml, " statusCallback=\"{}\"", escape_xml_attr(sc))?;
+                            }
+                            if let Some(scm) = &cr.status_callback_method {
+                                write!(xml, " statusCallbackMethod=\"{}\"", escape_xml_attr(scm))?;
+                            }
+                            if let Some(md) = cr.max_duration {
+                                write!(xml, " maxDuration=\"{}\"", md)?;
                             }
-                            DialNoun::Application(app) => {
-                                let has_nested = !app.parameters.is_empty();
 
-                                xml.push_str("\n    <Application");
-                                if let Some(sid) = &app.application_sid {
-                                    xml.push_str(&format!(" sid=\"{}\"", escape_xml_attr(sid)));
-                                }
-                                if let Some(cid) = &app.customer_id {
-                                    xml.push_str(&format!(
-                                        " customerId=\"{}\"",
-                                        escape_xml_attr(cid)
-                                    ));
-                                }
-                                if let Some(cpt) = &app.copy_parent_to {
-                                    xml.push_str(&format!(
-                                        " copyParentTo=\"{}\"",
-                                        escape_xml_attr(cpt)
-                                    ));
-                                }
+                            if has_nested {
+                                xml.write_char('>')?;
 
-                                if has_nested {
-                                    xml.push('>');
-                                    for param in &app.parameters {
-                                        xml.push_str("\n      <Parameter");
-                                        if let Some(name) = &param.name {
-                                            xml.push_str(&format!(
-                                                " name=\"{}\"",
-                                                escape_xml_attr(name)
-                                            ));
-                                        }
-                                        if let Some(value) = &param.value {
-                                            xml.push_str(&format!(
-                                                " value=\"{}\"",
-                                                escape_xml_attr(value)
-                                            ));
-                                        }
-                                        xml.push_str(" />");
+                                // Add Language elements
+                                for lang in &cr.languages {
+                                    options.write_line(xml, 3)?;
+                                    xml.write_str("<Language")?;
+                                    if let Some(code) = &lang.language_code {
+                                        write!(xml, " code=\"{}\"", escape_xml_attr(code))?;
                                     }
-                                    xml.push_str("\n    </Application>");
-                                } else {
-                                    xml.push_str(" />");
-                                }
-                            }
-                            DialNoun::WhatsApp(wa) => {
-                                xml.push_str("\n    <WhatsApp");
-                                if let Some(u) = &wa.url {
-                                    xml.push_str(&format!(" url=\"{}\"", escape_xml_attr(u)));
+                                    if let Some(tts) = &lang.tts_provider {
+                                        write!(xml, " ttsProvider=\"{}\"", escape_xml_attr(tts))?;
+                                    }
+                                    if let Some(stt) = &lang.stt_provider {
+                                        write!(xml, " sttProvider=\"{}\"", escape_xml_attr(stt))?;
+                                    }
+                                    options.write_self_close(xml)?;
                                 }
-                                if let Some(m) = &wa.method {
-                                    xml.push_str(&format!(" method=\"{}\"", escape_xml_attr(m)));
+
+                                // Add Parameter elements
+                                for param in &cr.parameters {
+                                    options.write_line(xml, 3)?;
+                                    xml.write_str("<Parameter")?;
+                                    if let Some(name) = &param.name {
+                                        write!(xml, " name=\"{}\"", escape_xml_attr(name))?;
+                                    }
+                                    if let Some(value) = &param.value {
+                                        write!(xml, " value=\"{}\"", escape_xml_attr(value))?;
+                                    }
+                                    options.write_self_close(xml)?;
                                 }
-                                xml.push_str(&format!(
-                                    ">{}</WhatsApp>",
-                                    escape_xml_attr(&wa.phone_number)
-                                ));
+
+                                options.write_line(xml, 2)?;
+                                xml.write_str("</ConversationRelay>")?;
+                            } else {
+                                options.write_self_close(xml)?;
                             }
                         }
                     }
-
-                    if !dial.nested.is_empty() {
-                        xml.push_str("\n  ");
-                    }
-                    xml.push_str("</Dial>\n");
-                }
-                VoiceVerb::Hangup(_) => {
-                    xml.push_str("  <Hangup />\n");
-                }
-                VoiceVerb::Redirect(redirect) => {
-                    xml.push_str("  <Redirect");
-                    if let Some(m) = &redirect.attributes.method {
-                        xml.push_str(&format!(" method=\"{}\"", escape_xml_attr(m)));
-                    }
-                    xml.push_str(&format!(">{}</Redirect>\n", escape_xml_text(&redirect.url)));
                 }
-                VoiceVerb::Reject(reject) => {
-                    xml.push_str("  <Reject");
-                    if let Some(r) = &reject.attributes.reason {
-                        xml.push_str(&format!(" reason=\"{}\"", escape_xml_attr(r)));
-                    }
-                    xml.push_str(" />\n");
-                }
-                VoiceVerb::Gather(gather) => {
-                    xml.push_str("  <Gather");
-                    if let Some(i) = &gather.attributes.input {
-                        xml.push_str(&format!(" input=\"{}\"", escape_xml_attr(&i.join(" "))));
-                    }
-                    if let Some(a) = &gather.attributes.action {
-                        xml.push_str(&format!(" action=\"{}\"", escape_xml_attr(a)));
-                    }
-                    if let Some(m) = &gather.attributes.method {
-                        xml.push_str(&format!(" method=\"{}\"", escape_xml_attr(m)));
-                    }
-                    if let Some(t) = gather.attributes.timeout {
-                        xml.push_str(&format!(" timeout=\"{}\"", t));
-                    }
-                    if let Some(f) = &gather.attributes.finish_on_key {
-                        xml.push_str(&format!(" finishOnKey=\"{}\"", escape_xml_attr(f)));
-                    }
-                    if let Some(n) = gather.attributes.num_digits {
-                        xml.push_str(&format!(" numDigits=\"{}\"", n));
-                    }
-                    if let Some(p) = &gather.attributes.partial_result_callback {
-                        xml.push_str(&format!(
-                            " partialResultCallback=\"{}\"",
-                            escape_xml_attr(p)
-                        ));
-                    }
-                    if let Some(l) = &gather.attributes.language {
-                        xml.push_str(&format!(" language=\"{}\"", escape_xml_attr(l)));
-                    }
-                    if let Some(h) = &gather.attributes.hints {
-                        xml.push_str(&format!(" hints=\"{}\"", escape_xml_attr(h)));
-                    }
-                    if let Some(b) = gather.attributes.barge_in {
-                        xml.push_str(&format!(" bargeIn=\"{}\"", b));
-                    }
-                    if let Some(st) = &gather.attributes.speech_timeout {
-                        xml.push_str(&format!(" speechTimeout=\"{}\"", escape_xml_attr(st)));
-                    }
-                    if let Some(aer) = gather.attributes.action_on_empty_result {
-                        xml.push_str(&format!(" actionOnEmptyResult=\"{}\"", aer));
-                    }
-                    if let Some(d) = gather.attributes.debug {
-                        xml.push_str(&format!(" debug=\"{}\"", d));
-                    }
-                    if let Some(dd) = gather.attributes.dtmf_detection {
-                        xml.push_str(&format!(" dtmfDetection=\"{}\"", dd));
-                    }
-                    if let Some(e) = gather.attributes.enhanced {
-                        xml.push_str(&format!(" enhanced=\"{}\"", e));
-                    }
-                    if let Some(mst) = gather.attributes.max_speech_time {
-                        xml.push_str(&format!(" maxSpeechTime=\"{}\"", mst));
-                    }
-                    if let Some(prcm) = &gather.attributes.partial_result_callback_method {
-                        xml.push_str(&format!(
-                            " partialResultCallbackMethod=\"{}\"",
-                            escape_xml_attr(prcm)
-                        ));
-                    }
-                    if let Some(pf) = gather.attributes.profanity_filter {
-                        xml.push_str(&format!(" profanityFilter=\"{}\"", pf));
-                    }
-                    if let Some(sm) = &gather.attributes.speech_model {
-                        xml.push_str(&format!(" speechModel=\"{}\"", escape_xml_attr(sm)));
-                    }
 
-                    if gather.nested.is_empty() {
-                        xml.push_str(" />\n");
-                    } else {
-                        xml.push_str(">\n");
-                        for noun in &gather.nested {
-                            match noun {
-                                GatherNoun::Say(say) => {
-                                    xml.push_str("    <Say");
-                                    if let Some(v) = &say.attributes.voice {
-                                        xml.push_str(&format!(" voice=\"{}\"", escape_xml_attr(v)));
-                                    }
-                                    if let Some(l) = &say.attributes.language {
-                                        xml.push_str(&format!(
-                                            " language=\"{}\"",
-                                            escape_xml_attr(l)
-                                        ));
-                                    }
-                                    if let Some(lc) = say.attributes.loop_count {
-                                        xml.push_str(&format!(" loop=\"{}\"", lc));
-                                    }
-                                    xml.push_str(&format!(
-                                        ">{}</Say>\n",
-                                        escape_xml_text(&say.message)
-                                    ));
-                                }
-                                GatherNoun::Play(play) => {
-                                    xml.push_str("    <Play");
-                                    if let Some(d) = &play.attributes.digits {
-                                        xml.push_str(&format!(
-                                            " digits=\"{}\"",
-                                            escape_xml_attr(d)
-                                        ));
-                                    }
-                                    if let Some(lc) = play.attributes.loop_count {
-                                        xml.push_str(&format!(" loop=\"{}\"", lc));
-                                    }
-                                    xml.push('>');
-                                    if let Some(url) = &play.url {
-                                        xml.push_str(&escape_xml_text(url));
-                                    }
-                                    xml.push_str("</Play>\n");
-                                }
-                                GatherNoun::Pause(pause) => {
-                                    xml.push_str("    <Pause");
-                                    if let Some(len) = pause.attributes.length {
-                                        xml.push_str(&format!(" length=\"{}\"", len));
-                                    }
-                                    xml.push_str(" />\n");
-                                }
-                            }
-                        }
-                        xml.push_str("  </Gather>\n");
-                    }
+                options.write_line(xml, 1)?;
+                xml.write_str("</Connect>")?;
+                options.write_newline(xml)?;
+            }
+        }
+        VoiceVerb::Stop(_) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Stop")?;
+            options.write_self_close(xml)?;
+            options.write_newline(xml)?;
+        }
+        VoiceVerb::Echo(_) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Echo")?;
+            options.write_self_close(xml)?;
+            options.write_newline(xml)?;
+        }
+        VoiceVerb::Leave(_) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Leave")?;
+            options.write_self_close(xml)?;
+            options.write_newline(xml)?;
+        }
+        VoiceVerb::Sms(sms) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Sms")?;
+            if let Some(t) = &sms.attributes.to {
+                write!(xml, " to=\"{}\"", escape_xml_attr(t))?;
+            }
+            if let Some(f) = &sms.attributes.from {
+                write!(xml, " from=\"{}\"", escape_xml_attr(f))?;
+            }
+            if let Some(a) = &sms.attributes.action {
+                write!(xml, " action=\"{}\"", escape_xml_attr(a))?;
+            }
+            if let Some(m) = &sms.attributes.method {
+                write!(xml, " method=\"{}\"", escape_xml_attr(m))?;
+            }
+            if let Some(sc) = &sms.attributes.status_callback {
+                write!(xml, " statusCallback=\"{}\"", escape_xml_attr(sc))?;
+            }
+            write!(xml, ">{}</Sms>", escape_xml_text(&sms.message))?;
+            options.write_newline(xml)?;
+        }
+        VoiceVerb::Enqueue(enqueue) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Enqueue")?;
+            if let Some(a) = &enqueue.attributes.action {
+                write!(xml, " action=\"{}\"", escape_xml_attr(a))?;
+            }
+            if let Some(m) = &enqueue.attributes.method {
+                write!(xml, " method=\"{}\"", escape_xml_attr(m))?;
+            }
+            if let Some(wu) = &enqueue.attributes.wait_url {
+                write!(xml, " waitUrl=\"{}\"", escape_xml_attr(wu))?;
+            }
+            if let Some(wum) = &enqueue.attributes.wait_url_method {
+                write!(xml, " waitUrlMethod=\"{}\"", escape_xml_attr(wum))?;
+            }
+            if let Some(ws) = &enqueue.attributes.workflow_sid {
+                write!(xml, " workflowSid=\"{}\"", escape_xml_attr(ws))?;
+            }
+            if let Some(mqs) = enqueue.attributes.max_queue_size {
+                write!(xml, " maxQueueSize=\"{}\"", mqs)?;
+            }
+
+            if enqueue.name.is_none() && enqueue.task.is_none() {
+                options.write_self_close(xml)?;
+                options.write_newline(xml)?;
+            } else {
+                xml.write_char('>')?;
+                if let Some(name) = &enqueue.name {
+                    xml.write_str(&escape_xml_attr(name))?;
                 }
-                VoiceVerb::Record(record) => {
-                    xml.push_str("  <Record");
-                    if let Some(a) = &record.attributes.action {
-                        xml.push_str(&format!(" action=\"{}\"", escape_xml_attr(a)));
-                    }
-                    if let Some(m) = &record.attributes.method {
-                        xml.push_str(&format!(" method=\"{}\"", escape_xml_attr(m)));
-                    }
-                    if let Some(t) = record.attributes.timeout {
-                        xml.push_str(&format!(" timeout=\"{}\"", t));
+                if let Some(task) = &enqueue.task {
+                    options.write_line(xml, 2)?;
+                    xml.write_str("<Task")?;
+                    if let Some(sid) = &task.task_sid {
+                        write!(xml, " sid=\"{}\"", escape_xml_attr(sid))?;
                     }
-                    if let Some(f) = &record.attributes.finish_on_key {
-                        xml.push_str(&format!(" finishOnKey=\"{}\"", escape_xml_attr(f)));
-                    }
-                    if let Some(ml) = record.attributes.max_length {
-                        xml.push_str(&format!(" maxLength=\"{}\"", ml));
-                    }
-                    if let Some(pb) = record.attributes.play_beep {
-                        xml.push_str(&format!(" playBeep=\"{}\"", pb));
-                    }
-                    if let Some(tr) = &record.attributes.trim {
-                        xml.push_str(&format!(" trim=\"{}\"", escape_xml_attr(tr)));
-                    }
-                    if let Some(rsc) = &record.attributes.recording_status_callback {
-                        xml.push_str(&format!(
-                            " recordingStatusCallback=\"{}\"",
-                            escape_xml_attr(rsc)
-                        ));
-                    }
-                    if let Some(rsce) = &record.attributes.recording_status_callback_event {
-                        let events = rsce.join(" ");
-                        xml.push_str(&format!(
-                            " recordingStatusCallbackEvent=\"{}\"",
-                            escape_xml_attr(&events)
-                        ));
-                    }
-                    if let Some(rscm) = &record.attributes.recording_status_callback_method {
-                        xml.push_str(&format!(
-                            " recordingStatusCallbackMethod=\"{}\"",
-                            escape_xml_attr(rscm)
-                        ));
+                    options.write_self_close(xml)?;
+                }
+                options.write_line(xml, 1)?;
+                xml.write_str("</Enqueue>")?;
+                options.write_newline(xml)?;
+            }
+        }
+        VoiceVerb::Queue(queue) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Queue")?;
+            if let Some(u) = &queue.attributes.url {
+                write!(xml, " url=\"{}\"", escape_xml_attr(u))?;
+            }
+            if let Some(m) = &queue.attributes.method {
+                write!(xml, " method=\"{}\"", escape_xml_attr(m))?;
+            }
+            if let Some(rs) = &queue.attributes.reservation_sid {
+                write!(xml, " reservationSid=\"{}\"", escape_xml_attr(rs))?;
+            }
+            if let Some(pwas) = &queue.attributes.post_work_activity_sid {
+                write!(xml, " postWorkActivitySid=\"{}\"", escape_xml_attr(pwas))?;
+            }
+            write!(xml, ">{}</Queue>", escape_xml_attr(&queue.name))?;
+            options.write_newline(xml)?;
+        }
+        VoiceVerb::Pay(pay) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Pay")?;
+            if let Some(i) = &pay.attributes.input {
+                write!(xml, " input=\"{}\"", escape_xml_attr(i.as_str()))?;
+            }
+            if let Some(a) = &pay.attributes.action {
+                write!(xml, " action=\"{}\"", escape_xml_attr(a))?;
+            }
+            if let Some(ca) = &pay.attributes.charge_amount {
+                write!(xml, " chargeAmount=\"{}\"", escape_xml_attr(ca))?;
+            }
+            if let Some(c) = &pay.attributes.currency {
+                write!(xml, " currency=\"{}\"", escape_xml_attr(c))?;
+            }
+            if let Some(pc) = &pay.attributes.payment_connector {
+                write!(xml, " paymentConnector=\"{}\"", escape_xml_attr(pc))?;
+            }
+            if let Some(pm) = &pay.attributes.payment_method {
+                write!(xml, " paymentMethod=\"{}\"", escape_xml_attr(pm.as_str()))?;
+            }
+            if let Some(t) = pay.attributes.timeout {
+                write!(xml, " timeout=\"{}\"", t)?;
+            }
+            if let Some(scm) = &pay.attributes.status_callback_method {
+                write!(xml, " statusCallbackMethod=\"{}\"", escape_xml_attr(scm))?;
+            }
+            if let Some(sc) = &pay.attributes.status_callback {
+                write!(xml, " statusCallback=\"{}\"", escape_xml_attr(sc))?;
+            }
+            if let Some(bat) = &pay.attributes.bank_account_type {
+                write!(
+                    xml,
+                    " bankAccountType=\"{}\"",
+                    escape_xml_attr(bat.as_str())
+                )?;
+            }
+            if let Some(d) = &pay.attributes.description {
+                write!(xml, " description=\"{}\"", escape_xml_attr(d))?;
+            }
+            if let Some(l) = &pay.attributes.language {
+                write!(xml, " language=\"{}\"", escape_xml_attr(l.as_str()))?;
+            }
+            if let Some(ma) = pay.attributes.max_attempts {
+                write!(xml, " maxAttempts=\"{}\"", ma)?;
+            }
+            if let Some(m) = &pay.attributes.method {
+                write!(xml, " method=\"{}\"", escape_xml_attr(m))?;
+            }
+            if let Some(mpcl) = pay.attributes.min_postal_code_length {
+                write!(xml, " minPostalCodeLength=\"{}\"", mpcl)?;
+            }
+            if let Some(pc) = pay.attributes.postal_code {
+                write!(xml, " postalCode=\"{}\"", pc)?;
+            }
+            if let Some(sc) = pay.attributes.security_code {
+                write!(xml, " securityCode=\"{}\"", sc)?;
+            }
+            if let Some(tt) = &pay.attributes.token_type {
+                write!(xml, " tokenType=\"{}\"", escape_xml_attr(tt.as_str()))?;
+            }
+            if let Some(vct) = &pay.attributes.valid_card_types {
+                write!(
+                    xml,
+                    " validCardTypes=\"{}\"",
+                    escape_xml_attr(&join_values(vct))
+                )?;
+            }
+
+            if pay.prompts.is_empty() && pay.parameters.is_empty() {
+                options.write_self_close(xml)?;
+                options.write_newline(xml)?;
+            } else {
+                xml.write_char('>')?;
+
+                for prompt in &pay.prompts {
+                    options.write_line(xml, 2)?;
+                    xml.write_str("<Prompt")?;
+                    if let Some(f) = &prompt.attributes.for_attr {
+                        write!(xml, " for=\"{}\"", escape_xml_attr(f.as_str()))?;
                     }
-                    if let Some(t) = record.attributes.transcribe {
-                        xml.push_str(&format!(" transcribe=\"{}\"", t));
+                    if let Some(a) = &prompt.attributes.attempt {
+                        let attempts = a
+                            .iter()
+                            .map(|n| n.to_string())
+                            .collect::<Vec<_>>()
+                            .join(" ");
+                        write!(xml, " attempt=\"{}\"", escape_xml_attr(&attempts))?;
                     }
-                    if let Some(tc) = &record.attributes.transcribe_callback {
-                        xml.push_str(&format!(" transcribeCallback=\"{}\"", escape_xml_attr(tc)));
+                    if let Some(ct) = &prompt.attributes.card_type {
+                        let types = join_values(ct);
+                        write!(xml, " cardType=\"{}\"", escape_xml_attr(&types))?;
                     }
-                    if let Some(rc) = &record.attributes.recording_channels {
-                        xml.push_str(&format!(" recordingChannels=\"{}\"", escape_xml_attr(rc)));
+                    if let Some(et) = &prompt.attributes.error_type {
+                        let types = join_values(et);
+                        write!(xml, " errorType=\"{}\"", escape_xml_attr(&types))?;
                     }
-                    if let Some(rt) = &record.attributes.recording_track {
-                        xml.push_str(&format!(" recordingTrack=\"{}\"", escape_xml_attr(rt)));
+                    if let Some(rmi) = prompt.attributes.require_matching_inputs {
+                        write!(xml, " requireMatchingInputs=\"{}\"", rmi)?;
                     }
-                    xml.push_str(" />\n");
+                    options.write_self_close(xml)?;
                 }
-                VoiceVerb::Connect(connect) => {
-                    xml.push_str("  <Connect");
-                    if let Some(a) = &connect.attributes.action {
-                        xml.push_str(&format!(" action=\"{}\"", escape_xml_attr(a)));
+
+                for param in &pay.parameters {
+                    options.write_line(xml, 2)?;
+                    xml.write_str("<Parameter")?;
+                    if let Some(n) = &param.name {
+                        write!(xml, " name=\"{}\"", escape_xml_attr(n))?;
                     }
-                    if let Some(m) = &connect.attributes.method {
-                        xml.push_str(&format!(" method=\"{}\"", escape_xml_attr(m)));
+                    if let Some(v) = &param.value {
+                        write!(xml, " value=\"{}\"", escape_xml_attr(v))?;
                     }
+                    options.write_self_close(xml)?;
+                }
 
-                    if connect.nested.is_empty() {
-                        xml.push_str(" />\n");
-                    } else {
-                        xml.push('>');
-
-                        for noun in &connect.nested {
-                            match noun {
-                                ConnectNoun::Stream(stream) => {
-                                    xml.push_str("\n    <Stream");
-                                    if let Some(n) = &stream.name {
-                                        xml.push_str(&format!(" name=\"{}\"", escape_xml_attr(n)));
-                                    }
-                                    if let Some(cn) = &stream.connector_name {
-                                        xml.push_str(&format!(
-                                            " connectorName=\"{}\"",
-                                            escape_xml_attr(cn)
-                                        ));
-                                    }
-                                    if let Some(u) = &stream.url {
-                                        xml.push_str(&format!(" url=\"{}\"", escape_xml_attr(u)));
-                                    }
-                                    if let Some(t) = &stream.track {
-                                        xml.push_str(&format!(" track=\"{}\"", escape_xml_attr(t)));
-                                    }
-                                    if stream.parameters.is_empty() {
-                                        xml.push_str(" />");
-                                    } else {
-                                        xml.push('>');
-                                        for param in &stream.parameters {
-                                            xml.push_str("\n      <Parameter");
-                                            if let Some(n) = &param.name {
-                                                xml.push_str(&format!(
-                                                    " name=\"{}\"",
-                                                    escape_xml_attr(n)
-                                                ));
-                                            }
-                                            if let Some(v) = &param.value {
-                                                xml.push_str(&format!(
-                                                    " value=\"{}\"",
-                                                    escape_xml_attr(v)
-                                                ));
-                                            }
-                                            xml.push_str(" />");
-                                        }
-                                        xml.push_str("\n    </Stream>");
-                                    }
-                                }
-                                ConnectNoun::Room(room) => {
-                                    xml.push_str("\n    <Room");
-                                    if let Some(pi) = &room.participant_identity {
-                                        xml.push_str(&format!(
-                                            " participantIdentity=\"{}\"",
-                                            escape_xml_attr(pi)
-                                        ));
-                                    }
-                                    xml.push('>');
-                                    if let Some(n) = &room.name {
-                                        xml.push_str(&escape_xml_attr(n));
-                                    }
-                                    xml.push_str("</Room>");
-                                }
-                                ConnectNoun::Conversation(conv) => {
-                                    xml.push_str("\n    <Conversation");
-                                    if let Some(sid) = &conv.service_instance_sid {
-                                        xml.push_str(&format!(
-                                            " serviceInstanceSid=\"{}\"",
-                                            escape_xml_attr(sid)
-                                        ));
-                                    }
-                                    xml.push_str(" />");
-                                }
-                                ConnectNoun::VirtualAgent(va) => {
-                                    xml.push_str("\n    <VirtualAgent");
-                                    if let Some(cn) = &va.connector_name {
-                                        xml.push_str(&format!(
-                                            " connectorName=\"{}\"",
-                                            escape_xml_attr(cn)
-                                        ));
-                                    }
-                                    if let Some(l) = &va.language {
-                                        xml.push_str(&format!(
-                                            " language=\"{}\"",
-                                            escape_xml_attr(l)
-                                        ));
-                                    }
-                                    if va.parameters.is_empty() {
-                                        xml.push_str(" />");
-                                    } else {
-                                        xml.push('>');
-                                        for param in &va.parameters {
-                                            xml.push_str("\n      <Parameter");
-                                            if let Some(n) = &param.name {
-                                                xml.push_str(&format!(
-                                                    " name=\"{}\"",
-                                                    escape_xml_attr(n)
-                                                ));
-                                            }
-                                            if let Some(v) = &param.value {
-                                                xml.push_str(&format!(
-                                                    " value=\"{}\"",
-                                                    escape_xml_attr(v)
-                                                ));
-                                            }
-                                            xml.push_str(" />");
-                                        }
-                                        xml.push_str("\n    </VirtualAgent>");
-                                    }
-                                }
-                                ConnectNoun::Autopilot(ap) => {
-                                    xml.push_str("\n    <Autopilot");
-                                    xml.push('>');
-                                    if let Some(n) = &ap.name {
-                                        xml.push_str(&escape_xml_attr(n));
-                                    }
-                                    xml.push_str("</Autopilot>");
-                                }
-                                ConnectNoun::AiSession(ai) => {
-                                    xml.push_str("\n    <AiSession");
-                                    if let Some(sid) = &ai.assistant_sid {
-                                        xml.push_str(&format!(
-                                            " assistantSid=\"{}\"",
-                                            escape_xml_attr(sid)
-                                        ));
-                                    }
-                                    xml.push_str(" />");
-                                }
-                                ConnectNoun::ConversationRelaySession(crs) => {
-                                    xml.push_str("\n    <ConversationRelaySession");
-                                    if let Some(c) = &crs.connector {
-                                        xml.push_str(&format!(
-                                            " connector=\"{}\"",
-                                            escape_xml_attr(c)
-                                        ));
-                                    }
-                                    if let Some(sc) = &crs.session_configuration {
-                                        xml.push_str(&format!(
-                                            " sessionConfiguration=\"{}\"",
-                                            escape_xml_attr(sc)
-                                        ));
-                                    }
-                                    xml.push_str(" />");
-                                }
-                                ConnectNoun::Assistant(asst) => {
-                                    xml.push_str("\n    <Assistant");
-                                    if let Some(sid) = &asst.sid {
-                                        xml.push_str(&format!(" sid=\"{}\"", escape_xml_attr(sid)));
-                                    }
-                                    xml.push_str(" />");
-                                }
-                                ConnectNoun::ConversationRelay(cr) => {
-                                    let has_nested =
-                                        !cr.languages.is_empty() || !cr.parameters.is_empty();
+                options.write_line(xml, 1)?;
+                xml.write_str("</Pay>")?;
+                options.write_newline(xml)?;
+            }
+        }
+        VoiceVerb::Prompt(prompt) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Prompt")?;
+            if let Some(f) = &prompt.attributes.for_attr {
+                write!(xml, " for=\"{}\"", escape_xml_attr(f.as_str()))?;
+            }
+            if let Some(a) = &prompt.attributes.attempt {
+                let attempts = a
+                    .iter()
+                    .map(|n| n.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                write!(xml, " attempt=\"{}\"", escape_xml_attr(&attempts))?;
+            }
+            if let Some(ct) = &prompt.attributes.card_type {
+                let types = join_values(ct);
+                write!(xml, " cardType=\"{}\"", escape_xml_attr(&types))?;
+            }
+            if let Some(et) = &prompt.attributes.error_type {
+                let types = join_values(et);
+                write!(xml, " errorType=\"{}\"", escape_xml_attr(&types))?;
+            }
+            if let Some(rmi) = prompt.attributes.require_matching_inputs {
+                write!(xml, " requireMatchingInputs=\"{}\"", rmi)?;
+            }
+            options.write_self_close(xml)?;
+            options.write_newline(xml)?;
+        }
+        VoiceVerb::Refer(refer) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Refer")?;
+            if let Some(a) = &refer.attributes.action {
+                write!(xml, " action=\"{}\"", escape_xml_attr(a))?;
+            }
+            if let Some(m) = &refer.attributes.method {
+                write!(xml, " method=\"{}\"", escape_xml_attr(m))?;
+            }
 
-                                    xml.push_str("\n    <ConversationRelay");
-                                    if let Some(u) = &cr.url {
-                                        xml.push_str(&format!(" url=\"{}\"", escape_xml_attr(u)));
-                                    }
-                                    if let Some(g) = &cr.welcome_greeting {
-                                        xml.push_str(&format!(
-                                            " welcomeGreeting=\"{}\"",
-                                            escape_xml_attr(g)
-                                        ));
-                                    }
-                                    if let Some(v) = &cr.voice {
-                                        xml.push_str(&format!(" voice=\"{}\"", escape_xml_attr(v)));
-                                    }
-                                    if let Some(l) = &cr.language {
-                                        xml.push_str(&format!(
-                                            " language=\"{}\"",
-                                            escape_xml_attr(l)
-                                        ));
-                                    }
-                                    if let Some(d) = cr.dtmf_detection {
-                                        xml.push_str(&format!(" dtmfDetection=\"{}\"", d));
-                                    }
-                                    if let Some(i) = cr.interruptible {
-                                        xml.push_str(&format!(" interruptible=\"{}\"", i));
-                                    }
-                                    if let Some(s) = &cr.interruption_sensitivity {
-                                        xml.push_str(&format!(
-                                            " interruptionSensitivity=\"{}\"",
-                                            escape_xml_attr(s)
-                                        ));
-                                    }
-                                    if let Some(m) = &cr.speech_model {
-                                        xml.push_str(&format!(
-                                            " speechModel=\"{}\"",
-                                            escape_xml_attr(m)
-                                        ));
-                                    }
-                                    if let Some(p) = cr.profanity_filter {
-                                        xml.push_str(&format!(" profanityFilter=\"{}\"", p));
-                                    }
-                                    if let Some(t) = cr.transcription_enabled {
-                                        xml.push_str(&format!(" transcriptionEnabled=\"{}\"", t));
-                                    }
-                                    if let Some(sc) = &cr.status_callback {
-                                        xml.push_str(&format!(
-                                            " statusCallback=\"{}\"",
-                                            escape_xml_attr(sc)
-                                        ));
-                                    }
-                                    if let Some(scm) = &cr.status_callback_method {
-                                        xml.push_str(&format!(
-                                            " statusCallbackMethod=\"{}\"",
-                                            escape_xml_attr(scm)
-                                        ));
-                                    }
-                                    if let Some(md) = cr.max_duration {
-                                        xml.push_str(&format!(" maxDuration=\"{}\"", md));
-                                    }
+            if let Some(sip) = &refer.refer_sip {
+                xml.write_char('>')?;
+                options.write_line(xml, 2)?;
+                write!(xml, "<Sip>{}</Sip>", escape_xml_attr(&sip.sip_url))?;
+                options.write_line(xml, 1)?;
+                xml.write_str("</Refer>")?;
+                options.write_newline(xml)?;
+            } else {
+                options.write_self_close(xml)?;
+                options.write_newline(xml)?;
+            }
+        }
+        VoiceVerb::Start(start) => {
+            options.write_indent(xml, 1)?;
+            xml.write_str("<Start")?;
+            if let Some(a) = &start.attributes.action {
+                write!(xml, " action=\"{}\"", escape_xml_attr(a))?;
+            }
+            if let Some(m) = &start.attributes.method {
+                write!(xml, " method=\"{}\"", escape_xml_attr(m))?;
+            }
+
+            if start.nested.is_empty() {
+                options.write_self_close(xml)?;
+                options.write_newline(xml)?;
+            } else {
+                xml.write_char('>')?;
+
+                for noun in &start.nested {
+                    match noun {
+                        StartNoun::Stream(stream) => {
+                            options.write_line(xml, 2)?;
+                            xml.write_str("<Stream")?;
+                            if let Some(n) = &stream.name {
+                                write!(xml, " name=\"{}\"", escape_xml_attr(n))?;
+                            }
+                            if let Some(cn) = &stream.connector_name {
+                                write!(xml, " connectorName=\"{}\"", escape_xml_attr(cn))?;
+                            }
+                            if let Some(u) = &stream.url {
+                                write!(xml, " url=\"{}\"", escape_xml_attr(u))?;
+                            }
+                            if let Some(t) = &stream.track {
+                                write!(xml, " track=\"{}\"", escape_xml_attr(t.as_str()))?;
+                            }
+                            if let Some(sc) = &stream.status_callback {
+                                write!(xml, " statusCallback=\"{}\"", escape_xml_attr(sc))?;
+                            }
+                            if let Some(scm) = &stream.status_callback_method {
+                                write!(xml, " statusCallbackMethod=\"{}\"", escape_xml_attr(scm))?;
+                            }
 
-                                    if has_nested {
-                                        xml.push('>');
-
-                                        // Add Language elements
-                                        for lang in &cr.languages {
-                                            xml.push_str("\n      <Language");
-                                            if let Some(code) = &lang.language_code {
-                                                xml.push_str(&format!(
-                                                    " code=\"{}\"",
-                                                    escape_xml_attr(code)
-                                                ));
-                                            }
-                                            if let Some(tts) = &lang.tts_provider {
-                                                xml.push_str(&format!(
-                                                    " ttsProvider=\"{}\"",
-                                                    escape_xml_attr(tts)
-                                                ));
-                                            }
-                                            if let Some(stt) = &lang.stt_provider {
-                                                xml.push_str(&format!(
-                                                    " sttProvider=\"{}\"",
-                                                    escape_xml_attr(stt)
-                                                ));
-                                            }
-                                            xml.push_str(" />");
-                                        }
-
-                                        // Add Parameter elements
-                                        for param in &cr.parameters {
-                                            xml.push_str("\n      <Parameter");
-                                            if let Some(name) = &param.name {
-                                                xml.push_str(&format!(
-                                                    " name=\"{}\"",
-                                                    escape_xml_attr(name)
-                                                ));
-                                            }
-                                            if let Some(value) = &param.value {
-                                                xml.push_str(&format!(
-                                                    " value=\"{}\"",
-                                                    escape_xml_attr(value)
-                                                ));
-                                            }
-                                            xml.push_str(" />");
-                                        }
-
-                                        xml.push_str("\n    </ConversationRelay>");
-                                    } else {
-                                        xml.push_str(" />");
+                            if stream.parameters.is_empty() {
+                                options.write_self_close(xml)?;
+                            } else {
+                                xml.write_char('>')?;
+                                for param in &stream.parameters {
+                                    options.write_line(xml, 3)?;
+                                    xml.write_str("<Parameter")?;
+                                    if let Some(n) = &param.name {
+                                        write!(xml, " name=\"{}\"", escape_xml_attr(n))?;
+                                    }
+                                    if let Some(v) = &param.value {
+                                        write!(xml, " value=\"{}\"", escape_xml_attr(v))?;
                                     }
+                                    options.write_self_close(xml)?;
                                 }
+                                options.write_line(xml, 2)?;
+                                xml.write_str("</Stream>")?;
                             }
                         }
-
-                        xml.push_str("\n  </Connect>\n");
-                    }
-                }
-                VoiceVerb::Stop(_) => {
-                    xml.push_str("  <Stop />\n");
-                }
-                VoiceVerb::Echo(_) => {
-                    xml.push_str("  <Echo />\n");
-                }
-                VoiceVerb::Leave(_) => {
-                    xml.push_str("  <Leave />\n");
-                }
-                VoiceVerb::Sms(sms) => {
-                    xml.push_str("  <Sms");
-                    if let Some(t) = &sms.attributes.to {
-                        xml.push_str(&format!(" to=\"{}\"", escape_xml_attr(t)));
-                    }
-                    if let Some(f) = &sms.attributes.from {
-                        xml.push_str(&format!(" from=\"{}\"", escape_xml_attr(f)));
-                    }
-                    if let Some(a) = &sms.attributes.action {
-                        xml.push_str(&format!(" action=\"{}\"", escape_xml_attr(a)));
-                    }
-                    if let Some(m) = &sms.attributes.method {
-                        xml.push_str(&format!(" method=\"{}\"", escape_xml_attr(m)));
-                    }
-                    if let Some(sc) = &sms.attributes.status_callback {
-                        xml.push_str(&format!(" statusCallback=\"{}\"", escape_xml_attr(sc)));
-                    }
-                    xml.push_str(&format!(">{}</Sms>\n", escape_xml_text(&sms.message)));
-                }
-                VoiceVerb::Enqueue(enqueue) => {
-                    xml.push_str("  <Enqueue");
-                    if let Some(a) = &enqueue.attributes.action {
-                        xml.push_str(&format!(" action=\"{}\"", escape_xml_attr(a)));
-                    }
-                    if let Some(m) = &enqueue.attributes.method {
-                        xml.push_str(&format!(" method=\"{}\"", escape_xml_attr(m)));
-                    }
-                    if let Some(wu) = &enqueue.attributes.wait_url {
-                        xml.push_str(&format!(" waitUrl=\"{}\"", escape_xml_attr(wu)));
-                    }
-                    if let Some(wum) = &enqueue.attributes.wait_url_method {
-                        xml.push_str(&format!(" waitUrlMethod=\"{}\"", escape_xml_attr(wum)));
-                    }
-                    if let Some(ws) = &enqueue.attributes.workflow_sid {
-                        xml.push_str(&format!(" workflowSid=\"{}\"", escape_xml_attr(ws)));
-                    }
-                    if let Some(mqs) = enqueue.attributes.max_queue_size {
-                        xml.push_str(&format!(" maxQueueSize=\"{}\"", mqs));
-                    }
-
-                    if enqueue.name.is_none() && enqueue.task.is_none() {
-                        xml.push_str(" />\n");
-                    } else {
-                        xml.push('>');
-                        if let Some(name) = &enqueue.name {
-                            xml.push_str(&escape_xml_attr(name));
-                        }
-                        if let Some(task) = &enqueue.task {
-                            xml.push_str("\n    <Task");
-                            if let Some(sid) = &task.task_sid {
-                                xml.push_str(&format!(" sid=\"{}\"", escape_xml_attr(sid)));
+                        StartNoun::Siprec(siprec) => {
+                            options.write_line(xml, 2)?;
+                            xml.write_str("<Siprec")?;
+                            if let Some(n) = &siprec.name {
+                                write!(xml, " name=\"{}\"", escape_xml_attr(n))?;
+                            }
+                            if let Some(cn) = &siprec.connector_name {
+                                write!(xml, " connectorName=\"{}\"", escape_xml_attr(cn))?;
                             }
-                            xml.push_str(" />");
+                            if let Some(t) = &siprec.track {
+                                write!(xml, " track=\"{}\"", escape_xml_attr(t.as_str()))?;
+                            }
+                            options.write_self_close(xml)?;
                         }
-                        xml.push_str("\n  </Enqueue>\n");
-                    }
-                }
-                VoiceVerb::Queue(queue) => {
-                    xml.push_str("  <Queue");
-                    if let Some(u) = &queue.attributes.url {
-                        xml.push_str(&format!(" url=\"{}\"", escape_xml_attr(u)));
-                    }
-                    if let Some(m) = &queue.attributes.method {
-                        xml.push_str(&format!(" method=\"{}\"", escape_xml_attr(m)));
-                    }
-                    if let Some(rs) = &queue.attributes.reservation_sid {
-                        xml.push_str(&format!(" reservationSid=\"{}\"", escape_xml_attr(rs)));
-                    }
-                    if let Some(pwas) = &queue.attributes.post_work_activity_sid {
-                        xml.push_str(&format!(
-                            " postWorkActivitySid=\"{}\"",
-                            escape_xml_attr(pwas)
-                        ));
-                    }
-                    xml.push_str(&format!(">{}</Queue>\n", escape_xml_attr(&queue.name)));
-                }
-                VoiceVerb::Pay(pay) => {
-                    xml.push_str("  <Pay");
-                    if let Some(i) = &pay.attributes.input {
-                        xml.push_str(&format!(" input=\"{}\"", escape_xml_attr(i)));
-                    }
-                    if let Some(a) = &pay.attributes.action {
-                        xml.push_str(&format!(" action=\"{}\"", escape_xml_attr(a)));
-                    }
-                    if let Some(ca) = &pay.attributes.charge_amount {
-                        xml.push_str(&format!(" chargeAmount=\"{}\"", escape_xml_attr(ca)));
-                    }
-                    if let Some(c) = &pay.attributes.currency {
-                        xml.push_str(&format!(" currency=\"{}\"", escape_xml_attr(c)));
-                    }
-                    if let Some(pc) = &pay.attributes.payment_connector {
-                        xml.push_str(&format!(" paymentConnector=\"{}\"", escape_xml_attr(pc)));
-                    }
-                    if let Some(pm) = &pay.attributes.payment_method {
-                        xml.push_str(&format!(" paymentMethod=\"{}\"", escape_xml_attr(pm)));
-                    }
-                    if let Some(t) = pay.attributes.timeout {
-                        xml.push_str(&format!(" timeout=\"{}\"", t));
-                    }
-                    if let Some(scm) = &pay.attributes.status_callback_method {
-                        xml.push_str(&format!(
-                            " statusCallbackMethod=\"{}\"",
-                            escape_xml_attr(scm)
-                        ));
-                    }
-                    if let Some(sc) = &pay.attributes.status_callback {
-                        xml.push_str(&format!(" statusCallback=\"{}\"", escape_xml_attr(sc)));
-                    }
-
-                    if pay.prompts.is_empty() && pay.parameters.is_empty() {
-                        xml.push_str(" />\n");
-                    } else {
-                        xml.push('>');
-
-                        for prompt in &pay.prompts {
-                            xml.push_str("\n    <Prompt");
-                            if let Some(f) = &prompt.attributes.for_attr {
-                                xml.push_str(&format!(" for=\"{}\"", escape_xml_attr(f)));
+                        StartNoun::Transcription(trans) => {
+                            options.write_line(xml, 2)?;
+                            xml.write_str("<Transcription")?;
+                            if let Some(n) = &trans.attributes.name {
+                                write!(xml, " name=\"{}\"", escape_xml_attr(n))?;
+                            }
+                            if let Some(t) = &trans.attributes.track {
+                                write!(xml, " track=\"{}\"", escape_xml_attr(t.as_str()))?;
+                            }
+                            if let Some(lc) = &trans.attributes.language_code {
+                                write!(xml, " languageCode=\"{}\"", escape_xml_attr(lc))?;
                             }
-                            if let Some(a) = &prompt.attributes.attempt {
-                                let attempts = a
-                                    .iter()
-                                    .map(|n| n.to_string())
-                                    .collect::<Vec<_>>()
-                                    .join(" ");
-                                xml.push_str(&format!(
-                                    " attempt=\"{}\"",
-                                    escape_xml_attr(&attempts)
-                                ));
+                            if let Some(enable) = trans.attributes.enable_automatic_punctuation {
+                                write!(xml, " enableAutomaticPunctuation=\"{}\"", enable)?;
                             }
-                            if let Some(ct) = &prompt.attributes.card_type {
-                                let types = ct.join(" ");
-                                xml.push_str(&format!(" cardType=\"{}\"", escape_xml_attr(&types)));
+                            if let Some(h) = &trans.attributes.hints {
+                                write!(xml, " hints=\"{}\"", escape_xml_attr(h))?;
                             }
-                            if let Some(et) = &prompt.attributes.error_type {
-                                let types = et.join(" ");
-                                xml.push_str(&format!(
-                                    " errorType=\"{}\"",
-                                    escape_xml_attr(&types)
-                                ));
+                            if let Some(label) = &trans.attributes.inbound_track_label {
+                                write!(xml, " inboundTrackLabel=\"{}\"", escape_xml_attr(label))?;
                             }
-                            xml.push_str(" />");
+                            if let Some(service) = &trans.attributes.intelligence_service {
+                                write!(
+                                    xml,
+                                    " intelligenceService=\"{}\"",
+                                    escape_xml_attr(service)
+                                )?;
+                            }
+                            if let Some(label) = &trans.attributes.outbound_track_label {
+                                write!(xml, " outboundTrackLabel=\"{}\"", escape_xml_attr(label))?;
+                            }
+                            if let Some(enable) = trans.attributes.partial_results {
+                                write!(xml, " partialResults=\"{}\"", enable)?;
+                            }
+                            if let Some(enable) = trans.attributes.profanity_filter {
+                                write!(xml, " profanityFilter=\"{}\"", enable)?;
+                            }
+                            if let Some(model) = &trans.attributes.speech_model {
+                                write!(xml, " speechModel=\"{}\"", escape_xml_attr(model))?;
+                            }
+                            if let Some(method) = &trans.attributes.status_callback_method {
+                                write!(
+                                    xml,
+                                    " statusCallbackMethod=\"{}\"",
+                                    escape_xml_attr(method)
+                                )?;
+                            }
+                            if let Some(url) = &trans.attributes.status_callback_url {
+                                write!(xml, " statusCallbackUrl=\"{}\"", escape_xml_attr(url))?;
+                            }
+                            if let Some(engine) = &trans.attributes.transcription_engine {
+                                write!(
+                                    xml,
+                                    " transcriptionEngine=\"{}\"",
+                                    escape_xml_attr(engine)
+                                )?;
+                            }
+                            options.write_self_close(xml)?;
                         }
-
-                        for param in &pay.parameters {
-                            xml.push_str("\n    <Parameter");
-                            if let Some(n) = &param.name {
-                                xml.push_str(&format!(" name=\"{}\"", escape_xml_attr(n)));
+                        StartNoun::Recording(rec) => {
+                            options.write_line(xml, 2)?;
+                            xml.write_str("<Recording")?;
+                            if let Some(cb) = &rec.recording_status_callback {
+                                write!(
+                                    xml,
+                                    " recordingStatusCallback=\"{}\"",
+                                    escape_xml_attr(cb)
+                                )?;
+                            }
+                            if let Some(m) = &rec.recording_status_callback_method {
+                                write!(
+                                    xml,
+                                    " recordingStatusCallbackMethod=\"{}\"",
+                                    escape_xml_attr(m)
+                                )?;
+                            }
+                            if let Some(e) = &rec.recording_status_callback_event {
+                                write!(
+                                    xml,
+                                    " recordingStatusCallbackEvent=\"{}\"",
+                                    escape_xml_attr(&join_values(e))
+                                )?;
                             }
-                            if let Some(v) = &param.value {
-                                xml.push_str(&format!(" value=\"{}\"", escape_xml_attr(v)));
+                            if let Some(tr) = &rec.trim {
+                                write!(xml, " trim=\"{}\"", escape_xml_attr(tr.as_str()))?;
                             }
-                            xml.push_str(" />");
+                            if let Some(t) = &rec.track {
+                                write!(xml, " track=\"{}\"", escape_xml_attr(t.as_str()))?;
+                            }
+                            if let Some(ch) = &rec.channels {
+                                write!(xml, " channels=\"{}\"", escape_xml_attr(ch.as_str()))?;
+                            }
+                            options.write_self_close(xml)?;
                         }
-
-                        xml.push_str("\n  </Pay>\n");
                     }
                 }
-                VoiceVerb::Prompt(prompt) => {
-                    xml.push_str("  <Prompt");
-                    if let Some(f) = &prompt.attributes.for_attr {
-                        xml.push_str(&format!(" for=\"{}\"", escape_xml_attr(f)));
-                    }
-                    if let Some(a) = &prompt.attributes.attempt {
-                        let attempts = a
-                            .iter()
-                            .map(|n| n.to_string())
-                            .collect::<Vec<_>>()
-                            .join(" ");
-                        xml.push_str(&format!(" attempt=\"{}\"", escape_xml_attr(&attempts)));
-                    }
-                    if let Some(ct) = &prompt.attributes.card_type {
-                        let types = ct.join(" ");
-                        xml.push_str(&format!(" cardType=\"{}\"", escape_xml_attr(&types)));
+
+                options.write_line(xml, 1)?;
+                xml.write_str("</Start>")?;
+                options.write_newline(xml)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl TwiML for VoiceResponse {
+    /// [`RenderOptions::pretty`], with `<SipHeader/>`
+    fn default_render_options(&self) -> RenderOptions {
+        RenderOptions::pretty().self_closing_per_element()
+    }
+
+    fn write_xml_with<W: fmt::Write + ?Sized>(
+        &self,
+        xml: &mut W,
+        options: &RenderOptions,
+    ) -> fmt::Result {
+        options.write_declaration(xml)?;
+
+        // Add comments before Response
+        for comment in &self.comments_before {
+            options.write_leading_comment(xml, comment)?;
+        }
+
+        xml.write_str("<Response>")?;
+
+        // Add comments inside Response
+        for comment in &self.comments {
+            options.write_comment_line(xml, 1, comment)?;
+        }
+        options.write_newline(xml)?;
+
+        for (index, verb) in self.verbs.iter().enumerate() {
+            write_comments_between(xml, &self.comments_between, index, options)?;
+            write_verb(xml, verb, options)?;
+        }
+        write_comments_between(xml, &self.comments_between, self.verbs.len(), options)?;
+
+        xml.write_str("</Response>")?;
+
+        // Add comments after Response
+        for comment in &self.comments_after {
+            options.write_comment_line(xml, 0, comment)?;
+        }
+
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<Vec<ValidationError>> {
+        Ok(self.validate_model())
+    }
+
+    fn validate_strict(&self) -> Result<Vec<ValidationError>> {
+        Ok(self.validate_model_strict())
+    }
+}
+
+// ============================================================================
+// Fragments - verbs rendered once and spliced into many responses
+// ============================================================================
+
+/// Top-level verbs rendered and escaped once, for splicing into later responses
+///
+/// Built with [`VoiceResponse::to_fragment`] or, for a single verb,
+/// [`VoiceVerb::to_fragment`], and added to a response with
+/// [`VoiceResponse::fragment`]. A response rendered with the same
+/// [`RenderOptions`] copies the stored XML as is; one rendered with different
+/// options re-renders the verbs so the layout still matches. The typed verbs
+/// are kept so validation sees them too. Cloning shares the rendered XML.
+///
+/// # Example
+/// ```
+/// use twiml_rust::voice::{Gather, GatherInput, VoiceResponse};
+/// use twiml_rust::TwiML;
+///
+/// let menu = VoiceResponse::new()
+///     .gather(Gather::new().input(vec![GatherInput::Dtmf]).num_digits(1))
+///     .to_fragment();
+///
+/// let response = VoiceResponse::new().say("Welcome back").fragment(menu.clone());
+/// assert!(response.to_xml().contains("<Gather input=\"dtmf\" numDigits=\"1\" />"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RenderedFragment {
+    xml: Arc<str>,
+    pub(crate) verbs: Arc<[VoiceVerb]>,
+    options: RenderOptions,
+}
+
+impl RenderedFragment {
+    /// The rendered XML, indented as children of `<Response>`
+    pub fn as_str(&self) -> &str {
+        &self.xml
+    }
+
+    /// The rendered XML as UTF-8 bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        self.xml.as_bytes()
+    }
+
+    /// The layout the fragment was rendered with
+    pub fn options(&self) -> &RenderOptions {
+        &self.options
+    }
+}
+
+/// A fragment serializes as its list of verbs and is rendered again with the
+/// default [`RenderOptions`] when deserialized
+#[cfg(feature = "serde")]
+impl serde::Serialize for RenderedFragment {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serialize_verbs(&self.verbs, serializer)
+    }
+}
+
+/// Serialize verbs with the verbs of fragments in their place, so a response
+/// built from fragments has the same JSON as one built verb by verb
+#[cfg(feature = "serde")]
+fn serialize_verbs<S: serde::Serializer>(
+    verbs: &[VoiceVerb],
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    use serde::ser::SerializeSeq;
+
+    fn count(verbs: &[VoiceVerb]) -> usize {
+        verbs
+            .iter()
+            .map(|verb| match verb {
+                VoiceVerb::Fragment(fragment) => count(&fragment.verbs),
+                _ => 1,
+            })
+            .sum()
+    }
+
+    fn serialize_each<S: SerializeSeq>(
+        seq: &mut S,
+        verbs: &[VoiceVerb],
+    ) -> std::result::Result<(), S::Error> {
+        for verb in verbs {
+            match verb {
+                VoiceVerb::Fragment(fragment) => serialize_each(seq, &fragment.verbs)?,
+                verb => seq.serialize_element(verb)?,
+            }
+        }
+        Ok(())
+    }
+
+    let mut seq = serializer.serialize_seq(Some(count(verbs)))?;
+    serialize_each(&mut seq, verbs)?;
+    seq.end()
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RenderedFragment {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let verbs = Vec::<VoiceVerb>::deserialize(deserializer)?;
+        Ok(VoiceResponse {
+            verbs,
+            ..VoiceResponse::default()
+        }
+        .to_fragment())
+    }
+}
+
+impl VoiceResponse {
+    /// Pre-render this response's verbs into a [`RenderedFragment`]
+    ///
+    /// Comments are not part of the fragment.
+    pub fn to_fragment(&self) -> RenderedFragment {
+        self.to_fragment_with(&self.default_render_options())
+    }
+
+    /// Pre-render this response's verbs with the given layout
+    ///
+    /// Splice the fragment into responses rendered with the same options to
+    /// skip rendering its verbs again.
+    pub fn to_fragment_with(&self, options: &RenderOptions) -> RenderedFragment {
+        RenderedFragment::render(self.verbs.as_slice().into(), options)
+    }
+}
+
+impl VoiceVerb {
+    /// Pre-render this verb into a [`RenderedFragment`], with the layout of
+    /// [`VoiceResponse::to_xml`]
+    ///
+    /// # Example
+    /// ```
+    /// use twiml_rust::voice::{Say, VoiceResponse, VoiceVerb};
+    /// use twiml_rust::TwiML;
+    ///
+    /// let greeting = VoiceVerb::Say(Say::new("Welcome back")).to_fragment();
+    /// let response = VoiceResponse::new().fragment(greeting).hangup();
+    /// assert!(response.to_xml().contains("<Say>Welcome back</Say>"));
+    /// ```
+    pub fn to_fragment(&self) -> RenderedFragment {
+        self.to_fragment_with(&VoiceResponse::new().default_render_options())
+    }
+
+    /// Pre-render this verb with the given layout
+    pub fn to_fragment_with(&self, options: &RenderOptions) -> RenderedFragment {
+        RenderedFragment::render(Arc::new([self.clone()]), options)
+    }
+}
+
+impl RenderedFragment {
+    fn render(verbs: Arc<[VoiceVerb]>, options: &RenderOptions) -> Self {
+        let mut xml = String::new();
+        for verb in verbs.iter() {
+            write_verb(&mut xml, verb, options).expect("writing to a String does not fail");
+        }
+        RenderedFragment {
+            xml: xml.into(),
+            verbs,
+            options: options.clone(),
+        }
+    }
+}
+
+// ============================================================================
+// Parsing - TwiML XML back into VoiceResponse
+// ============================================================================
+
+impl VoiceResponse {
+    /// Parse a TwiML voice document into a `VoiceResponse`
+    ///
+    /// Every verb, nested noun and SSML element that this module can render is
+    /// reconstructed, and comments around and between the verbs keep their
+    /// position, so `to_xml` after parsing reproduces a document written in
+    /// the default layout. Other layouts come back in the default one: an
+    /// empty `<Response/>`, for example, is rendered as
+    /// `<Response>` `</Response>`.
+    ///
+    /// Elements or attributes without a counterpart in the model are reported
+    /// as [`Error::UnknownElement`] / [`Error::UnknownAttribute`] instead of
+    /// being dropped. Comments nested inside verbs are not part of the model
+    /// and are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use twiml_rust::{VoiceResponse, TwiML};
+    ///
+    /// let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+    /// <Response>
+    ///   <Say voice="alice">Hello</Say>
+    ///   <Hangup />
+    /// </Response>"#;
+    ///
+    /// let response = VoiceResponse::from_xml(xml).unwrap();
+    /// assert_eq!(response.to_xml(), xml);
+    /// ```
+    pub fn from_xml(xml: &str) -> Result<Self> {
+        let document = parse_document(xml)?;
+        let root = &document.root;
+        if root.name != "Response" {
+            return Err(Error::unknown_element("", root.name.clone()));
+        }
+        let path = "Response";
+        root.attributes(path).finish()?;
+
+        let mut response = VoiceResponse {
+            comments_before: document.comments_before,
+            comments_after: document.comments_after,
+            ..Default::default()
+        };
+
+        for child in &root.children {
+            match child {
+                Node::Element(element) => response.verbs.push(parse_verb(element, path)?),
+                Node::Comment(comment) if response.verbs.is_empty() => {
+                    response.comments.push(comment.clone())
+                }
+                Node::Comment(comment) => response
+                    .comments_between
+                    .push((response.verbs.len(), comment.clone())),
+                Node::Text(text) if text.trim().is_empty() => {}
+                Node::Text(text) => {
+                    return Err(Error::parse(
+                        path,
+                        format!("unexpected text content '{}'", text.trim()),
+                    ))
+                }
+            }
+        }
+
+        Ok(response)
+    }
+}
+
+fn parse_verb(element: &Element, parent: &str) -> Result<VoiceVerb> {
+    let path = child_path(parent, &element.name);
+    let path = path.as_str();
+    let verb = match element.name.as_str() {
+        "Connect" => VoiceVerb::Connect(parse_connect(element, path)?),
+        "Dial" => VoiceVerb::Dial(parse_dial(element, path)?),
+        "Echo" => {
+            element.expect_empty(path)?;
+            VoiceVerb::Echo(Echo::new())
+        }
+        "Enqueue" => VoiceVerb::Enqueue(parse_enqueue(element, path)?),
+        "Gather" => VoiceVerb::Gather(parse_gather(element, path)?),
+        "Hangup" => {
+            element.expect_empty(path)?;
+            VoiceVerb::Hangup(Hangup::new())
+        }
+        "Leave" => {
+            element.expect_empty(path)?;
+            VoiceVerb::Leave(Leave::new())
+        }
+        "Pause" => VoiceVerb::Pause(parse_pause(element, path)?),
+        "Pay" => VoiceVerb::Pay(parse_pay(element, path)?),
+        "Play" => VoiceVerb::Play(parse_play(element, path)?),
+        "Prompt" => VoiceVerb::Prompt(parse_prompt(element, path)?),
+        "Queue" => {
+            let mut attrs = element.attributes(path);
+            let attributes = QueueAttributes {
+                url: attrs.string("url"),
+                method: attrs.string("method"),
+                reservation_sid: attrs.string("reservationSid"),
+                post_work_activity_sid: attrs.string("postWorkActivitySid"),
+            };
+            attrs.finish()?;
+            VoiceVerb::Queue(Queue::new(
+                attributes,
+                element.text(path)?.trim().to_string(),
+            ))
+        }
+        "Record" => VoiceVerb::Record(parse_record(element, path)?),
+        "Redirect" => {
+            let mut attrs = element.attributes(path);
+            let attributes = RedirectAttributes {
+                method: attrs.string("method"),
+            };
+            attrs.finish()?;
+            VoiceVerb::Redirect(Redirect::new(
+                attributes,
+                element.text(path)?.trim().to_string(),
+            ))
+        }
+        "Refer" => VoiceVerb::Refer(parse_refer(element, path)?),
+        "Reject" => {
+            let mut attrs = element.attributes(path);
+            let attributes = RejectAttributes {
+                reason: attrs.value("reason"),
+            };
+            attrs.finish()?;
+            element.expect_empty_content(path)?;
+            VoiceVerb::Reject(Reject { attributes })
+        }
+        "Say" => VoiceVerb::Say(parse_say(element, path)?),
+        "Sms" => {
+            let mut attrs = element.attributes(path);
+            let attributes = SmsAttributes {
+                to: attrs.string("to"),
+                from: attrs.string("from"),
+                action: attrs.string("action"),
+                method: attrs.string("method"),
+                status_callback: attrs.string("statusCallback"),
+            };
+            attrs.finish()?;
+            VoiceVerb::Sms(Sms::new(attributes, element.text(path)?))
+        }
+        "Start" => VoiceVerb::Start(parse_start(element, path)?),
+        "Stop" => {
+            element.expect_empty(path)?;
+            VoiceVerb::Stop(Stop::new())
+        }
+        other => return Err(Error::unknown_element(parent, other)),
+    };
+    Ok(verb)
+}
+
+fn parse_say(element: &Element, path: &str) -> Result<Say> {
+    let mut attrs = element.attributes(path);
+    let attributes = SayAttributes {
+        voice: attrs.value("voice"),
+        language: attrs.string("language"),
+        loop_count: attrs.u32("loop")?,
+    };
+    attrs.finish()?;
+
+    let mut say = Say::new("");
+    say.attributes = attributes;
+    for child in &element.children {
+        match child {
+            Node::Text(text) if say.ssml_elements.is_empty() => say.message.push_str(text),
+            Node::Text(text) => say
+                .ssml_elements
+                .push(SsmlElement::Text { text: text.clone() }),
+            Node::Element(ssml) => say.ssml_elements.push(parse_ssml(ssml, path)?),
+            Node::Comment(_) => {}
+        }
+    }
+    Ok(say)
+}
+
+fn parse_ssml(element: &Element, parent: &str) -> Result<SsmlElement> {
+    let path = child_path(parent, &element.name);
+    let path = path.as_str();
+    let mut attrs = element.attributes(path);
+    let ssml = match element.name.as_str() {
+        "break" => SsmlElement::Break {
+            strength: attrs.value("strength"),
+            time: attrs.string("time"),
+        },
+        "emphasis" => SsmlElement::Emphasis {
+            level: attrs.value("level"),
+            text: element.text(path)?,
+        },
+        "lang" => SsmlElement::Lang {
+            xml_lang: required(attrs.string("xml:lang"), path, "xml:lang")?,
+            text: element.text(path)?,
+        },
+        "p" => SsmlElement::P {
+            text: element.text(path)?,
+        },
+        "phoneme" => SsmlElement::Phoneme {
+            alphabet: attrs.value("alphabet"),
+            ph: required(attrs.string("ph"), path, "ph")?,
+            text: element.text(path)?,
+        },
+        "prosody" => SsmlElement::Prosody {
+            pitch: attrs.string("pitch"),
+            rate: attrs.string("rate"),
+            volume: attrs.string("volume"),
+            text: element.text(path)?,
+        },
+        "s" => SsmlElement::S {
+            text: element.text(path)?,
+        },
+        "say-as" => SsmlElement::SayAs {
+            interpret_as: required(attrs.value("interpret-as"), path, "interpret-as")?,
+            format: attrs.value("format"),
+            text: element.text(path)?,
+        },
+        "sub" => SsmlElement::Sub {
+            alias: required(attrs.string("alias"), path, "alias")?,
+            text: element.text(path)?,
+        },
+        "w" => SsmlElement::W {
+            role: attrs.string("role"),
+            text: element.text(path)?,
+        },
+        "amazon:effect" => SsmlElement::AmazonEffect {
+            name: required(attrs.string("name"), path, "name")?,
+            text: element.text(path)?,
+        },
+        "amazon:domain" => SsmlElement::AmazonDomain {
+            name: required(attrs.string("name"), path, "name")?,
+            text: element.text(path)?,
+        },
+        other => return Err(Error::unknown_element(parent, other)),
+    };
+    attrs.finish()?;
+    if matches!(ssml, SsmlElement::Break { .. }) {
+        element.expect_empty_content(path)?;
+    }
+    Ok(ssml)
+}
+
+fn parse_play(element: &Element, path: &str) -> Result<Play> {
+    let mut attrs = element.attributes(path);
+    let attributes = PlayAttributes {
+        digits: attrs.string("digits"),
+        loop_count: attrs.u32("loop")?,
+    };
+    attrs.finish()?;
+    let url = element.text(path)?.trim().to_string();
+    Ok(Play {
+        attributes,
+        url: if url.is_empty() { None } else { Some(url) },
+    })
+}
+
+fn parse_pause(element: &Element, path: &str) -> Result<Pause> {
+    let mut attrs = element.attributes(path);
+    let attributes = PauseAttributes {
+        length: attrs.u32("length")?,
+    };
+    attrs.finish()?;
+    element.expect_empty_content(path)?;
+    Ok(Pause::new(Some(attributes)))
+}
+
+fn parse_dial(element: &Element, path: &str) -> Result<Dial> {
+    let mut attrs = element.attributes(path);
+    let attributes = DialAttributes {
+        action: attrs.string("action"),
+        method: attrs.string("method"),
+        timeout: attrs.u32("timeout")?,
+        hangup_on_star: attrs.bool("hangupOnStar")?,
+        time_limit: attrs.u32("timeLimit")?,
+        caller_id: attrs.string("callerId"),
+        call_reason: attrs.string("callReason"),
+        record: attrs.value("record"),
+        trim: attrs.value("trim"),
+        recording_status_callback: attrs.string("recordingStatusCallback"),
+        answer_on_bridge: attrs.bool("answerOnBridge")?,
+        ring_tone: attrs.value("ringTone"),
+        events: attrs.value("events"),
+        refer_method: attrs.string("referMethod"),
+        refer_url: attrs.string("referUrl"),
+        sequential: attrs.bool("sequential")?,
+        recording_track: attrs.value("recordingTrack"),
+        recording_status_callback_event: attrs.values("recordingStatusCallbackEvent", ' '),
+        recording_status_callback_method: attrs.string("recordingStatusCallbackMethod"),
+    };
+    attrs.finish()?;
+
+    let mut dial = Dial::with_attributes(attributes);
+    let mut number = String::new();
+    for child in &element.children {
+        match child {
+            Node::Text(text) => number.push_str(text),
+            Node::Element(noun) => dial.nested.push(parse_dial_noun(noun, path)?),
+            Node::Comment(_) => {}
+        }
+    }
+    let number = number.trim();
+    if !number.is_empty() {
+        dial.number = Some(number.to_string());
+    }
+    Ok(dial)
+}
+
+fn parse_dial_noun(element: &Element, parent: &str) -> Result<DialNoun> {
+    let path = child_path(parent, &element.name);
+    let path = path.as_str();
+    let mut attrs = element.attributes(path);
+    let noun = match element.name.as_str() {
+        "Number" => {
+            let mut n = DialNumber::new(element.text(path)?.trim());
+            n.send_digits = attrs.string("sendDigits");
+            n.url = attrs.string("url");
+            n.method = attrs.string("method");
+            n.status_callback = attrs.string("statusCallback");
+            n.status_callback_event = attrs.values("statusCallbackEvent", ' ');
+            n.status_callback_method = attrs.string("statusCallbackMethod");
+            n.call_reason = attrs.string("callReason");
+            n.byoc = attrs.string("byoc");
+            n.machine_detection = attrs.string("machineDetection");
+            n.machine_detection_timeout = attrs.u32("machineDetectionTimeout")?;
+            n.machine_detection_speech_threshold = attrs.u32("machineDetectionSpeechThreshold")?;
+            n.machine_detection_speech_end_threshold =
+                attrs.u32("machineDetectionSpeechEndThreshold")?;
+            n.machine_detection_silence_timeout = attrs.u32("machineDetectionSilenceTimeout")?;
+            n.amd_status_callback = attrs.string("amdStatusCallback");
+            n.amd_status_callback_method = attrs.string("amdStatusCallbackMethod");
+            DialNoun::Number(n)
+        }
+        "Client" => {
+            let mut c = DialClient::new(element.text(path)?.trim());
+            c.url = attrs.string("url");
+            c.method = attrs.string("method");
+            c.status_callback_event = attrs.values("statusCallbackEvent", ' ');
+            c.status_callback = attrs.string("statusCallback");
+            c.status_callback_method = attrs.string("statusCallbackMethod");
+            c.client_notification_url = attrs.string("clientNotificationUrl");
+            DialNoun::Client(c)
+        }
+        "Conference" => {
+            let mut conf = DialConference::new(element.text(path)?.trim());
+            conf.muted = attrs.bool("muted")?;
+            conf.beep = attrs.value("beep");
+            conf.start_conference_on_enter = attrs.bool("startConferenceOnEnter")?;
+            conf.end_conference_on_exit = attrs.bool("endConferenceOnExit")?;
+            conf.wait_url = attrs.string("waitUrl");
+            conf.wait_method = attrs.string("waitMethod");
+            conf.max_participants = attrs.u32("maxParticipants")?;
+            conf.record = attrs.value("record");
+            conf.region = attrs.value("region");
+            conf.coach = attrs.string("coach");
+            conf.trim = attrs.value("trim");
+            conf.status_callback_event = attrs.values("statusCallbackEvent", ' ');
+            conf.status_callback = attrs.string("statusCallback");
+            conf.status_callback_method = attrs.string("statusCallbackMethod");
+            conf.recording_status_callback = attrs.string("recordingStatusCallback");
+            conf.recording_status_callback_method = attrs.string("recordingStatusCallbackMethod");
+            conf.recording_status_callback_event =
+                attrs.values("recordingStatusCallbackEvent", ' ');
+            conf.event_callback_url = attrs.string("eventCallbackUrl");
+            conf.jitter_buffer_size = attrs.value("jitterBufferSize");
+            conf.participant_label = attrs.string("participantLabel");
+            conf.call_sid_to_coach = attrs.string("callSidToCoach");
+            conf.beep_on_customer_entrance = attrs.bool("beepOnCustomerEntrance")?;
+            conf.coaching = attrs.bool("coaching")?;
+            DialNoun::Conference(conf)
+        }
+        "Queue" => {
+            let mut q = DialQueue::new(element.text(path)?.trim());
+            q.url = attrs.string("url");
+            q.method = attrs.string("method");
+            q.reservation_sid = attrs.string("reservationSid");
+            q.post_work_activity_sid = attrs.string("postWorkActivitySid");
+            DialNoun::Queue(q)
+        }
+        "Sip" => {
+            let mut sip_url = String::new();
+            let mut headers = Vec::new();
+            for child in &element.children {
+                match child {
+                    Node::Text(text) => sip_url.push_str(text),
+                    Node::Element(header) if header.name == "SipHeader" => {
+                        let header_path = child_path(path, "SipHeader");
+                        let mut header_attrs = header.attributes(&header_path);
+                        let name = required(header_attrs.string("name"), &header_path, "name")?;
+                        let value = header_attrs.string("value").unwrap_or_default();
+                        header_attrs.finish()?;
+                        header.expect_empty_content(&header_path)?;
+                        headers.push((name, value));
                     }
-                    if let Some(et) = &prompt.attributes.error_type {
-                        let types = et.join(" ");
-                        xml.push_str(&format!(" errorType=\"{}\"", escape_xml_attr(&types)));
+                    Node::Element(other) => {
+                        return Err(Error::unknown_element(path, other.name.clone()))
                     }
-                    xml.push_str(" />\n");
+                    Node::Comment(_) => {}
                 }
-                VoiceVerb::Refer(refer) => {
-                    xml.push_str("  <Refer");
-                    if let Some(a) = &refer.attributes.action {
-                        xml.push_str(&format!(" action=\"{}\"", escape_xml_attr(a)));
-                    }
-                    if let Some(m) = &refer.attributes.method {
-                        xml.push_str(&format!(" method=\"{}\"", escape_xml_attr(m)));
+            }
+            let mut s = DialSip::new(sip_url.trim());
+            s.url = attrs.string("url");
+            s.method = attrs.string("method");
+            s.username = attrs.string("username");
+            s.password = attrs.string("password");
+            s.status_callback_event = attrs.values("statusCallbackEvent", ' ');
+            s.status_callback = attrs.string("statusCallback");
+            s.status_callback_method = attrs.string("statusCallbackMethod");
+            s.codecs = attrs.list("codecs", ',');
+            if !headers.is_empty() {
+                s.custom_headers = Some(headers);
+            }
+            DialNoun::Sip(s)
+        }
+        "Sim" => DialNoun::Sim(DialSim::new(element.text(path)?.trim())),
+        "Application" => DialNoun::Application(DialApplication {
+            application_sid: attrs.string("sid"),
+            customer_id: attrs.string("customerId"),
+            copy_parent_to: attrs.string("copyParentTo"),
+            parameters: parse_parameters(element, path)?,
+        }),
+        "WhatsApp" => {
+            let mut wa = DialWhatsApp::new(element.text(path)?.trim());
+            wa.url = attrs.string("url");
+            wa.method = attrs.string("method");
+            DialNoun::WhatsApp(wa)
+        }
+        other => return Err(Error::unknown_element(parent, other)),
+    };
+    attrs.finish()?;
+    Ok(noun)
+}
+
+fn parse_gather(element: &Element, path: &str) -> Result<Gather> {
+    let mut attrs = element.attributes(path);
+    let attributes = GatherAttributes {
+        input: attrs.values("input", ' '),
+        action: attrs.string("action"),
+        method: attrs.string("method"),
+        timeout: attrs.u32("timeout")?,
+        finish_on_key: attrs.string("finishOnKey"),
+        num_digits: attrs.u32("numDigits")?,
+        partial_result_callback: attrs.string("partialResultCallback"),
+        language: attrs.string("language"),
+        hints: attrs.string("hints"),
+        barge_in: attrs.bool("bargeIn")?,
+        speech_timeout: attrs.string("speechTimeout"),
+        action_on_empty_result: attrs.bool("actionOnEmptyResult")?,
+        debug: attrs.bool("debug")?,
+        dtmf_detection: attrs.bool("dtmfDetection")?,
+        enhanced: attrs.bool("enhanced")?,
+        max_speech_time: attrs.u32("maxSpeechTime")?,
+        partial_result_callback_method: attrs.string("partialResultCallbackMethod"),
+        profanity_filter: attrs.bool("profanityFilter")?,
+        speech_model: attrs.value("speechModel"),
+    };
+    attrs.finish()?;
+
+    let mut gather = Gather::with_attributes(attributes);
+    for noun in element.child_elements(path)? {
+        let noun_path = child_path(path, &noun.name);
+        let parsed = match noun.name.as_str() {
+            "Say" => GatherNoun::Say(parse_say(noun, &noun_path)?),
+            "Play" => GatherNoun::Play(parse_play(noun, &noun_path)?),
+            "Pause" => GatherNoun::Pause(parse_pause(noun, &noun_path)?),
+            other => return Err(Error::unknown_element(path, other)),
+        };
+        gather.nested.push(parsed);
+    }
+    Ok(gather)
+}
+
+fn parse_record(element: &Element, path: &str) -> Result<Record> {
+    let mut attrs = element.attributes(path);
+    let attributes = RecordAttributes {
+        action: attrs.string("action"),
+        method: attrs.string("method"),
+        timeout: attrs.u32("timeout")?,
+        finish_on_key: attrs.string("finishOnKey"),
+        max_length: attrs.u32("maxLength")?,
+        play_beep: attrs.bool("playBeep")?,
+        trim: attrs.value("trim"),
+        recording_status_callback: attrs.string("recordingStatusCallback"),
+        recording_status_callback_event: attrs.values("recordingStatusCallbackEvent", ' '),
+        recording_status_callback_method: attrs.string("recordingStatusCallbackMethod"),
+        transcribe: attrs.bool("transcribe")?,
+        transcribe_callback: attrs.string("transcribeCallback"),
+        recording_channels: attrs.value("recordingChannels"),
+        recording_track: attrs.value("recordingTrack"),
+    };
+    attrs.finish()?;
+    element.expect_empty_content(path)?;
+    Ok(Record { attributes })
+}
+
+fn parse_connect(element: &Element, path: &str) -> Result<Connect> {
+    let mut attrs = element.attributes(path);
+    let attributes = ConnectAttributes {
+        action: attrs.string("action"),
+        method: attrs.string("method"),
+    };
+    attrs.finish()?;
+
+    let mut connect = Connect::with_attributes(attributes);
+    for noun in element.child_elements(path)? {
+        let noun_path = child_path(path, &noun.name);
+        let noun_path = noun_path.as_str();
+        let mut attrs = noun.attributes(noun_path);
+        let parsed = match noun.name.as_str() {
+            "Stream" => ConnectNoun::Stream(parse_stream(noun, noun_path, &mut attrs)?),
+            "Room" => ConnectNoun::Room(Room {
+                name: non_empty(noun.text(noun_path)?),
+                participant_identity: attrs.string("participantIdentity"),
+            }),
+            "Conversation" => ConnectNoun::Conversation(Conversation {
+                service_instance_sid: attrs.string("serviceInstanceSid"),
+            }),
+            "VirtualAgent" => ConnectNoun::VirtualAgent(VirtualAgent {
+                connector_name: attrs.string("connectorName"),
+                language: attrs.string("language"),
+                parameters: parse_parameters(noun, noun_path)?,
+            }),
+            "Autopilot" => ConnectNoun::Autopilot(Autopilot {
+                name: non_empty(noun.text(noun_path)?),
+            }),
+            "AiSession" => ConnectNoun::AiSession(AiSession {
+                assistant_sid: attrs.string("assistantSid"),
+            }),
+            "ConversationRelaySession" => {
+                ConnectNoun::ConversationRelaySession(ConversationRelaySession {
+                    connector: attrs.string("connector"),
+                    session_configuration: attrs.string("sessionConfiguration"),
+                })
+            }
+            "Assistant" => ConnectNoun::Assistant(Assistant {
+                sid: attrs.string("sid"),
+            }),
+            "ConversationRelay" => {
+                let mut relay = ConversationRelay {
+                    url: attrs.string("url"),
+                    welcome_greeting: attrs.string("welcomeGreeting"),
+                    voice: attrs.string("voice"),
+                    language: attrs.string("language"),
+                    dtmf_detection: attrs.bool("dtmfDetection")?,
+                    interruptible: attrs.bool("interruptible")?,
+                    interruption_sensitivity: attrs.string("interruptionSensitivity"),
+                    speech_model: attrs.string("speechModel"),
+                    profanity_filter: attrs.bool("profanityFilter")?,
+                    transcription_enabled: attrs.bool("transcriptionEnabled")?,
+                    status_callback: attrs.string("statusCallback"),
+                    status_callback_method: attrs.string("statusCallbackMethod"),
+                    max_duration: attrs.u32("maxDuration")?,
+                    ..Default::default()
+                };
+                for child in noun.child_elements(noun_path)? {
+                    let child_path = child_path(noun_path, &child.name);
+                    let mut child_attrs = child.attributes(&child_path);
+                    match child.name.as_str() {
+                        "Language" => relay.languages.push(Language {
+                            language_code: child_attrs.string("code"),
+                            tts_provider: child_attrs.string("ttsProvider"),
+                            stt_provider: child_attrs.string("sttProvider"),
+                        }),
+                        "Parameter" => relay.parameters.push(Parameter {
+                            name: child_attrs.string("name"),
+                            value: child_attrs.string("value"),
+                        }),
+                        other => return Err(Error::unknown_element(noun_path, other)),
                     }
+                    child_attrs.finish()?;
+                    child.expect_empty_content(&child_path)?;
+                }
+                ConnectNoun::ConversationRelay(relay)
+            }
+            other => return Err(Error::unknown_element(path, other)),
+        };
+        attrs.finish()?;
+        connect.nested.push(parsed);
+    }
+    Ok(connect)
+}
+
+fn parse_stream(element: &Element, path: &str, attrs: &mut Attributes<'_>) -> Result<Stream> {
+    Ok(Stream {
+        name: attrs.string("name"),
+        connector_name: attrs.string("connectorName"),
+        url: attrs.string("url"),
+        track: attrs.value("track"),
+        status_callback: attrs.string("statusCallback"),
+        status_callback_method: attrs.string("statusCallbackMethod"),
+        parameters: parse_parameters(element, path)?,
+    })
+}
+
+/// Parse `<Parameter>` children, the only children allowed on the element
+fn parse_parameters(element: &Element, path: &str) -> Result<Vec<Parameter>> {
+    let mut parameters = Vec::new();
+    for child in element.child_elements(path)? {
+        if child.name != "Parameter" {
+            return Err(Error::unknown_element(path, child.name.clone()));
+        }
+        let param_path = child_path(path, "Parameter");
+        let mut attrs = child.attributes(&param_path);
+        parameters.push(Parameter {
+            name: attrs.string("name"),
+            value: attrs.string("value"),
+        });
+        attrs.finish()?;
+        child.expect_empty_content(&param_path)?;
+    }
+    Ok(parameters)
+}
+
+fn parse_enqueue(element: &Element, path: &str) -> Result<Enqueue> {
+    let mut attrs = element.attributes(path);
+    let attributes = EnqueueAttributes {
+        action: attrs.string("action"),
+        method: attrs.string("method"),
+        wait_url: attrs.string("waitUrl"),
+        wait_url_method: attrs.string("waitUrlMethod"),
+        workflow_sid: attrs.string("workflowSid"),
+        max_queue_size: attrs.u32("maxQueueSize")?,
+    };
+    attrs.finish()?;
+
+    let mut name = String::new();
+    let mut task = None;
+    for child in &element.children {
+        match child {
+            Node::Text(text) => name.push_str(text),
+            Node::Element(t) if t.name == "Task" => {
+                let task_path = child_path(path, "Task");
+                let mut task_attrs = t.attributes(&task_path);
+                task = Some(Task {
+                    task_sid: task_attrs.string("sid"),
+                });
+                task_attrs.finish()?;
+                t.expect_empty_content(&task_path)?;
+            }
+            Node::Element(other) => return Err(Error::unknown_element(path, other.name.clone())),
+            Node::Comment(_) => {}
+        }
+    }
+
+    Ok(Enqueue {
+        attributes,
+        name: non_empty(name),
+        task,
+    })
+}
+
+fn parse_pay(element: &Element, path: &str) -> Result<Pay> {
+    let mut attrs = element.attributes(path);
+    let attributes = PayAttributes {
+        input: attrs.value("input"),
+        action: attrs.string("action"),
+        charge_amount: attrs.string("chargeAmount"),
+        currency: attrs.string("currency"),
+        payment_connector: attrs.string("paymentConnector"),
+        payment_method: attrs.value("paymentMethod"),
+        timeout: attrs.u32("timeout")?,
+        status_callback_method: attrs.string("statusCallbackMethod"),
+        status_callback: attrs.string("statusCallback"),
+        bank_account_type: attrs.value("bankAccountType"),
+        description: attrs.string("description"),
+        language: attrs.value("language"),
+        max_attempts: attrs.u32("maxAttempts")?,
+        method: attrs.string("method"),
+        min_postal_code_length: attrs.u32("minPostalCodeLength")?,
+        postal_code: attrs.bool("postalCode")?,
+        security_code: attrs.bool("securityCode")?,
+        token_type: attrs.value("tokenType"),
+        valid_card_types: attrs.values("validCardTypes", ' '),
+    };
+    attrs.finish()?;
+
+    let mut pay = Pay {
+        attributes,
+        prompts: Vec::new(),
+        parameters: Vec::new(),
+    };
+    for child in element.child_elements(path)? {
+        let child_path = child_path(path, &child.name);
+        match child.name.as_str() {
+            "Prompt" => pay.prompts.push(parse_prompt(child, &child_path)?),
+            "Parameter" => {
+                let mut param_attrs = child.attributes(&child_path);
+                pay.parameters.push(Parameter {
+                    name: param_attrs.string("name"),
+                    value: param_attrs.string("value"),
+                });
+                param_attrs.finish()?;
+                child.expect_empty_content(&child_path)?;
+            }
+            other => return Err(Error::unknown_element(path, other)),
+        }
+    }
+    Ok(pay)
+}
+
+fn parse_prompt(element: &Element, path: &str) -> Result<Prompt> {
+    let mut attrs = element.attributes(path);
+    let attempt = match attrs.list("attempt", ' ') {
+        Some(values) => Some(
+            values
+                .iter()
+                .map(|v| {
+                    v.parse::<u32>().map_err(|_| {
+                        Error::invalid_parameter(
+                            "attempt",
+                            format!("expected a list of integers, got '{}'", v),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?,
+        ),
+        None => None,
+    };
+    let attributes = PromptAttributes {
+        for_attr: attrs.value("for"),
+        attempt,
+        card_type: attrs.values("cardType", ' '),
+        error_type: attrs.values("errorType", ' '),
+        require_matching_inputs: attrs.bool("requireMatchingInputs")?,
+    };
+    attrs.finish()?;
+    element.expect_empty_content(path)?;
+    Ok(Prompt::with_attributes(attributes))
+}
+
+fn parse_refer(element: &Element, path: &str) -> Result<Refer> {
+    let mut attrs = element.attributes(path);
+    let attributes = ReferAttributes {
+        action: attrs.string("action"),
+        method: attrs.string("method"),
+    };
+    attrs.finish()?;
+
+    let mut refer_sip = None;
+    for child in element.child_elements(path)? {
+        if child.name != "Sip" || refer_sip.is_some() {
+            return Err(Error::unknown_element(path, child.name.clone()));
+        }
+        let sip_path = child_path(path, "Sip");
+        child.attributes(&sip_path).finish()?;
+        refer_sip = Some(ReferSip::new(child.text(&sip_path)?.trim()));
+    }
+    Ok(Refer {
+        attributes,
+        refer_sip,
+    })
+}
+
+fn parse_start(element: &Element, path: &str) -> Result<Start> {
+    let mut attrs = element.attributes(path);
+    let attributes = StartAttributes {
+        action: attrs.string("action"),
+        method: attrs.string("method"),
+    };
+    attrs.finish()?;
+
+    let mut start = Start {
+        attributes,
+        nested: Vec::new(),
+    };
+    for noun in element.child_elements(path)? {
+        let noun_path = child_path(path, &noun.name);
+        let noun_path = noun_path.as_str();
+        let mut attrs = noun.attributes(noun_path);
+        let parsed = match noun.name.as_str() {
+            "Stream" => StartNoun::Stream(parse_stream(noun, noun_path, &mut attrs)?),
+            "Siprec" => StartNoun::Siprec(Siprec {
+                name: attrs.string("name"),
+                connector_name: attrs.string("connectorName"),
+                track: attrs.value("track"),
+            }),
+            "Transcription" => StartNoun::Transcription(Transcription {
+                attributes: TranscriptionAttributes {
+                    name: attrs.string("name"),
+                    track: attrs.value("track"),
+                    language_code: attrs.string("languageCode"),
+                    enable_automatic_punctuation: attrs.bool("enableAutomaticPunctuation")?,
+                    hints: attrs.string("hints"),
+                    inbound_track_label: attrs.string("inboundTrackLabel"),
+                    intelligence_service: attrs.string("intelligenceService"),
+                    outbound_track_label: attrs.string("outboundTrackLabel"),
+                    partial_results: attrs.bool("partialResults")?,
+                    profanity_filter: attrs.bool("profanityFilter")?,
+                    speech_model: attrs.string("speechModel"),
+                    status_callback_method: attrs.string("statusCallbackMethod"),
+                    status_callback_url: attrs.string("statusCallbackUrl"),
+                    transcription_engine: attrs.string("transcriptionEngine"),
+                },
+            }),
+            "Recording" => StartNoun::Recording(Recording {
+                recording_status_callback: attrs.string("recordingStatusCallback"),
+                recording_status_callback_method: attrs.string("recordingStatusCallbackMethod"),
+                recording_status_callback_event: attrs.values("recordingStatusCallbackEvent", ' '),
+                trim: attrs.value("trim"),
+                track: attrs.value("track"),
+                channels: attrs.value("channels"),
+            }),
+            other => return Err(Error::unknown_element(path, other)),
+        };
+        attrs.finish()?;
+        if !matches!(parsed, StartNoun::Stream(_)) {
+            noun.expect_empty_content(noun_path)?;
+        }
+        start.nested.push(parsed);
+    }
+    Ok(start)
+}
+
+fn required<T>(value: Option<T>, path: &str, attribute: &str) -> Result<T> {
+    value.ok_or_else(|| Error::parse(path, format!("missing required attribute '{}'", attribute)))
+}
 
-                    if let Some(sip) = &refer.refer_sip {
-                        xml.push('>');
-                        xml.push_str(&format!(
-                            "\n    <Sip>{}</Sip>",
-                            escape_xml_attr(&sip.sip_url)
-                        ));
-                        xml.push_str("\n  </Refer>\n");
-                    } else {
-                        xml.push_str(" />\n");
+fn non_empty(text: String) -> Option<String> {
+    let text = text.trim();
+    if text.is_empty() {
+        None
+    } else {
+        Some(text.to_string())
+    }
+}
+
+// ============================================================================
+// Validation - walking the typed model
+// ============================================================================
+
+impl ValidateModel for VoiceResponse {
+    fn validate_model_with(&self, validator: &TwiMLValidator) -> Vec<ValidationError> {
+        let mut checker = ModelChecker::new(validator);
+        for (i, verb) in self.verbs.iter().enumerate() {
+            check_verb(&mut checker, &format!("verbs[{}]", i), verb);
+        }
+        checker.finish()
+    }
+}
+
+fn check_verb(checker: &mut ModelChecker, path: &str, verb: &VoiceVerb) {
+    match verb {
+        VoiceVerb::Fragment(fragment) => {
+            for (i, verb) in fragment.verbs.iter().enumerate() {
+                check_verb(checker, &format!("{}.Fragment[{}]", path, i), verb);
+            }
+        }
+        VoiceVerb::Connect(connect) => {
+            let path = format!("{}.Connect", path);
+            let a = &connect.attributes;
+            checker
+                .element(format!("{}.attributes", path), "Connect")
+                .string("action", "action", a.action.as_deref())
+                .string("method", "method", a.method.as_deref());
+            for (i, noun) in connect.nested.iter().enumerate() {
+                let path = format!("{}.nested[{}]", path, i);
+                match noun {
+                    ConnectNoun::Stream(stream) => check_stream(checker, &path, stream),
+                    ConnectNoun::ConversationRelay(relay) => {
+                        checker
+                            .element(format!("{}.ConversationRelay", path), "ConversationRelay")
+                            .string("url", "url", relay.url.as_deref())
+                            .string(
+                                "status_callback",
+                                "statusCallback",
+                                relay.status_callback.as_deref(),
+                            )
+                            .string(
+                                "status_callback_method",
+                                "statusCallbackMethod",
+                                relay.status_callback_method.as_deref(),
+                            )
+                            .number("max_duration", "maxDuration", relay.max_duration);
                     }
+                    ConnectNoun::Room(_)
+                    | ConnectNoun::Conversation(_)
+                    | ConnectNoun::VirtualAgent(_)
+                    | ConnectNoun::Autopilot(_)
+                    | ConnectNoun::AiSession(_)
+                    | ConnectNoun::ConversationRelaySession(_)
+                    | ConnectNoun::Assistant(_) => {}
+                }
+            }
+        }
+        VoiceVerb::Dial(dial) => {
+            let path = format!("{}.Dial", path);
+            let a = &dial.attributes;
+            checker
+                .element(format!("{}.attributes", path), "Dial")
+                .string("action", "action", a.action.as_deref())
+                .string("method", "method", a.method.as_deref())
+                .number("timeout", "timeout", a.timeout)
+                .number("time_limit", "timeLimit", a.time_limit)
+                .string("record", "record", a.record.as_ref())
+                .string("trim", "trim", a.trim.as_ref())
+                .string(
+                    "recording_status_callback",
+                    "recordingStatusCallback",
+                    a.recording_status_callback.as_deref(),
+                )
+                .list(
+                    "recording_status_callback_event",
+                    "recordingStatusCallbackEvent",
+                    a.recording_status_callback_event.as_deref(),
+                )
+                .string(
+                    "recording_status_callback_method",
+                    "recordingStatusCallbackMethod",
+                    a.recording_status_callback_method.as_deref(),
+                )
+                .string(
+                    "recording_track",
+                    "recordingTrack",
+                    a.recording_track.as_ref(),
+                )
+                .string("ring_tone", "ringTone", a.ring_tone.as_ref())
+                .string("events", "events", a.events.as_ref())
+                .string("refer_method", "referMethod", a.refer_method.as_deref());
+            for (i, noun) in dial.nested.iter().enumerate() {
+                check_dial_noun(checker, &format!("{}.nested[{}]", path, i), noun);
+            }
+        }
+        VoiceVerb::Enqueue(enqueue) => {
+            let a = &enqueue.attributes;
+            checker
+                .element(format!("{}.Enqueue.attributes", path), "Enqueue")
+                .string("action", "action", a.action.as_deref())
+                .string("method", "method", a.method.as_deref())
+                .string(
+                    "wait_url_method",
+                    "waitUrlMethod",
+                    a.wait_url_method.as_deref(),
+                )
+                .number("max_queue_size", "maxQueueSize", a.max_queue_size);
+        }
+        VoiceVerb::Gather(gather) => {
+            let path = format!("{}.Gather", path);
+            let a = &gather.attributes;
+            checker
+                .element(format!("{}.attributes", path), "Gather")
+                .list("input", "input", a.input.as_deref())
+                .string("action", "action", a.action.as_deref())
+                .string("method", "method", a.method.as_deref())
+                .number("timeout", "timeout", a.timeout)
+                .number("num_digits", "numDigits", a.num_digits)
+                .number("max_speech_time", "maxSpeechTime", a.max_speech_time)
+                .string(
+                    "partial_result_callback_method",
+                    "partialResultCallbackMethod",
+                    a.partial_result_callback_method.as_deref(),
+                )
+                .string("speech_model", "speechModel", a.speech_model.as_ref())
+                .speech_model(
+                    "speech_model",
+                    a.speech_model.as_ref(),
+                    a.language.as_deref(),
+                )
+                .enhanced("enhanced", a.enhanced, a.speech_model.as_ref());
+            for (i, noun) in gather.nested.iter().enumerate() {
+                let path = format!("{}.nested[{}]", path, i);
+                match noun {
+                    GatherNoun::Say(say) => check_say(checker, &path, say),
+                    GatherNoun::Play(play) => check_play(checker, &path, play),
+                    GatherNoun::Pause(pause) => check_pause(checker, &path, pause),
                 }
-                VoiceVerb::Start(start) => {
-                    xml.push_str("  <Start");
-                    if let Some(a) = &start.attributes.action {
-                        xml.push_str(&format!(" action=\"{}\"", escape_xml_attr(a)));
+            }
+        }
+        VoiceVerb::Pause(pause) => check_pause(checker, path, pause),
+        VoiceVerb::Pay(pay) => {
+            let path = format!("{}.Pay", path);
+            let a = &pay.attributes;
+            checker
+                .element(format!("{}.attributes", path), "Pay")
+                .string("input", "input", a.input.as_ref())
+                .string("action", "action", a.action.as_deref())
+                .string(
+                    "bank_account_type",
+                    "bankAccountType",
+                    a.bank_account_type.as_ref(),
+                )
+                .string("language", "language", a.language.as_ref())
+                .number("max_attempts", "maxAttempts", a.max_attempts)
+                .string("method", "method", a.method.as_deref())
+                .number(
+                    "min_postal_code_length",
+                    "minPostalCodeLength",
+                    a.min_postal_code_length,
+                )
+                .string("payment_method", "paymentMethod", a.payment_method.as_ref())
+                .string(
+                    "status_callback",
+                    "statusCallback",
+                    a.status_callback.as_deref(),
+                )
+                .string(
+                    "status_callback_method",
+                    "statusCallbackMethod",
+                    a.status_callback_method.as_deref(),
+                )
+                .number("timeout", "timeout", a.timeout)
+                .string("token_type", "tokenType", a.token_type.as_ref())
+                .list(
+                    "valid_card_types",
+                    "validCardTypes",
+                    a.valid_card_types.as_deref(),
+                );
+            for (i, prompt) in pay.prompts.iter().enumerate() {
+                check_prompt(checker, &format!("{}.prompts[{}]", path, i), prompt);
+            }
+        }
+        VoiceVerb::Play(play) => check_play(checker, path, play),
+        VoiceVerb::Prompt(prompt) => check_prompt(checker, path, prompt),
+        VoiceVerb::Queue(queue) => {
+            let a = &queue.attributes;
+            checker
+                .element(format!("{}.Queue.attributes", path), "Queue")
+                .string("url", "url", a.url.as_deref())
+                .string("method", "method", a.method.as_deref());
+        }
+        VoiceVerb::Record(record) => {
+            let a = &record.attributes;
+            checker
+                .element(format!("{}.Record.attributes", path), "Record")
+                .string("action", "action", a.action.as_deref())
+                .string("method", "method", a.method.as_deref())
+                .number("timeout", "timeout", a.timeout)
+                .number("max_length", "maxLength", a.max_length)
+                .string("trim", "trim", a.trim.as_ref())
+                .string(
+                    "recording_status_callback",
+                    "recordingStatusCallback",
+                    a.recording_status_callback.as_deref(),
+                )
+                .list(
+                    "recording_status_callback_event",
+                    "recordingStatusCallbackEvent",
+                    a.recording_status_callback_event.as_deref(),
+                )
+                .string(
+                    "recording_status_callback_method",
+                    "recordingStatusCallbackMethod",
+                    a.recording_status_callback_method.as_deref(),
+                )
+                .string(
+                    "transcribe_callback",
+                    "transcribeCallback",
+                    a.transcribe_callback.as_deref(),
+                )
+                .string(
+                    "recording_channels",
+                    "recordingChannels",
+                    a.recording_channels.as_ref(),
+                )
+                .string(
+                    "recording_track",
+                    "recordingTrack",
+                    a.recording_track.as_ref(),
+                );
+        }
+        VoiceVerb::Redirect(redirect) => {
+            checker
+                .element(format!("{}.Redirect.attributes", path), "Redirect")
+                .string("method", "method", redirect.attributes.method.as_deref());
+        }
+        VoiceVerb::Refer(refer) => {
+            let a = &refer.attributes;
+            checker
+                .element(format!("{}.Refer.attributes", path), "Refer")
+                .string("action", "action", a.action.as_deref())
+                .string("method", "method", a.method.as_deref());
+        }
+        VoiceVerb::Reject(reject) => {
+            checker
+                .element(format!("{}.Reject.attributes", path), "Reject")
+                .string("reason", "reason", reject.attributes.reason.as_ref());
+        }
+        VoiceVerb::Say(say) => check_say(checker, path, say),
+        VoiceVerb::Sms(sms) => {
+            let a = &sms.attributes;
+            checker
+                .element(format!("{}.Sms.attributes", path), "Sms")
+                .string("action", "action", a.action.as_deref())
+                .string("method", "method", a.method.as_deref())
+                .string(
+                    "status_callback",
+                    "statusCallback",
+                    a.status_callback.as_deref(),
+                );
+        }
+        VoiceVerb::Start(start) => {
+            let path = format!("{}.Start", path);
+            let a = &start.attributes;
+            checker
+                .element(format!("{}.attributes", path), "Start")
+                .string("action", "action", a.action.as_deref())
+                .string("method", "method", a.method.as_deref());
+            for (i, noun) in start.nested.iter().enumerate() {
+                let path = format!("{}.nested[{}]", path, i);
+                match noun {
+                    StartNoun::Stream(stream) => check_stream(checker, &path, stream),
+                    StartNoun::Siprec(siprec) => {
+                        checker
+                            .element(format!("{}.Siprec", path), "Siprec")
+                            .string("track", "track", siprec.track.as_ref());
                     }
-                    if let Some(m) = &start.attributes.method {
-                        xml.push_str(&format!(" method=\"{}\"", escape_xml_attr(m)));
+                    StartNoun::Transcription(transcription) => {
+                        let a = &transcription.attributes;
+                        checker
+                            .element(
+                                format!("{}.Transcription.attributes", path),
+                                "Transcription",
+                            )
+                            .string("track", "track", a.track.as_ref())
+                            .string(
+                                "status_callback_url",
+                                "statusCallbackUrl",
+                                a.status_callback_url.as_deref(),
+                            )
+                            .string(
+                                "status_callback_method",
+                                "statusCallbackMethod",
+                                a.status_callback_method.as_deref(),
+                            );
                     }
-
-                    if start.nested.is_empty() {
-                        xml.push_str(" />\n");
-                    } else {
-                        xml.push('>');
-
-                        for noun in &start.nested {
-                            match noun {
-                                StartNoun::Stream(stream) => {
-                                    xml.push_str("\n    <Stream");
-                                    if let Some(n) = &stream.name {
-                                        xml.push_str(&format!(" name=\"{}\"", escape_xml_attr(n)));
-                                    }
-                                    if let Some(cn) = &stream.connector_name {
-                                        xml.push_str(&format!(
-                                            " connectorName=\"{}\"",
-                                            escape_xml_attr(cn)
-                                        ));
-                                    }
-                                    if let Some(u) = &stream.url {
-                                        xml.push_str(&format!(" url=\"{}\"", escape_xml_attr(u)));
-                                    }
-                                    if let Some(t) = &stream.track {
-                                        xml.push_str(&format!(" track=\"{}\"", escape_xml_attr(t)));
-                                    }
-                                    if let Some(sc) = &stream.status_callback {
-                                        xml.push_str(&format!(
-                                            " statusCallback=\"{}\"",
-                                            escape_xml_attr(sc)
-                                        ));
-                                    }
-                                    if let Some(scm) = &stream.status_callback_method {
-                                        xml.push_str(&format!(
-                                            " statusCallbackMethod=\"{}\"",
-                                            escape_xml_attr(scm)
-                                        ));
-                                    }
-
-                                    if stream.parameters.is_empty() {
-                                        xml.push_str(" />");
-                                    } else {
-                                        xml.push('>');
-                                        for param in &stream.parameters {
-                                            xml.push_str("\n      <Parameter");
-                                            if let Some(n) = &param.name {
-                                                xml.push_str(&format!(
-                                                    " name=\"{}\"",
-                                                    escape_xml_attr(n)
-                                                ));
-                                            }
-                                            if let Some(v) = &param.value {
-                                                xml.push_str(&format!(
-                                                    " value=\"{}\"",
-                                                    escape_xml_attr(v)
-                                                ));
-                                            }
-                                            xml.push_str(" />");
-                                        }
-                                        xml.push_str("\n    </Stream>");
-                                    }
-                                }
-                                StartNoun::Siprec(siprec) => {
-                                    xml.push_str("\n    <Siprec");
-                                    if let Some(n) = &siprec.name {
-                                        xml.push_str(&format!(" name=\"{}\"", escape_xml_attr(n)));
-                                    }
-                                    if let Some(cn) = &siprec.connector_name {
-                                        xml.push_str(&format!(
-                                            " connectorName=\"{}\"",
-                                            escape_xml_attr(cn)
-                                        ));
-                                    }
-                                    if let Some(t) = &siprec.track {
-                                        xml.push_str(&format!(" track=\"{}\"", escape_xml_attr(t)));
-                                    }
-                                    xml.push_str(" />");
-                                }
-                                StartNoun::Transcription(trans) => {
-                                    xml.push_str("\n    <Transcription");
-                                    if let Some(n) = &trans.attributes.name {
-                                        xml.push_str(&format!(" name=\"{}\"", escape_xml_attr(n)));
-                                    }
-                                    if let Some(t) = &trans.attributes.track {
-                                        xml.push_str(&format!(" track=\"{}\"", escape_xml_attr(t)));
-                                    }
-                                    if let Some(lc) = &trans.attributes.language_code {
-                                        xml.push_str(&format!(
-                                            " languageCode=\"{}\"",
-                                            escape_xml_attr(lc)
-                                        ));
-                                    }
-                                    if let Some(enable) =
-                                        trans.attributes.enable_automatic_punctuation
-                                    {
-                                        xml.push_str(&format!(
-                                            " enableAutomaticPunctuation=\"{}\"",
-                                            enable
-                                        ));
-                                    }
-                                    if let Some(h) = &trans.attributes.hints {
-                                        xml.push_str(&format!(" hints=\"{}\"", escape_xml_attr(h)));
-                                    }
-                                    if let Some(label) = &trans.attributes.inbound_track_label {
-                                        xml.push_str(&format!(
-                                            " inboundTrackLabel=\"{}\"",
-                                            escape_xml_attr(label)
-                                        ));
-                                    }
-                                    if let Some(service) = &trans.attributes.intelligence_service {
-                                        xml.push_str(&format!(
-                                            " intelligenceService=\"{}\"",
-                                            escape_xml_attr(service)
-                                        ));
-                                    }
-                                    if let Some(label) = &trans.attributes.outbound_track_label {
-                                        xml.push_str(&format!(
-                                            " outboundTrackLabel=\"{}\"",
-                                            escape_xml_attr(label)
-                                        ));
-                                    }
-                                    if let Some(enable) = trans.attributes.partial_results {
-                                        xml.push_str(&format!(" partialResults=\"{}\"", enable));
-                                    }
-                                    if let Some(enable) = trans.attributes.profanity_filter {
-                                        xml.push_str(&format!(" profanityFilter=\"{}\"", enable));
-                                    }
-                                    if let Some(model) = &trans.attributes.speech_model {
-                                        xml.push_str(&format!(
-                                            " speechModel=\"{}\"",
-                                            escape_xml_attr(model)
-                                        ));
-                                    }
-                                    if let Some(method) = &trans.attributes.status_callback_method {
-                                        xml.push_str(&format!(
-                                            " statusCallbackMethod=\"{}\"",
-                                            escape_xml_attr(method)
-                                        ));
-                                    }
-                                    if let Some(url) = &trans.attributes.status_callback_url {
-                                        xml.push_str(&format!(
-                                            " statusCallbackUrl=\"{}\"",
-                                            escape_xml_attr(url)
-                                        ));
-                                    }
-                                    if let Some(engine) = &trans.attributes.transcription_engine {
-                                        xml.push_str(&format!(
-                                            " transcriptionEngine=\"{}\"",
-                                            escape_xml_attr(engine)
-                                        ));
-                                    }
-                                    xml.push_str(" />");
-                                }
-                                StartNoun::Recording(rec) => {
-                                    xml.push_str("\n    <Recording");
-                                    if let Some(cb) = &rec.recording_status_callback {
-                                        xml.push_str(&format!(
-                                            " recordingStatusCallback=\"{}\"",
-                                            escape_xml_attr(cb)
-                                        ));
-                                    }
-                                    if let Some(m) = &rec.recording_status_callback_method {
-                                        xml.push_str(&format!(
-                                            " recordingStatusCallbackMethod=\"{}\"",
-                                            escape_xml_attr(m)
-                                        ));
-                                    }
-                                    if let Some(e) = &rec.recording_status_callback_event {
-                                        xml.push_str(&format!(
-                                            " recordingStatusCallbackEvent=\"{}\"",
-                                            escape_xml_attr(e)
-                                        ));
-                                    }
-                                    if let Some(tr) = &rec.trim {
-                                        xml.push_str(&format!(" trim=\"{}\"", escape_xml_attr(tr)));
-                                    }
-                                    if let Some(t) = &rec.track {
-                                        xml.push_str(&format!(" track=\"{}\"", escape_xml_attr(t)));
-                                    }
-                                    if let Some(ch) = &rec.channels {
-                                        xml.push_str(&format!(
-                                            " channels=\"{}\"",
-                                            escape_xml_attr(ch)
-                                        ));
-                                    }
-                                    xml.push_str(" />");
-                                }
-                            }
-                        }
-
-                        xml.push_str("\n  </Start>\n");
+                    StartNoun::Recording(recording) => {
+                        checker
+                            .element(format!("{}.Recording", path), "Recording")
+                            .string(
+                                "recording_status_callback",
+                                "recordingStatusCallback",
+                                recording.recording_status_callback.as_deref(),
+                            )
+                            .string(
+                                "recording_status_callback_method",
+                                "recordingStatusCallbackMethod",
+                                recording.recording_status_callback_method.as_deref(),
+                            )
+                            .list(
+                                "recording_status_callback_event",
+                                "recordingStatusCallbackEvent",
+                                recording.recording_status_callback_event.as_deref(),
+                            )
+                            .string("trim", "trim", recording.trim.as_ref())
+                            .string("track", "track", recording.track.as_ref())
+                            .string("channels", "channels", recording.channels.as_ref());
                     }
                 }
             }
         }
-
-        xml.push_str("</Response>");
-
-        // Add comments after Response
-        for comment in &self.comments_after {
-            xml.push_str(&format!("\n<!-- {} -->", escape_xml_text(comment)));
+        VoiceVerb::Echo(_) | VoiceVerb::Hangup(_) | VoiceVerb::Leave(_) | VoiceVerb::Stop(_) => {}
+    }
+}
+
+fn check_dial_noun(checker: &mut ModelChecker, path: &str, noun: &DialNoun) {
+    match noun {
+        DialNoun::Number(n) => {
+            checker
+                .element(format!("{}.Number", path), "Number")
+                .content("number", &n.number, false)
+                .string("url", "url", n.url.as_deref())
+                .string("method", "method", n.method.as_deref())
+                .string(
+                    "status_callback",
+                    "statusCallback",
+                    n.status_callback.as_deref(),
+                )
+                .list(
+                    "status_callback_event",
+                    "statusCallbackEvent",
+                    n.status_callback_event.as_deref(),
+                )
+                .string(
+                    "status_callback_method",
+                    "statusCallbackMethod",
+                    n.status_callback_method.as_deref(),
+                )
+                .string(
+                    "machine_detection",
+                    "machineDetection",
+                    n.machine_detection.as_deref(),
+                )
+                .number(
+                    "machine_detection_timeout",
+                    "machineDetectionTimeout",
+                    n.machine_detection_timeout,
+                )
+                .number(
+                    "machine_detection_speech_threshold",
+                    "machineDetectionSpeechThreshold",
+                    n.machine_detection_speech_threshold,
+                )
+                .number(
+                    "machine_detection_speech_end_threshold",
+                    "machineDetectionSpeechEndThreshold",
+                    n.machine_detection_speech_end_threshold,
+                )
+                .number(
+                    "machine_detection_silence_timeout",
+                    "machineDetectionSilenceTimeout",
+                    n.machine_detection_silence_timeout,
+                )
+                .string(
+                    "amd_status_callback_method",
+                    "amdStatusCallbackMethod",
+                    n.amd_status_callback_method.as_deref(),
+                );
+        }
+        DialNoun::Client(c) => {
+            checker
+                .element(format!("{}.Client", path), "Client")
+                .string("url", "url", c.url.as_deref())
+                .string("method", "method", c.method.as_deref())
+                .string(
+                    "status_callback",
+                    "statusCallback",
+                    c.status_callback.as_deref(),
+                )
+                .list(
+                    "status_callback_event",
+                    "statusCallbackEvent",
+                    c.status_callback_event.as_deref(),
+                )
+                .string(
+                    "status_callback_method",
+                    "statusCallbackMethod",
+                    c.status_callback_method.as_deref(),
+                );
+        }
+        DialNoun::Conference(c) => {
+            checker
+                .element(format!("{}.Conference", path), "Conference")
+                .string("beep", "beep", c.beep.as_ref())
+                .string("wait_method", "waitMethod", c.wait_method.as_deref())
+                .number("max_participants", "maxParticipants", c.max_participants)
+                .string("record", "record", c.record.as_ref())
+                .string("region", "region", c.region.as_ref())
+                .string("trim", "trim", c.trim.as_ref())
+                .list(
+                    "status_callback_event",
+                    "statusCallbackEvent",
+                    c.status_callback_event.as_deref(),
+                )
+                .string(
+                    "status_callback",
+                    "statusCallback",
+                    c.status_callback.as_deref(),
+                )
+                .string(
+                    "status_callback_method",
+                    "statusCallbackMethod",
+                    c.status_callback_method.as_deref(),
+                )
+                .string(
+                    "recording_status_callback",
+                    "recordingStatusCallback",
+                    c.recording_status_callback.as_deref(),
+                )
+                .string(
+                    "recording_status_callback_method",
+                    "recordingStatusCallbackMethod",
+                    c.recording_status_callback_method.as_deref(),
+                )
+                .list(
+                    "recording_status_callback_event",
+                    "recordingStatusCallbackEvent",
+                    c.recording_status_callback_event.as_deref(),
+                )
+                .string(
+                    "jitter_buffer_size",
+                    "jitterBufferSize",
+                    c.jitter_buffer_size.as_ref(),
+                );
+        }
+        DialNoun::Queue(q) => {
+            checker
+                .element(format!("{}.Queue", path), "Queue")
+                .string("url", "url", q.url.as_deref())
+                .string("method", "method", q.method.as_deref());
+        }
+        DialNoun::Sip(s) => {
+            checker
+                .element(format!("{}.Sip", path), "Sip")
+                .string("url", "url", s.url.as_deref())
+                .string("method", "method", s.method.as_deref())
+                .string(
+                    "status_callback",
+                    "statusCallback",
+                    s.status_callback.as_deref(),
+                )
+                .list(
+                    "status_callback_event",
+                    "statusCallbackEvent",
+                    s.status_callback_event.as_deref(),
+                )
+                .string(
+                    "status_callback_method",
+                    "statusCallbackMethod",
+                    s.status_callback_method.as_deref(),
+                );
+        }
+        DialNoun::WhatsApp(w) => {
+            checker
+                .element(format!("{}.WhatsApp", path), "WhatsApp")
+                .string("url", "url", w.url.as_deref())
+                .string("method", "method", w.method.as_deref())
+                .string(
+                    "status_callback",
+                    "statusCallback",
+                    w.status_callback.as_deref(),
+                )
+                .list(
+                    "status_callback_event",
+                    "statusCallbackEvent",
+                    w.status_callback_event.as_deref(),
+                )
+                .string(
+                    "status_callback_method",
+                    "statusCallbackMethod",
+                    w.status_callback_method.as_deref(),
+                );
+        }
+        DialNoun::Sim(_) | DialNoun::Application(_) => {}
+    }
+}
+
+fn check_say(checker: &mut ModelChecker, path: &str, say: &Say) {
+    let path = format!("{}.Say", path);
+    checker
+        .element(&path, "Say")
+        .content("message", &say.message, !say.ssml_elements.is_empty());
+    checker
+        .element(format!("{}.attributes", path), "Say")
+        .number("loop_count", "loop", say.attributes.loop_count)
+        .voice(
+            "voice",
+            say.attributes.voice.as_ref(),
+            say.attributes.language.as_deref(),
+        );
+
+    for (i, ssml) in say.ssml_elements.iter().enumerate() {
+        let path = format!("{}.ssml_elements[{}]", path, i);
+        match ssml {
+            SsmlElement::Break { strength, .. } => {
+                checker.element(format!("{}.Break", path), "break").string(
+                    "strength",
+                    "strength",
+                    strength.as_ref(),
+                );
+            }
+            SsmlElement::Emphasis { level, .. } => {
+                checker
+                    .element(format!("{}.Emphasis", path), "emphasis")
+                    .string("level", "level", level.as_ref());
+            }
+            SsmlElement::Phoneme { alphabet, .. } => {
+                checker
+                    .element(format!("{}.Phoneme", path), "phoneme")
+                    .string("alphabet", "alphabet", alphabet.as_ref());
+            }
+            SsmlElement::SayAs {
+                interpret_as,
+                format,
+                ..
+            } => {
+                checker
+                    .element(format!("{}.SayAs", path), "say-as")
+                    .string("interpret_as", "interpret-as", Some(interpret_as))
+                    .string("format", "format", format.as_ref());
+            }
+            _ => {}
         }
-
-        xml
     }
 }
+
+fn check_play(checker: &mut ModelChecker, path: &str, play: &Play) {
+    let has_url = play
+        .url
+        .as_deref()
+        .is_some_and(|url| !url.trim().is_empty());
+    checker.element(format!("{}.Play", path), "Play").required(
+        "url",
+        has_url || play.attributes.digits.is_some(),
+        "a media URL or digits",
+    );
+    checker
+        .element(format!("{}.Play.attributes", path), "Play")
+        .number("loop_count", "loop", play.attributes.loop_count);
+}
+
+fn check_pause(checker: &mut ModelChecker, path: &str, pause: &Pause) {
+    checker
+        .element(format!("{}.Pause.attributes", path), "Pause")
+        .number("length", "length", pause.attributes.length);
+}
+
+fn check_prompt(checker: &mut ModelChecker, path: &str, prompt: &Prompt) {
+    let a = &prompt.attributes;
+    checker
+        .element(format!("{}.Prompt.attributes", path), "Prompt")
+        .list("card_type", "cardType", a.card_type.as_deref())
+        .list("error_type", "errorType", a.error_type.as_deref())
+        .string("for_attr", "for", a.for_attr.as_ref());
+}
+
+fn check_stream(checker: &mut ModelChecker, path: &str, stream: &Stream) {
+    checker
+        .element(format!("{}.Stream", path), "Stream")
+        .string("url", "url", stream.url.as_deref())
+        .string("track", "track", stream.track.as_ref())
+        .string(
+            "status_callback",
+            "statusCallback",
+            stream.status_callback.as_deref(),
+        )
+        .string(
+            "status_callback_method",
+            "statusCallbackMethod",
+            stream.status_callback_method.as_deref(),
+        );
+}
@@ -0,0 +1,367 @@
+//! Text-to-speech voices for `<Say>`
+//!
+//! [`Voice`] names every voice Twilio accepts in the `voice` attribute of
+//! `<Say>`: the legacy `man`, `woman` and `alice` voices, Amazon Polly voices
+//! with their engine, and Google voices. Each known voice knows the languages
+//! it speaks, so the validator can report a `voice`/`language` pair that
+//! Twilio would not honour.
+//!
+//! ```rust
+//! use twiml_rust::voice_catalog::{PollyEngine, PollyVoice, Voice};
+//!
+//! let voice = Voice::Polly(PollyVoice::Joanna, PollyEngine::Neural);
+//! assert_eq!(voice.to_string(), "Polly.Joanna-Neural");
+//! assert_eq!(voice.supports_language("en-US"), Some(true));
+//! assert_eq!(voice.supports_language("de-DE"), Some(false));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::serde_support::serde_attribute_value;
+use crate::xml_parser::AttributeValue;
+
+/// Amazon Polly engine a voice is rendered with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PollyEngine {
+    /// `Polly.<Name>`
+    Standard,
+    /// `Polly.<Name>-Neural`
+    Neural,
+    /// `Polly.<Name>-Generative`
+    Generative,
+}
+
+impl PollyEngine {
+    /// Suffix appended to the voice name, empty for the standard engine
+    fn suffix(&self) -> &'static str {
+        match self {
+            Self::Standard => "",
+            Self::Neural => "-Neural",
+            Self::Generative => "-Generative",
+        }
+    }
+}
+
+/// Declares [`PollyVoice`] with the languages and engines of each voice
+macro_rules! polly_voices {
+    ($($name:ident => [$($language:literal),+], [$($engine:ident),+];)+) => {
+        /// An Amazon Polly voice
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum PollyVoice {
+            $(
+                #[doc = concat!("`Polly.", stringify!($name), "` (", $($language, " ",)+ ")")]
+                $name,
+            )+
+        }
+
+        impl PollyVoice {
+            /// Every Polly voice in the catalog
+            pub const ALL: &'static [PollyVoice] = &[$(PollyVoice::$name,)+];
+
+            /// The voice name, without the `Polly.` prefix or engine suffix
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$name => stringify!($name),)+
+                }
+            }
+
+            /// Languages the voice speaks
+            pub fn languages(&self) -> &'static [&'static str] {
+                match self {
+                    $(Self::$name => &[$($language),+],)+
+                }
+            }
+
+            /// Engines the voice is available with
+            pub fn engines(&self) -> &'static [PollyEngine] {
+                match self {
+                    $(Self::$name => &[$(PollyEngine::$engine),+],)+
+                }
+            }
+        }
+    };
+}
+
+polly_voices! {
+    Aditi => ["hi-IN", "en-IN"], [Standard];
+    Amy => ["en-GB"], [Standard, Neural, Generative];
+    Andres => ["es-MX"], [Neural];
+    Aria => ["en-NZ"], [Neural];
+    Arthur => ["en-GB"], [Neural];
+    Astrid => ["sv-SE"], [Standard];
+    Ayanda => ["en-ZA"], [Neural];
+    Bianca => ["it-IT"], [Standard, Neural];
+    Brian => ["en-GB"], [Standard, Neural];
+    Camila => ["pt-BR"], [Standard, Neural];
+    Carla => ["it-IT"], [Standard];
+    Carmen => ["ro-RO"], [Standard];
+    Celine => ["fr-FR"], [Standard];
+    Chantal => ["fr-CA"], [Standard];
+    Conchita => ["es-ES"], [Standard];
+    Cristiano => ["pt-PT"], [Standard];
+    Daniel => ["de-DE"], [Neural];
+    Danielle => ["en-US"], [Neural, Generative];
+    Dora => ["is-IS"], [Standard];
+    Elin => ["sv-SE"], [Neural];
+    Emma => ["en-GB"], [Standard, Neural];
+    Enrique => ["es-ES"], [Standard];
+    Ewa => ["pl-PL"], [Standard];
+    Filiz => ["tr-TR"], [Standard];
+    Gabrielle => ["fr-CA"], [Neural];
+    Geraint => ["en-GB-WLS"], [Standard];
+    Gregory => ["en-US"], [Neural];
+    Gwyneth => ["cy-GB"], [Standard];
+    Hala => ["ar-AE"], [Neural];
+    Hannah => ["de-AT"], [Neural];
+    Hans => ["de-DE"], [Standard];
+    Hiujin => ["yue-CN"], [Neural];
+    Ida => ["nb-NO"], [Neural];
+    Ines => ["pt-PT"], [Standard, Neural];
+    Isabelle => ["fr-BE"], [Neural];
+    Ivy => ["en-US"], [Standard, Neural];
+    Jacek => ["pl-PL"], [Standard];
+    Jan => ["pl-PL"], [Standard];
+    Joanna => ["en-US"], [Standard, Neural, Generative];
+    Joey => ["en-US"], [Standard, Neural];
+    Justin => ["en-US"], [Standard, Neural];
+    Kajal => ["en-IN", "hi-IN"], [Neural];
+    Karl => ["is-IS"], [Standard];
+    Kazuha => ["ja-JP"], [Neural];
+    Kendra => ["en-US"], [Standard, Neural];
+    Kevin => ["en-US"], [Neural];
+    Kimberly => ["en-US"], [Standard, Neural];
+    Laura => ["nl-NL"], [Neural];
+    Lea => ["fr-FR"], [Standard, Neural];
+    Liam => ["fr-CA"], [Neural];
+    Lisa => ["nl-BE"], [Neural];
+    Liv => ["nb-NO"], [Standard];
+    Lotte => ["nl-NL"], [Standard];
+    Lucia => ["es-ES"], [Standard, Neural, Generative];
+    Lupe => ["es-US"], [Standard, Neural, Generative];
+    Mads => ["da-DK"], [Standard];
+    Maja => ["pl-PL"], [Standard];
+    Marlene => ["de-DE"], [Standard];
+    Mathieu => ["fr-FR"], [Standard];
+    Matthew => ["en-US"], [Standard, Neural, Generative];
+    Maxim => ["ru-RU"], [Standard];
+    Mia => ["es-MX"], [Standard, Neural];
+    Miguel => ["es-US"], [Standard];
+    Mizuki => ["ja-JP"], [Standard];
+    Naja => ["da-DK"], [Standard];
+    Niamh => ["en-IE"], [Neural];
+    Nicole => ["en-AU"], [Standard];
+    Ola => ["pl-PL"], [Neural];
+    Olivia => ["en-AU"], [Neural, Generative];
+    Pedro => ["es-US"], [Neural];
+    Penelope => ["es-US"], [Standard];
+    Raveena => ["en-IN"], [Standard];
+    Remi => ["fr-FR"], [Neural];
+    Ricardo => ["pt-BR"], [Standard];
+    Ruben => ["nl-NL"], [Standard];
+    Russell => ["en-AU"], [Standard];
+    Ruth => ["en-US"], [Neural, Generative];
+    Salli => ["en-US"], [Standard, Neural];
+    Seoyeon => ["ko-KR"], [Standard, Neural];
+    Sergio => ["es-ES"], [Neural];
+    Stephen => ["en-US"], [Neural, Generative];
+    Suvi => ["fi-FI"], [Neural];
+    Takumi => ["ja-JP"], [Standard, Neural];
+    Tatyana => ["ru-RU"], [Standard];
+    Thiago => ["pt-BR"], [Neural];
+    Tomoko => ["ja-JP"], [Neural];
+    Vicki => ["de-DE"], [Standard, Neural];
+    Vitoria => ["pt-BR"], [Standard, Neural];
+    Zeina => ["arb"], [Standard];
+    Zhiyu => ["cmn-CN"], [Standard, Neural];
+}
+
+/// Google text-to-speech voice model
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GoogleVoiceModel {
+    Standard,
+    Wavenet,
+    Neural2,
+    Journey,
+}
+
+impl GoogleVoiceModel {
+    const ALL: &'static [GoogleVoiceModel] =
+        &[Self::Standard, Self::Wavenet, Self::Neural2, Self::Journey];
+
+    /// The model as written in the voice name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Standard => "Standard",
+            Self::Wavenet => "Wavenet",
+            Self::Neural2 => "Neural2",
+            Self::Journey => "Journey",
+        }
+    }
+}
+
+/// Languages of the legacy `man` and `woman` voices
+const MAN_WOMAN_LANGUAGES: &[&str] = &["en-US", "en-GB", "es-ES", "fr-FR", "de-DE"];
+
+/// Languages of the legacy `alice` voice
+const ALICE_LANGUAGES: &[&str] = &[
+    "da-DK", "de-DE", "en-AU", "en-CA", "en-GB", "en-IN", "en-US", "ca-ES", "es-ES", "es-MX",
+    "fi-FI", "fr-CA", "fr-FR", "it-IT", "ja-JP", "ko-KR", "nb-NO", "nl-NL", "pl-PL", "pt-BR",
+    "pt-PT", "ru-RU", "sv-SE", "zh-CN", "zh-HK", "zh-TW",
+];
+
+/// `voice` on `<Say>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Voice {
+    /// `man` (legacy)
+    Man,
+    /// `woman` (legacy)
+    Woman,
+    /// `alice` (legacy)
+    Alice,
+    /// `Polly.<Name>`, `Polly.<Name>-Neural` or `Polly.<Name>-Generative`
+    Polly(PollyVoice, PollyEngine),
+    /// `Google.<language>-<model>-<variant>`, e.g. `Google.en-US-Neural2-A`
+    Google {
+        language: String,
+        model: GoogleVoiceModel,
+        variant: String,
+    },
+    /// Any other voice, rendered as-is
+    Custom(String),
+}
+
+impl Voice {
+    /// A Google voice such as `Google.en-US-Neural2-A`
+    pub fn google(
+        language: impl Into<String>,
+        model: GoogleVoiceModel,
+        variant: impl Into<String>,
+    ) -> Self {
+        Self::Google {
+            language: language.into(),
+            model,
+            variant: variant.into(),
+        }
+    }
+
+    /// Whether the voice speaks `language`, or `None` for a custom voice
+    ///
+    /// Language codes compare case-insensitively, and a bare language such
+    /// as `en` matches any region of it.
+    pub fn supports_language(&self, language: &str) -> Option<bool> {
+        let supported = match self {
+            Self::Man | Self::Woman => language_in(MAN_WOMAN_LANGUAGES, language),
+            Self::Alice => language_in(ALICE_LANGUAGES, language),
+            Self::Polly(voice, _) => language_in(voice.languages(), language),
+            Self::Google {
+                language: spoken, ..
+            } => language_in(&[spoken.as_str()], language),
+            Self::Custom(_) => return None,
+        };
+        Some(supported)
+    }
+
+    /// Whether a Polly voice is offered with its engine; always true for
+    /// other voices
+    pub fn is_available(&self) -> bool {
+        match self {
+            Self::Polly(voice, engine) => voice.engines().contains(engine),
+            _ => true,
+        }
+    }
+}
+
+/// Whether `language` is one of `languages`, ignoring case; a bare language
+/// such as `en` matches any region of it, on either side
+pub(crate) fn language_in(languages: &[&str], language: &str) -> bool {
+    let primary = |tag: &str| tag.split('-').next().unwrap_or(tag).to_ascii_lowercase();
+    languages.iter().any(|supported| {
+        supported.eq_ignore_ascii_case(language)
+            || (!language.contains('-') && primary(supported) == language.to_ascii_lowercase())
+            || (!supported.contains('-') && primary(language) == supported.to_ascii_lowercase())
+    })
+}
+
+impl fmt::Display for Voice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Man => f.write_str("man"),
+            Self::Woman => f.write_str("woman"),
+            Self::Alice => f.write_str("alice"),
+            Self::Polly(voice, engine) => write!(f, "Polly.{}{}", voice.name(), engine.suffix()),
+            Self::Google {
+                language,
+                model,
+                variant,
+            } => write!(f, "Google.{}-{}-{}", language, model.as_str(), variant),
+            Self::Custom(voice) => f.write_str(voice),
+        }
+    }
+}
+
+impl FromStr for Voice {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let voice = match s {
+            "man" => Some(Self::Man),
+            "woman" => Some(Self::Woman),
+            "alice" => Some(Self::Alice),
+            _ => {
+                if let Some(name) = s.strip_prefix("Polly.") {
+                    parse_polly(name)
+                } else if let Some(name) = s.strip_prefix("Google.") {
+                    parse_google(name)
+                } else {
+                    None
+                }
+            }
+        };
+        voice.ok_or_else(|| {
+            Error::invalid_parameter(
+                "voice",
+                format!(
+                    "expected man, woman, alice or a known Polly.* or Google.* voice; got '{}' (use Voice::Custom for other values)",
+                    s
+                ),
+            )
+        })
+    }
+}
+
+fn parse_polly(name: &str) -> Option<Voice> {
+    let (name, engine) = if let Some(name) = name.strip_suffix("-Neural") {
+        (name, PollyEngine::Neural)
+    } else if let Some(name) = name.strip_suffix("-Generative") {
+        (name, PollyEngine::Generative)
+    } else {
+        (name, PollyEngine::Standard)
+    };
+    PollyVoice::ALL
+        .iter()
+        .find(|voice| voice.name() == name)
+        .map(|voice| Voice::Polly(*voice, engine))
+}
+
+fn parse_google(name: &str) -> Option<Voice> {
+    GoogleVoiceModel::ALL.iter().find_map(|model| {
+        let marker = format!("-{}-", model.as_str());
+        let (language, variant) = name.split_once(&marker)?;
+        if language.is_empty() || variant.is_empty() {
+            return None;
+        }
+        Some(Voice::google(language, *model, variant))
+    })
+}
+
+impl AttributeValue for Voice {
+    fn from_attribute(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Custom(value.to_string()))
+    }
+}
+
+serde_attribute_value!(Voice);
@@ -0,0 +1,685 @@
+//! Typed access to the webhook requests Twilio sends.
+//!
+//! Twilio calls your application with an `application/x-www-form-urlencoded`
+//! body describing the call, message or fax. [`FormParams`] decodes such a
+//! body without any dependencies, and [`VoiceRequest`], [`MessagingRequest`]
+//! and [`FaxRequest`] pick out the standard parameters:
+//!
+//! ```
+//! use twiml_rust::webhook::{CallStatus, VoiceRequest};
+//!
+//! let body = "CallSid=CA123&AccountSid=AC456&From=%2B15551230001&To=%2B15559870002\
+//!             &CallStatus=ringing&Direction=inbound&CallerCity=SAN+FRANCISCO";
+//! let request = VoiceRequest::from_form(body).unwrap();
+//!
+//! assert_eq!(request.from, "+15551230001");
+//! assert_eq!(request.call_status, CallStatus::Ringing);
+//! assert_eq!(request.caller.city.as_deref(), Some("SAN FRANCISCO"));
+//! ```
+//!
+//! [`GatherResult`] and [`GatherPartialResult`] read the callbacks of a
+//! `<Gather>` and can be checked against the [`Gather`] that produced them.
+//!
+//! Parameters without a field of their own stay available through the
+//! `params` field of each request. A missing required parameter is reported
+//! as [`Error::InvalidParameter`] naming the parameter.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::voice::{Gather, GatherInput};
+
+/// Define an enum for a webhook parameter with a fixed set of values
+///
+/// Parsing never fails: values Twilio adds later become `Custom`.
+macro_rules! webhook_values {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($variant:ident => $value:literal,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                #[doc = concat!("`", $value, "`")]
+                $variant,
+            )+
+            /// Any other value
+            Custom(String),
+        }
+
+        impl $name {
+            /// The parameter value as Twilio sends it
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)+
+                    Self::Custom(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => Self::$variant,)+
+                    _ => Self::Custom(value.to_string()),
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+webhook_values! {
+    /// `CallStatus` of a voice request
+    pub enum CallStatus {
+        Queued => "queued",
+        Ringing => "ringing",
+        InProgress => "in-progress",
+        Completed => "completed",
+        Busy => "busy",
+        Failed => "failed",
+        NoAnswer => "no-answer",
+        Canceled => "canceled",
+    }
+}
+
+webhook_values! {
+    /// `Direction` of a voice request
+    pub enum CallDirection {
+        Inbound => "inbound",
+        OutboundApi => "outbound-api",
+        OutboundDial => "outbound-dial",
+        TrunkingOriginating => "trunking-originating",
+        TrunkingTerminating => "trunking-terminating",
+    }
+}
+
+webhook_values! {
+    /// `FaxStatus` of a fax request
+    pub enum FaxStatus {
+        Queued => "queued",
+        Processing => "processing",
+        Sending => "sending",
+        Delivered => "delivered",
+        Receiving => "receiving",
+        Received => "received",
+        NoAnswer => "no-answer",
+        Busy => "busy",
+        Failed => "failed",
+        Canceled => "canceled",
+    }
+}
+
+/// Decoded `application/x-www-form-urlencoded` parameters, in request order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormParams {
+    pairs: Vec<(String, String)>,
+}
+
+impl FormParams {
+    /// Decode a form body
+    ///
+    /// `+` decodes to a space and `%XX` to the byte it names; the decoded
+    /// bytes must be UTF-8. Empty `&&` segments are skipped and a name
+    /// without `=` gets an empty value.
+    ///
+    /// # Example
+    /// ```
+    /// use twiml_rust::webhook::FormParams;
+    ///
+    /// let params = FormParams::parse("Body=Hello+%26+bye&Empty").unwrap();
+    /// assert_eq!(params.get("Body"), Some("Hello & bye"));
+    /// assert_eq!(params.get("Empty"), Some(""));
+    /// ```
+    pub fn parse(body: &str) -> Result<Self> {
+        let mut pairs = Vec::new();
+        for segment in body.split('&').filter(|s| !s.is_empty()) {
+            let (name, value) = segment.split_once('=').unwrap_or((segment, ""));
+            pairs.push((decode_component(name)?, decode_component(value)?));
+        }
+        Ok(Self { pairs })
+    }
+
+    /// The first value of the parameter `name`
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.pairs
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Every parameter, in request order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.pairs.iter().map(|(n, v)| (n.as_str(), v.as_str()))
+    }
+
+    /// Number of parameters
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Whether there are no parameters
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// The parameter `name`, which the request must carry
+    pub(crate) fn required(&self, name: &str) -> Result<String> {
+        self.get(name)
+            .map(str::to_string)
+            .ok_or_else(|| Error::invalid_parameter(name, "missing from the webhook request"))
+    }
+
+    /// The parameter `name`, if present and not empty
+    pub(crate) fn optional(&self, name: &str) -> Option<String> {
+        self.get(name).filter(|v| !v.is_empty()).map(str::to_string)
+    }
+
+    /// The parameter `name` parsed as `T`, if present and not empty
+    pub(crate) fn parsed<T: FromStr>(&self, name: &str) -> Result<Option<T>> {
+        match self.get(name).filter(|v| !v.is_empty()) {
+            None => Ok(None),
+            Some(value) => value.parse().map(Some).map_err(|_| {
+                Error::invalid_parameter(name, format!("unexpected value '{}'", value))
+            }),
+        }
+    }
+}
+
+impl<N: Into<String>, V: Into<String>> FromIterator<(N, V)> for FormParams {
+    fn from_iter<I: IntoIterator<Item = (N, V)>>(iter: I) -> Self {
+        Self {
+            pairs: iter
+                .into_iter()
+                .map(|(n, v)| (n.into(), v.into()))
+                .collect(),
+        }
+    }
+}
+
+impl FromStr for FormParams {
+    type Err = Error;
+
+    fn from_str(body: &str) -> Result<Self> {
+        Self::parse(body)
+    }
+}
+
+/// Decode one name or value of a form body
+fn decode_component(component: &str) -> Result<String> {
+    if !component.contains(['%', '+']) {
+        return Ok(component.to_string());
+    }
+
+    let bytes = component.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                let byte = bytes
+                    .get(i + 1..i + 3)
+                    .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or_else(|| {
+                        Error::parse("form", format!("invalid percent escape in '{}'", component))
+                    })?;
+                decoded.push(byte);
+                i += 2;
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8(decoded)
+        .map_err(|_| Error::parse("form", format!("'{}' does not decode to UTF-8", component)))
+}
+
+/// Geographic data Twilio looks up for a phone number
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Location {
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub zip: Option<String>,
+    pub country: Option<String>,
+}
+
+impl Location {
+    /// The `<prefix>City`, `<prefix>State`, `<prefix>Zip` and
+    /// `<prefix>Country` parameters
+    fn from_params(params: &FormParams, prefix: &str) -> Self {
+        let field = |name: &str| params.optional(&format!("{}{}", prefix, name));
+        Self {
+            city: field("City"),
+            state: field("State"),
+            zip: field("Zip"),
+            country: field("Country"),
+        }
+    }
+}
+
+/// Parameters of a voice webhook request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoiceRequest {
+    /// `CallSid`
+    pub call_sid: String,
+    /// `AccountSid`
+    pub account_sid: String,
+    /// `From`
+    pub from: String,
+    /// `To`
+    pub to: String,
+    /// `CallStatus`
+    pub call_status: CallStatus,
+    /// `Direction`
+    pub direction: CallDirection,
+    /// `ApiVersion`
+    pub api_version: Option<String>,
+    /// `ForwardedFrom`, when the carrier passes on the forwarding number
+    pub forwarded_from: Option<String>,
+    /// `CallerName`, when caller ID lookup is enabled
+    pub caller_name: Option<String>,
+    /// `ParentCallSid`, for the child legs of a `<Dial>`
+    pub parent_call_sid: Option<String>,
+    /// `CallerCity`, `CallerState`, `CallerZip` and `CallerCountry`
+    pub caller: Location,
+    /// `CalledCity`, `CalledState`, `CalledZip` and `CalledCountry`
+    pub called: Location,
+    /// Every parameter of the request
+    pub params: FormParams,
+}
+
+impl VoiceRequest {
+    /// Parse a form-encoded request body
+    pub fn from_form(body: &str) -> Result<Self> {
+        Self::from_params(FormParams::parse(body)?)
+    }
+
+    /// Pick the voice parameters out of decoded form parameters
+    pub fn from_params(params: FormParams) -> Result<Self> {
+        Ok(Self {
+            call_sid: params.required("CallSid")?,
+            account_sid: params.required("AccountSid")?,
+            from: params.required("From")?,
+            to: params.required("To")?,
+            call_status: params.required("CallStatus")?.as_str().into(),
+            direction: params.required("Direction")?.as_str().into(),
+            api_version: params.optional("ApiVersion"),
+            forwarded_from: params.optional("ForwardedFrom"),
+            caller_name: params.optional("CallerName"),
+            parent_call_sid: params.optional("ParentCallSid"),
+            caller: Location::from_params(&params, "Caller"),
+            called: Location::from_params(&params, "Called"),
+            params,
+        })
+    }
+}
+
+/// The input a `<Gather>` collected
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GatheredInput<'a> {
+    /// Keypad digits, from `Digits`
+    Dtmf(&'a str),
+    /// Recognized speech, from `SpeechResult` and `Confidence`
+    Speech {
+        text: &'a str,
+        confidence: Option<f64>,
+    },
+}
+
+/// Why a `<Gather>` stopped collecting input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GatherEnd {
+    /// The caller entered `numDigits` digits
+    NumDigits,
+    /// The caller pressed this `finishOnKey` key
+    FinishOnKey(String),
+    /// The caller stopped speaking
+    Speech,
+    /// The timeout ran out after some digits were entered
+    Timeout,
+    /// Nothing was entered, as posted with `actionOnEmptyResult`
+    NoInput,
+}
+
+/// Parameters of the `action` callback of a `<Gather>`
+#[derive(Debug, Clone, PartialEq)]
+pub struct GatherResult {
+    /// The standard parameters of the call
+    pub call: VoiceRequest,
+    /// `Digits`
+    pub digits: Option<String>,
+    /// `SpeechResult`
+    pub speech_result: Option<String>,
+    /// `Confidence` of the speech result, from 0.0 to 1.0
+    pub confidence: Option<f64>,
+    /// `FinishedOnKey`
+    pub finished_on_key: Option<String>,
+}
+
+impl GatherResult {
+    /// Parse a form-encoded request body
+    pub fn from_form(body: &str) -> Result<Self> {
+        Self::from_params(FormParams::parse(body)?)
+    }
+
+    /// Pick the gather parameters out of decoded form parameters
+    pub fn from_params(params: FormParams) -> Result<Self> {
+        Ok(Self {
+            digits: params.optional("Digits"),
+            speech_result: params.optional("SpeechResult"),
+            confidence: params.parsed("Confidence")?,
+            finished_on_key: params.optional("FinishedOnKey"),
+            call: VoiceRequest::from_params(params)?,
+        })
+    }
+
+    /// The collected input, or `None` if nothing was entered
+    ///
+    /// # Example
+    /// ```
+    /// use twiml_rust::webhook::{GatheredInput, GatherResult};
+    ///
+    /// let body = "CallSid=CA1&AccountSid=AC1&From=a&To=b&CallStatus=in-progress\
+    ///             &Direction=inbound&SpeechResult=sales&Confidence=0.92";
+    /// let result = GatherResult::from_form(body).unwrap();
+    /// match result.input() {
+    ///     Some(GatheredInput::Dtmf(digits)) => println!("pressed {}", digits),
+    ///     Some(GatheredInput::Speech { text, .. }) => assert_eq!(text, "sales"),
+    ///     None => println!("no input"),
+    /// }
+    /// ```
+    pub fn input(&self) -> Option<GatheredInput<'_>> {
+        if let Some(digits) = &self.digits {
+            return Some(GatheredInput::Dtmf(digits));
+        }
+        self.speech_result
+            .as_deref()
+            .map(|text| GatheredInput::Speech {
+                text,
+                confidence: self.confidence,
+            })
+    }
+
+    /// Whether the caller used the keypad
+    pub fn is_dtmf(&self) -> bool {
+        matches!(self.input(), Some(GatheredInput::Dtmf(_)))
+    }
+
+    /// Whether the caller spoke
+    pub fn is_speech(&self) -> bool {
+        matches!(self.input(), Some(GatheredInput::Speech { .. }))
+    }
+
+    /// Why `gather` stopped collecting input
+    ///
+    /// Digits count against `num_digits` on `gather`. When Twilio does not
+    /// report `FinishedOnKey`, fewer digits than `num_digits` are reported
+    /// as [`GatherEnd::Timeout`].
+    pub fn ended_by(&self, gather: &Gather) -> GatherEnd {
+        match self.input() {
+            None => GatherEnd::NoInput,
+            Some(GatheredInput::Speech { .. }) => GatherEnd::Speech,
+            Some(GatheredInput::Dtmf(digits)) => {
+                if let Some(key) = &self.finished_on_key {
+                    return GatherEnd::FinishOnKey(key.clone());
+                }
+                match gather.attributes.num_digits {
+                    Some(n) if digits.chars().count() >= n as usize => GatherEnd::NumDigits,
+                    _ => GatherEnd::Timeout,
+                }
+            }
+        }
+    }
+
+    /// Whether `gather` could have produced this input
+    ///
+    /// The input kind must be enabled by `input` on `gather` (DTMF when it
+    /// is unset), digits must not exceed `num_digits`, and a reported
+    /// `FinishedOnKey` must be the `finish_on_key` of `gather` (`#` when it
+    /// is unset). An empty result is always accepted.
+    pub fn accepted_by(&self, gather: &Gather) -> bool {
+        let a = &gather.attributes;
+        let allows = |kind: &GatherInput| match &a.input {
+            Some(inputs) => inputs.contains(kind),
+            None => *kind == GatherInput::Dtmf,
+        };
+        match self.input() {
+            None => true,
+            Some(GatheredInput::Speech { .. }) => allows(&GatherInput::Speech),
+            Some(GatheredInput::Dtmf(digits)) => {
+                let within_limit = a
+                    .num_digits
+                    .map_or(true, |n| digits.chars().count() <= n as usize);
+                let finish_key = a.finish_on_key.as_deref().unwrap_or("#");
+                let key_matches = self
+                    .finished_on_key
+                    .as_deref()
+                    .map_or(true, |key| finish_key.contains(key));
+                allows(&GatherInput::Dtmf) && within_limit && key_matches
+            }
+        }
+    }
+}
+
+/// Parameters of the `partialResultCallback` of a `<Gather>`
+///
+/// Twilio posts these while the caller is still speaking; they carry the
+/// recognizer's current guess rather than the call's standard parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GatherPartialResult {
+    /// `CallSid`
+    pub call_sid: String,
+    /// `AccountSid`
+    pub account_sid: String,
+    /// `SequenceNumber`, counting the partial results of one `<Gather>`
+    pub sequence_number: Option<u32>,
+    /// `UnstableSpeechResult`, the words recognized so far that may change
+    pub unstable_speech_result: String,
+    /// `StableSpeechResult`, the words that will not change
+    pub stable_speech_result: Option<String>,
+    /// `Stability` of the unstable result, from 0.0 to 1.0
+    pub stability: Option<f64>,
+    /// Every parameter of the request
+    pub params: FormParams,
+}
+
+impl GatherPartialResult {
+    /// Parse a form-encoded request body
+    pub fn from_form(body: &str) -> Result<Self> {
+        Self::from_params(FormParams::parse(body)?)
+    }
+
+    /// Pick the partial result parameters out of decoded form parameters
+    pub fn from_params(params: FormParams) -> Result<Self> {
+        Ok(Self {
+            call_sid: params.required("CallSid")?,
+            account_sid: params.required("AccountSid")?,
+            sequence_number: params.parsed("SequenceNumber")?,
+            unstable_speech_result: params.required("UnstableSpeechResult")?,
+            stable_speech_result: params.optional("StableSpeechResult"),
+            stability: params.parsed("Stability")?,
+            params,
+        })
+    }
+}
+
+/// One media attachment of an inbound MMS
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaAttachment {
+    /// `MediaUrlN`
+    pub url: String,
+    /// `MediaContentTypeN`
+    pub content_type: Option<String>,
+}
+
+/// Parameters of a messaging webhook request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessagingRequest {
+    /// `MessageSid`
+    pub message_sid: String,
+    /// `AccountSid`
+    pub account_sid: String,
+    /// `MessagingServiceSid`, when the number belongs to a Messaging Service
+    pub messaging_service_sid: Option<String>,
+    /// `From`
+    pub from: String,
+    /// `To`
+    pub to: String,
+    /// `Body`, empty for a message with only media
+    pub body: String,
+    /// `NumMedia`
+    pub num_media: u32,
+    /// `MediaUrl0`.. and `MediaContentType0`.., one per `NumMedia`
+    pub media: Vec<MediaAttachment>,
+    /// `NumSegments`
+    pub num_segments: Option<u32>,
+    /// `ApiVersion`
+    pub api_version: Option<String>,
+    /// `FromCity`, `FromState`, `FromZip` and `FromCountry`
+    pub from_location: Location,
+    /// `ToCity`, `ToState`, `ToZip` and `ToCountry`
+    pub to_location: Location,
+    /// Every parameter of the request
+    pub params: FormParams,
+}
+
+impl MessagingRequest {
+    /// Parse a form-encoded request body
+    pub fn from_form(body: &str) -> Result<Self> {
+        Self::from_params(FormParams::parse(body)?)
+    }
+
+    /// Pick the messaging parameters out of decoded form parameters
+    pub fn from_params(params: FormParams) -> Result<Self> {
+        let num_media = params.parsed("NumMedia")?.unwrap_or(0);
+        let media = (0..num_media)
+            .map(|i| {
+                Ok(MediaAttachment {
+                    url: params.required(&format!("MediaUrl{}", i))?,
+                    content_type: params.optional(&format!("MediaContentType{}", i)),
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            message_sid: params.required("MessageSid")?,
+            account_sid: params.required("AccountSid")?,
+            messaging_service_sid: params.optional("MessagingServiceSid"),
+            from: params.required("From")?,
+            to: params.required("To")?,
+            body: params.get("Body").unwrap_or_default().to_string(),
+            num_media,
+            media,
+            num_segments: params.parsed("NumSegments")?,
+            api_version: params.optional("ApiVersion"),
+            from_location: Location::from_params(&params, "From"),
+            to_location: Location::from_params(&params, "To"),
+            params,
+        })
+    }
+}
+
+/// Parameters of a fax webhook request
+///
+/// The same struct covers the request for an incoming fax and the `action`
+/// callback of `<Receive>`, which adds the status, pages and media.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FaxRequest {
+    /// `FaxSid`
+    pub fax_sid: String,
+    /// `AccountSid`
+    pub account_sid: String,
+    /// `From`
+    pub from: String,
+    /// `To`
+    pub to: String,
+    /// `ApiVersion`
+    pub api_version: Option<String>,
+    /// `FaxStatus`
+    pub fax_status: Option<FaxStatus>,
+    /// `RemoteStationId`, the sending machine's identifier
+    pub remote_station_id: Option<String>,
+    /// `NumPages`
+    pub num_pages: Option<u32>,
+    /// `MediaUrl`, where the received document can be fetched
+    pub media_url: Option<String>,
+    /// `ErrorCode`, when the fax failed
+    pub error_code: Option<String>,
+    /// `ErrorMessage`, when the fax failed
+    pub error_message: Option<String>,
+    /// Every parameter of the request
+    pub params: FormParams,
+}
+
+impl FaxRequest {
+    /// Parse a form-encoded request body
+    pub fn from_form(body: &str) -> Result<Self> {
+        Self::from_params(FormParams::parse(body)?)
+    }
+
+    /// Pick the fax parameters out of decoded form parameters
+    pub fn from_params(params: FormParams) -> Result<Self> {
+        Ok(Self {
+            fax_sid: params.required("FaxSid")?,
+            account_sid: params.required("AccountSid")?,
+            from: params.required("From")?,
+            to: params.required("To")?,
+            api_version: params.optional("ApiVersion"),
+            fax_status: params.optional("FaxStatus").map(|s| s.as_str().into()),
+            remote_station_id: params.optional("RemoteStationId"),
+            num_pages: params.parsed("NumPages")?,
+            media_url: params.optional("MediaUrl"),
+            error_code: params.optional("ErrorCode"),
+            error_message: params.optional("ErrorMessage"),
+            params,
+        })
+    }
+}
+
+/// A webhook request that can be read from decoded form parameters
+///
+/// Implemented by every request type in this module, and by [`FormParams`]
+/// for handlers that read the parameters themselves. The web framework
+/// extractors are generic over it.
+pub trait WebhookRequest: Sized {
+    /// Pick the request out of decoded form parameters
+    fn from_params(params: FormParams) -> Result<Self>;
+}
+
+impl WebhookRequest for FormParams {
+    fn from_params(params: FormParams) -> Result<Self> {
+        Ok(params)
+    }
+}
+
+macro_rules! webhook_requests {
+    ($($request:ty),+ $(,)?) => {
+        $(
+            impl WebhookRequest for $request {
+                fn from_params(params: FormParams) -> Result<Self> {
+                    <$request>::from_params(params)
+                }
+            }
+        )+
+    };
+}
+
+webhook_requests!(
+    VoiceRequest,
+    GatherResult,
+    GatherPartialResult,
+    MessagingRequest,
+    FaxRequest,
+);
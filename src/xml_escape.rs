@@ -2,6 +2,32 @@
 //!
 //! This module provides functions to safely escape user-provided content
 //! before inserting it into XML documents.
+//!
+//! The `escape_*` functions return a [`Cow`] that borrows the input when
+//! nothing needs escaping; the `write_*` functions stream the escaped text
+//! into a [`fmt::Write`] sink without allocating. All of them drop characters
+//! that XML 1.0 does not allow (see [`is_xml_char`]), so caller input with
+//! stray control characters cannot produce a document Twilio rejects.
+
+use std::borrow::Cow;
+use std::fmt;
+
+/// Whether `c` may appear in an XML 1.0 document
+///
+/// XML 1.0 forbids the C0 control characters other than tab, line feed and
+/// carriage return, and the noncharacters U+FFFE and U+FFFF.
+///
+/// # Example
+/// ```
+/// use twiml_rust::xml_escape::is_xml_char;
+///
+/// assert!(is_xml_char('\n'));
+/// assert!(!is_xml_char('\u{0}'));
+/// assert!(!is_xml_char('\u{ffff}'));
+/// ```
+pub fn is_xml_char(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\r') || c >= ' ' && !matches!(c, '\u{fffe}' | '\u{ffff}')
+}
 
 /// Escape XML text content.
 ///
@@ -10,13 +36,15 @@
 /// - `<` → `&lt;`
 /// - `>` → `&gt;`
 ///
-/// This function should be used for text content between XML tags.
+/// Characters that are illegal in XML 1.0 are removed. This function should
+/// be used for text content between XML tags.
 ///
 /// # Arguments
 /// * `text` - The text to escape
 ///
 /// # Returns
-/// The escaped text safe for use in XML content
+/// The escaped text safe for use in XML content, borrowed from `text` when
+/// nothing had to change
 ///
 /// # Example
 /// ```
@@ -25,15 +53,8 @@
 /// let safe = escape_xml_text("Hello <script>alert('xss')</script>");
 /// assert_eq!(safe, "Hello &lt;script&gt;alert('xss')&lt;/script&gt;");
 /// ```
-pub fn escape_xml_text(text: &str) -> String {
-    text.chars()
-        .flat_map(|c| match c {
-            '&' => "&amp;".chars().collect::<Vec<_>>(),
-            '<' => "&lt;".chars().collect::<Vec<_>>(),
-            '>' => "&gt;".chars().collect::<Vec<_>>(),
-            _ => vec![c],
-        })
-        .collect()
+pub fn escape_xml_text(text: &str) -> Cow<'_, str> {
+    escape(text, false)
 }
 
 /// Escape XML attribute values.
@@ -45,13 +66,15 @@ pub fn escape_xml_text(text: &str) -> String {
 /// - `"` → `&quot;`
 /// - `'` → `&apos;`
 ///
-/// This function should be used for attribute values in XML tags.
+/// Characters that are illegal in XML 1.0 are removed. This function should
+/// be used for attribute values in XML tags.
 ///
 /// # Arguments
 /// * `text` - The attribute value to escape
 ///
 /// # Returns
-/// The escaped text safe for use in XML attributes
+/// The escaped text safe for use in XML attributes, borrowed from `text` when
+/// nothing had to change
 ///
 /// # Example
 /// ```
@@ -60,15 +83,65 @@ pub fn escape_xml_text(text: &str) -> String {
 /// let safe = escape_xml_attr("value with \"quotes\" and <tags>");
 /// assert_eq!(safe, "value with &quot;quotes&quot; and &lt;tags&gt;");
 /// ```
-pub fn escape_xml_attr(text: &str) -> String {
-    text.chars()
-        .flat_map(|c| match c {
-            '&' => "&amp;".chars().collect::<Vec<_>>(),
-            '<' => "&lt;".chars().collect::<Vec<_>>(),
-            '>' => "&gt;".chars().collect::<Vec<_>>(),
-            '"' => "&quot;".chars().collect::<Vec<_>>(),
-            '\'' => "&apos;".chars().collect::<Vec<_>>(),
-            _ => vec![c],
-        })
-        .collect()
+pub fn escape_xml_attr(text: &str) -> Cow<'_, str> {
+    escape(text, true)
+}
+
+/// Write XML text content into `out`, escaped as by [`escape_xml_text`]
+///
+/// # Example
+/// ```
+/// use twiml_rust::xml_escape::write_xml_text;
+///
+/// let mut xml = String::from("<Say>");
+/// write_xml_text(&mut xml, "Tom & Jerry").unwrap();
+/// assert_eq!(xml, "<Say>Tom &amp; Jerry");
+/// ```
+pub fn write_xml_text<W: fmt::Write + ?Sized>(out: &mut W, text: &str) -> fmt::Result {
+    write_escaped(out, text, false)
+}
+
+/// Write an XML attribute value into `out`, escaped as by [`escape_xml_attr`]
+pub fn write_xml_attr<W: fmt::Write + ?Sized>(out: &mut W, text: &str) -> fmt::Result {
+    write_escaped(out, text, true)
+}
+
+/// The replacement for `c`, or `None` if it is written as is
+fn replacement(c: char, attr: bool) -> Option<&'static str> {
+    match c {
+        '&' => Some("&amp;"),
+        '<' => Some("&lt;"),
+        '>' => Some("&gt;"),
+        '"' if attr => Some("&quot;"),
+        '\'' if attr => Some("&apos;"),
+        c if !is_xml_char(c) => Some(""),
+        _ => None,
+    }
+}
+
+fn escape(text: &str, attr: bool) -> Cow<'_, str> {
+    match text.find(|c| replacement(c, attr).is_some()) {
+        None => Cow::Borrowed(text),
+        Some(first) => {
+            let mut out = String::with_capacity(text.len() + 8);
+            out.push_str(&text[..first]);
+            write_escaped(&mut out, &text[first..], attr)
+                .expect("writing to a String does not fail");
+            Cow::Owned(out)
+        }
+    }
+}
+
+/// Write `text` with each character needing a replacement swapped out, copying
+/// the runs in between as whole slices
+fn write_escaped<W: fmt::Write + ?Sized>(out: &mut W, text: &str, attr: bool) -> fmt::Result {
+    let mut run = 0;
+    for (i, c) in text.char_indices() {
+        if let Some(replacement) = replacement(c, attr) {
+            out.write_str(&text[run..i])?;
+            out.write_str(replacement)?;
+            run = i + c.len_utf8();
+        }
+    }
+    out.write_str(&text[run..])
 }
@@ -0,0 +1,318 @@
+//! Minimal XML reader used to load TwiML documents back into the typed model.
+//!
+//! Builds a small element tree from the [`Tokenizer`] token stream, which
+//! covers everything TwiML documents use. Namespace declarations are kept as
+//! plain attributes; DTDs are reported as a parse error instead of being
+//! guessed at.
+
+use crate::error::{Error, Result};
+use crate::xml_tokenizer::{decode_entities, Position, Spanned, Token, Tokenizer};
+
+/// A model type that can be read back from an attribute value.
+///
+/// Reading never fails: values the model has no name for are kept verbatim.
+pub(crate) trait AttributeValue: Sized {
+    fn from_attribute(value: &str) -> Self;
+}
+
+/// A node inside an element
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Node {
+    Element(Element),
+    Text(String),
+    Comment(String),
+}
+
+/// An XML element with its attributes (in document order) and children
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+/// A parsed document: the root element plus comments around it
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Document {
+    pub comments_before: Vec<String>,
+    pub root: Element,
+    pub comments_after: Vec<String>,
+}
+
+/// Parse a complete XML document
+pub(crate) fn parse_document(xml: &str) -> Result<Document> {
+    let mut comments_before = Vec::new();
+    let mut root = None;
+    let mut comments_after = Vec::new();
+    let mut stack: Vec<Element> = Vec::new();
+
+    let mut tokens = Tokenizer::new(xml);
+    for token in tokens.by_ref() {
+        let Spanned { token, position } =
+            token.map_err(|e| error(&stack, e.message, e.position))?;
+        match token {
+            Token::Declaration(_) | Token::ProcessingInstruction { .. } => {}
+            Token::Doctype(_) => {
+                return Err(error(
+                    &stack,
+                    "document type declarations are not supported",
+                    position,
+                ))
+            }
+            Token::Comment(raw) => {
+                let comment =
+                    decode_entities(raw.trim()).unwrap_or_else(|_| raw.trim().to_string());
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(Node::Comment(comment)),
+                    None if root.is_none() => comments_before.push(comment),
+                    None => comments_after.push(comment),
+                }
+            }
+            Token::StartTag {
+                name,
+                attributes,
+                self_closing,
+            } => {
+                if stack.is_empty() && root.is_some() {
+                    return Err(error(&stack, "multiple root elements", position));
+                }
+                let element = Element {
+                    name: name.to_string(),
+                    attributes: attributes
+                        .into_iter()
+                        .map(|a| (a.name.to_string(), a.value))
+                        .collect(),
+                    children: Vec::new(),
+                };
+                if self_closing {
+                    attach(&mut stack, &mut root, element);
+                } else {
+                    stack.push(element);
+                }
+            }
+            Token::EndTag { name } => match stack.last() {
+                Some(open) if open.name == name => {
+                    let element = stack.pop().expect("stack is not empty");
+                    attach(&mut stack, &mut root, element);
+                }
+                Some(open) => {
+                    let reason = format!(
+                        "mismatched closing tag: expected </{}>, found </{}>",
+                        open.name, name
+                    );
+                    return Err(error(&stack, reason, position));
+                }
+                None => {
+                    let reason = format!("unexpected closing tag </{}>", name);
+                    return Err(error(&stack, reason, position));
+                }
+            },
+            Token::Text(text) => match stack.last_mut() {
+                Some(parent) => push_text(&mut parent.children, text),
+                None if text.trim().is_empty() => {}
+                None => return Err(error(&stack, "text outside of the root element", position)),
+            },
+            Token::CData(text) => match stack.last_mut() {
+                Some(parent) => push_text(&mut parent.children, text.to_string()),
+                None => return Err(error(&stack, "text outside of the root element", position)),
+            },
+        }
+    }
+
+    if let Some(open) = stack.last() {
+        let reason = format!("unclosed element <{}>", open.name);
+        return Err(error(&stack, reason, tokens.location()));
+    }
+    match root {
+        Some(root) => Ok(Document {
+            comments_before,
+            root,
+            comments_after,
+        }),
+        None => Err(Error::parse("", "document has no root element")),
+    }
+}
+
+/// Add a finished element to its parent, or make it the root
+fn attach(stack: &mut [Element], root: &mut Option<Element>, element: Element) {
+    match stack.last_mut() {
+        Some(parent) => parent.children.push(Node::Element(element)),
+        None => *root = Some(element),
+    }
+}
+
+/// Parse error at `position`, with the path of the currently open elements
+fn error(stack: &[Element], reason: impl Into<String>, position: Position) -> Error {
+    let path = stack
+        .iter()
+        .map(|e| e.name.as_str())
+        .collect::<Vec<_>>()
+        .join("/");
+    Error::parse(
+        path,
+        format!(
+            "{} (line {}, column {})",
+            reason.into(),
+            position.line,
+            position.column
+        ),
+    )
+}
+
+/// Append text, merging with a preceding text node (e.g. text around CDATA)
+fn push_text(children: &mut Vec<Node>, text: String) {
+    if let Some(Node::Text(previous)) = children.last_mut() {
+        previous.push_str(&text);
+    } else {
+        children.push(Node::Text(text));
+    }
+}
+
+impl Element {
+    /// Start reading this element's attributes; see [`Attributes::finish`]
+    pub(crate) fn attributes<'a>(&'a self, path: &'a str) -> Attributes<'a> {
+        Attributes {
+            path,
+            element: &self.name,
+            remaining: self
+                .attributes
+                .iter()
+                .map(|(n, v)| (n.as_str(), v.as_str()))
+                .collect(),
+        }
+    }
+
+    /// Child elements, rejecting any non-whitespace text. Comments are skipped.
+    pub(crate) fn child_elements(&self, path: &str) -> Result<Vec<&Element>> {
+        let mut elements = Vec::new();
+        for child in &self.children {
+            match child {
+                Node::Element(e) => elements.push(e),
+                Node::Text(t) if t.trim().is_empty() => {}
+                Node::Text(t) => {
+                    return Err(Error::parse(
+                        path,
+                        format!("unexpected text content '{}'", t.trim()),
+                    ))
+                }
+                Node::Comment(_) => {}
+            }
+        }
+        Ok(elements)
+    }
+
+    /// Text content of an element that must not contain child elements
+    pub(crate) fn text(&self, path: &str) -> Result<String> {
+        let mut text = String::new();
+        for child in &self.children {
+            match child {
+                Node::Text(t) => text.push_str(t),
+                Node::Element(e) => {
+                    return Err(Error::unknown_element(path, e.name.clone()));
+                }
+                Node::Comment(_) => {}
+            }
+        }
+        Ok(text)
+    }
+
+    /// Reject attributes and content on elements that take neither
+    pub(crate) fn expect_empty(&self, path: &str) -> Result<()> {
+        self.attributes(path).finish()?;
+        self.expect_empty_content(path)
+    }
+
+    /// Reject child elements and text on elements that only take attributes
+    pub(crate) fn expect_empty_content(&self, path: &str) -> Result<()> {
+        match self.child_elements(path)?.first() {
+            Some(e) => Err(Error::unknown_element(path, e.name.clone())),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Consumes attributes by name so unknown leftovers can be reported
+pub(crate) struct Attributes<'a> {
+    path: &'a str,
+    element: &'a str,
+    remaining: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> Attributes<'a> {
+    /// Take a string attribute
+    pub(crate) fn string(&mut self, name: &str) -> Option<String> {
+        let index = self.remaining.iter().position(|(n, _)| *n == name)?;
+        Some(self.remaining.remove(index).1.to_string())
+    }
+
+    /// Take an unsigned integer attribute
+    pub(crate) fn u32(&mut self, name: &str) -> Result<Option<u32>> {
+        match self.string(name) {
+            Some(v) => v.trim().parse().map(Some).map_err(|_| {
+                Error::invalid_parameter(
+                    name,
+                    format!(
+                        "expected a non-negative integer on <{}>, got '{}'",
+                        self.element, v
+                    ),
+                )
+            }),
+            None => Ok(None),
+        }
+    }
+
+    /// Take a boolean attribute (`true` / `false`)
+    pub(crate) fn bool(&mut self, name: &str) -> Result<Option<bool>> {
+        match self.string(name).as_deref() {
+            Some("true") => Ok(Some(true)),
+            Some("false") => Ok(Some(false)),
+            Some(v) => Err(Error::invalid_parameter(
+                name,
+                format!(
+                    "expected 'true' or 'false' on <{}>, got '{}'",
+                    self.element, v
+                ),
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Take a list attribute whose items are separated by `separator`
+    pub(crate) fn list(&mut self, name: &str, separator: char) -> Option<Vec<String>> {
+        self.string(name).map(|v| {
+            v.split(separator)
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(String::from)
+                .collect()
+        })
+    }
+
+    /// Take an attribute holding one of a fixed set of values
+    pub(crate) fn value<T: AttributeValue>(&mut self, name: &str) -> Option<T> {
+        self.string(name).map(|v| T::from_attribute(&v))
+    }
+
+    /// Take a list of values separated by `separator`
+    pub(crate) fn values<T: AttributeValue>(
+        &mut self,
+        name: &str,
+        separator: char,
+    ) -> Option<Vec<T>> {
+        self.list(name, separator)
+            .map(|v| v.iter().map(|item| T::from_attribute(item)).collect())
+    }
+
+    /// Fail if any attribute was not consumed
+    pub(crate) fn finish(self) -> Result<()> {
+        match self.remaining.first() {
+            Some((name, _)) => Err(Error::unknown_attribute(self.path, *name)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Build the path of a child element for error reporting
+pub(crate) fn child_path(parent: &str, child: &str) -> String {
+    format!("{}/{}", parent, child)
+}
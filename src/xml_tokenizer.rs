@@ -0,0 +1,425 @@
+//! Small XML tokenizer shared by the parser and the validator.
+//!
+//! Splits a document into tags, text, comments, CDATA sections, processing
+//! instructions and document type declarations, recording the line and column
+//! each token starts at. Attribute values and text are entity-decoded here;
+//! matching start and end tags is left to the consumer, which knows what it
+//! wants to do with the nesting.
+
+use crate::xml_escape::is_xml_char;
+
+/// A 1-based line and column in the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// An attribute on a start tag, with its value already decoded
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Attribute<'a> {
+    pub name: &'a str,
+    pub value: String,
+    pub position: Position,
+}
+
+/// A single piece of markup or character data
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Token<'a> {
+    /// The `<?xml ...?>` declaration (only valid at the start of the document)
+    Declaration(&'a str),
+    /// Any other `<?target data?>` processing instruction
+    ProcessingInstruction { target: &'a str, data: &'a str },
+    /// `<!-- ... -->`, raw content between the delimiters
+    Comment(&'a str),
+    /// `<!DOCTYPE ...>`, raw content after the keyword
+    Doctype(&'a str),
+    /// `<name attr="value">` or `<name />`
+    StartTag {
+        name: &'a str,
+        attributes: Vec<Attribute<'a>>,
+        self_closing: bool,
+    },
+    /// `</name>`
+    EndTag { name: &'a str },
+    /// Character data with entities decoded
+    Text(String),
+    /// `<![CDATA[ ... ]]>`, content taken verbatim
+    CData(&'a str),
+}
+
+/// A token together with the position of its first character
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Spanned<'a> {
+    pub token: Token<'a>,
+    pub position: Position,
+}
+
+/// A well-formedness error found while tokenizing
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TokenError {
+    pub message: String,
+    pub position: Position,
+}
+
+/// Iterator over the tokens of an XML document.
+///
+/// Yields at most one error, after which iteration stops.
+pub(crate) struct Tokenizer<'a> {
+    input: &'a str,
+    pos: usize,
+    start: usize,
+    line: usize,
+    column: usize,
+    failed: bool,
+}
+
+impl<'a> Tokenizer<'a> {
+    /// Start tokenizing `input`, skipping a leading byte order mark
+    pub(crate) fn new(input: &'a str) -> Self {
+        let start = if input.starts_with('\u{feff}') {
+            '\u{feff}'.len_utf8()
+        } else {
+            0
+        };
+        Self {
+            input,
+            pos: start,
+            start,
+            line: 1,
+            column: 1,
+            failed: false,
+        }
+    }
+
+    /// Position of the next unread character
+    pub(crate) fn location(&self) -> Position {
+        Position {
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.input.len()
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        self.rest().starts_with(s)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Move forward `bytes` bytes, keeping the line and column up to date
+    fn advance(&mut self, bytes: usize) {
+        let consumed = &self.input[self.pos..self.pos + bytes];
+        let end = advance_position(self.location(), consumed);
+        self.line = end.line;
+        self.column = end.column;
+        self.pos += bytes;
+    }
+
+    fn skip_whitespace(&mut self) -> bool {
+        let len = self.rest().len() - self.rest().trim_start_matches(is_xml_whitespace).len();
+        self.advance(len);
+        len > 0
+    }
+
+    fn error(&self, position: Position, message: impl Into<String>) -> TokenError {
+        TokenError {
+            message: message.into(),
+            position,
+        }
+    }
+
+    /// Consume everything up to and including `terminator`, returning the content
+    fn take_until(
+        &mut self,
+        terminator: &str,
+        what: &str,
+        start: Position,
+    ) -> Result<&'a str, TokenError> {
+        match self.rest().find(terminator) {
+            Some(end) => {
+                let content = &self.rest()[..end];
+                self.advance(end + terminator.len());
+                Ok(content)
+            }
+            None => Err(self.error(start, format!("Unterminated {}", what))),
+        }
+    }
+
+    fn read_name(&mut self, what: &str) -> Result<&'a str, TokenError> {
+        let rest = self.rest();
+        let mut len = 0;
+        for (i, c) in rest.char_indices() {
+            let valid = if i == 0 {
+                c.is_alphabetic() || matches!(c, '_' | ':')
+            } else {
+                c.is_alphanumeric() || matches!(c, '_' | ':' | '-' | '.')
+            };
+            if !valid {
+                break;
+            }
+            len = i + c.len_utf8();
+        }
+        if len == 0 {
+            return Err(self.error(self.location(), format!("Expected {}", what)));
+        }
+        self.advance(len);
+        Ok(&rest[..len])
+    }
+
+    fn next_token(&mut self) -> Result<Option<Spanned<'a>>, TokenError> {
+        if self.at_end() {
+            return Ok(None);
+        }
+
+        let position = self.location();
+        let at_document_start = self.pos == self.start;
+        let token = if self.starts_with("<?") {
+            self.advance(2);
+            let body = self.take_until("?>", "processing instruction", position)?;
+            let (target, data) = match body.find(is_xml_whitespace) {
+                Some(split) => (&body[..split], body[split..].trim()),
+                None => (body, ""),
+            };
+            if target.is_empty() {
+                return Err(self.error(position, "Processing instruction has no target"));
+            }
+            if target.eq_ignore_ascii_case("xml") {
+                if !at_document_start {
+                    return Err(self.error(
+                        position,
+                        "XML declaration is only allowed at the start of the document",
+                    ));
+                }
+                Token::Declaration(data)
+            } else {
+                Token::ProcessingInstruction { target, data }
+            }
+        } else if self.starts_with("<!--") {
+            self.advance(4);
+            let content = self.take_until("-->", "comment", position)?;
+            if content.contains("--") {
+                return Err(self.error(position, "'--' is not allowed inside comments"));
+            }
+            Token::Comment(content)
+        } else if self.starts_with("<![CDATA[") {
+            self.advance("<![CDATA[".len());
+            Token::CData(self.take_until("]]>", "CDATA section", position)?)
+        } else if self.starts_with("<!DOCTYPE") {
+            self.advance("<!DOCTYPE".len());
+            Token::Doctype(
+                self.take_until(">", "document type declaration", position)?
+                    .trim(),
+            )
+        } else if self.starts_with("<!") {
+            return Err(self.error(position, "Unexpected markup declaration"));
+        } else if self.starts_with("</") {
+            self.advance(2);
+            let name = self.read_name("an element name")?;
+            self.skip_whitespace();
+            if !self.starts_with(">") {
+                return Err(self.error(
+                    self.location(),
+                    format!("Expected '>' to end closing tag </{}>", name),
+                ));
+            }
+            self.advance(1);
+            Token::EndTag { name }
+        } else if self.starts_with("<") {
+            self.advance(1);
+            self.read_start_tag(position)?
+        } else {
+            let end = self.rest().find('<').unwrap_or(self.rest().len());
+            let raw = &self.rest()[..end];
+            if let Some(offset) = raw.find("]]>") {
+                let at = advance_position(position, &raw[..offset]);
+                return Err(self.error(at, "']]>' is not allowed in text"));
+            }
+            let text = decode(raw, position)?;
+            self.advance(end);
+            Token::Text(text)
+        };
+
+        Ok(Some(Spanned { token, position }))
+    }
+
+    fn read_start_tag(&mut self, position: Position) -> Result<Token<'a>, TokenError> {
+        let name = self.read_name("an element name")?;
+        let mut attributes: Vec<Attribute<'a>> = Vec::new();
+
+        loop {
+            let had_space = self.skip_whitespace();
+            if self.starts_with("/>") {
+                self.advance(2);
+                return Ok(Token::StartTag {
+                    name,
+                    attributes,
+                    self_closing: true,
+                });
+            }
+            if self.starts_with(">") {
+                self.advance(1);
+                return Ok(Token::StartTag {
+                    name,
+                    attributes,
+                    self_closing: false,
+                });
+            }
+            if self.at_end() {
+                return Err(self.error(position, format!("Unterminated start tag <{}>", name)));
+            }
+            if !had_space {
+                return Err(self.error(self.location(), "Expected whitespace between attributes"));
+            }
+
+            let attribute_position = self.location();
+            let attribute = self.read_name("an attribute name")?;
+            self.skip_whitespace();
+            if !self.starts_with("=") {
+                return Err(self.error(
+                    self.location(),
+                    format!("Expected '=' after attribute '{}'", attribute),
+                ));
+            }
+            self.advance(1);
+            self.skip_whitespace();
+
+            let quote = match self.peek() {
+                Some(q @ ('"' | '\'')) => q,
+                _ => {
+                    return Err(self.error(
+                        self.location(),
+                        format!("Value of attribute '{}' must be quoted", attribute),
+                    ))
+                }
+            };
+            self.advance(1);
+            let value_position = self.location();
+            let raw = self.take_until(
+                quote.encode_utf8(&mut [0; 4]),
+                "attribute value",
+                attribute_position,
+            )?;
+            if let Some(offset) = raw.find('<') {
+                let at = advance_position(value_position, &raw[..offset]);
+                return Err(self.error(at, "'<' is not allowed in attribute values"));
+            }
+            let value = decode(raw, value_position)?;
+
+            if attributes.iter().any(|a| a.name == attribute) {
+                return Err(self.error(
+                    attribute_position,
+                    format!("Duplicate attribute '{}' on <{}>", attribute, name),
+                ));
+            }
+            attributes.push(Attribute {
+                name: attribute,
+                value,
+                position: attribute_position,
+            });
+        }
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Result<Spanned<'a>, TokenError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        match self.next_token() {
+            Ok(token) => token.map(Ok),
+            Err(e) => {
+                self.failed = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+fn is_xml_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r')
+}
+
+/// Position reached after reading `text` starting at `start`
+fn advance_position(start: Position, text: &str) -> Position {
+    let mut position = start;
+    for c in text.chars() {
+        if c == '\n' {
+            position.line += 1;
+            position.column = 1;
+        } else {
+            position.column += 1;
+        }
+    }
+    position
+}
+
+/// Decode entities in `raw`, which starts at `start` in the input
+fn decode(raw: &str, start: Position) -> Result<String, TokenError> {
+    decode_entities_at(raw).map_err(|(offset, message)| TokenError {
+        message,
+        position: advance_position(start, &raw[..offset]),
+    })
+}
+
+/// Decode the predefined entities and numeric character references
+pub(crate) fn decode_entities(raw: &str) -> Result<String, String> {
+    decode_entities_at(raw).map_err(|(_, message)| message)
+}
+
+/// Like [`decode_entities`], but also reports the byte offset of a bad reference
+/// or of a character XML 1.0 does not allow
+fn decode_entities_at(raw: &str) -> Result<String, (usize, String)> {
+    if let Some((offset, c)) = raw.char_indices().find(|&(_, c)| !is_xml_char(c)) {
+        return Err((offset, format!("Illegal character U+{:04X}", c as u32)));
+    }
+    if !raw.contains('&') {
+        return Ok(raw.to_string());
+    }
+
+    let mut out = String::with_capacity(raw.len());
+    let mut offset = 0;
+    while let Some(amp) = raw[offset..].find('&') {
+        let amp = offset + amp;
+        out.push_str(&raw[offset..amp]);
+        let after = &raw[amp + 1..];
+        let semi = after
+            .find(';')
+            .ok_or_else(|| (amp, "Unterminated entity reference".to_string()))?;
+        let entity = &after[..semi];
+        let decoded = match entity {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => {
+                let code = if let Some(hex) = entity.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16).ok()
+                } else if let Some(dec) = entity.strip_prefix('#') {
+                    dec.parse::<u32>().ok()
+                } else {
+                    return Err((amp, format!("Unknown entity '&{};'", entity)));
+                };
+                code.and_then(char::from_u32)
+                    .filter(|&c| is_xml_char(c))
+                    .ok_or_else(|| (amp, format!("Invalid character reference '&{};'", entity)))?
+            }
+        };
+        out.push(decoded);
+        offset = amp + 1 + semi + 1;
+    }
+    out.push_str(&raw[offset..]);
+    Ok(out)
+}
@@ -0,0 +1,27 @@
+//! Fixtures shared by the framework integration tests
+
+// Each test crate uses a different subset
+#![allow(dead_code)]
+
+use twiml_rust::signature::RequestValidator;
+use twiml_rust::webhook::FormParams;
+
+/// The auth token of the example in Twilio's security documentation
+pub const TOKEN: &str = "12345";
+
+/// The form body of an incoming call
+pub const VOICE_BODY: &str = "CallSid=CA123&AccountSid=AC456&From=%2B15551230001\
+&To=%2B15559870002&CallStatus=ringing&Direction=inbound";
+
+/// The example request from Twilio's security documentation
+pub const EXAMPLE_HOST: &str = "mycompany.com";
+pub const EXAMPLE_PATH: &str = "/myapp.php?foo=1&bar=2";
+pub const EXAMPLE_BODY: &str = "CallSid=CA1234567890ABCDE&Caller=%2B14158675309&Digits=1234\
+&From=%2B14158675309&To=%2B18005551212";
+pub const EXAMPLE_SIGNATURE: &str = "RSOYDt4T1cUTdK1PDd93/VVr8B8=";
+
+/// The `X-Twilio-Signature` of `body` posted to `https://{host}{path}`
+pub fn signature(host: &str, path: &str, body: &str) -> String {
+    let params = FormParams::parse(body).unwrap();
+    RequestValidator::new(TOKEN).compute_signature(&format!("https://{}{}", host, path), &params)
+}
@@ -1,5 +1,5 @@
 use twiml_rust::fax::{FaxResponse, ReceiveAttributes, ReceiveMediaType, ReceivePageSize};
-use twiml_rust::TwiML;
+use twiml_rust::{RenderOptions, TwiML};
 
 #[test]
 fn test_simple_receive() {
@@ -207,3 +207,71 @@ fn test_empty_response_with_comments() {
     assert!(xml.contains("</Response>"));
     assert!(!xml.contains("<Receive"));
 }
+
+#[test]
+fn test_from_xml_round_trip() {
+    let response = FaxResponse::new().comment_before("fax route").receive(Some(
+        ReceiveAttributes::new()
+            .action("https://example.com/fax?tenant=a&b=1")
+            .method("POST")
+            .media_type(ReceiveMediaType::ImageTiff)
+            .page_size(ReceivePageSize::Legal)
+            .store_media(false),
+    ));
+    let xml = response.to_xml();
+
+    let parsed = FaxResponse::from_xml(&xml).unwrap();
+    assert_eq!(parsed.to_xml(), xml);
+
+    let attributes = parsed.receive_verb().unwrap().attributes();
+    assert_eq!(attributes.media_type, Some(ReceiveMediaType::ImageTiff));
+    assert_eq!(attributes.page_size, Some(ReceivePageSize::Legal));
+    assert_eq!(attributes.store_media, Some(false));
+}
+
+#[test]
+fn test_from_xml_without_receive() {
+    let parsed = FaxResponse::from_xml("<Response></Response>").unwrap();
+    assert!(parsed.receive_verb().is_none());
+}
+
+#[test]
+fn test_from_xml_unknown_media_type() {
+    let xml = r#"<Response><Receive mediaType="image/png"/></Response>"#;
+    let err = FaxResponse::from_xml(xml).unwrap_err();
+    assert!(matches!(
+        err,
+        twiml_rust::Error::InvalidParameter { ref param, .. } if param == "mediaType"
+    ));
+}
+
+#[test]
+fn test_from_xml_unknown_page_size() {
+    let xml = r#"<Response><Receive pageSize="tabloid"/></Response>"#;
+    let err = FaxResponse::from_xml(xml).unwrap_err();
+    assert!(matches!(
+        err,
+        twiml_rust::Error::InvalidParameter { ref param, .. } if param == "pageSize"
+    ));
+}
+
+#[test]
+fn test_to_xml_with_layout() {
+    let response = FaxResponse::new().receive(Some(
+        ReceiveAttributes::new().media_type(ReceiveMediaType::ImageTiff),
+    ));
+
+    assert_eq!(
+        response.to_xml_with(&RenderOptions::compact()),
+        "<Response><Receive mediaType=\"image/tiff\"/></Response>"
+    );
+    assert_eq!(
+        response.to_xml_with(&RenderOptions::pretty()),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Response>\n  <Receive mediaType=\"image/tiff\" />\n</Response>"
+    );
+    // The default layout is unchanged
+    assert_eq!(
+        response.to_xml(),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Response><Receive mediaType=\"image/tiff\"/></Response>"
+    );
+}
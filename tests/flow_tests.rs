@@ -0,0 +1,149 @@
+#![cfg(feature = "flow")]
+
+use std::collections::HashMap;
+
+use twiml_rust::flow::{Flow, Step};
+use twiml_rust::{Error, TwiML};
+
+fn context(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+const MENU: &str = r#"
+steps:
+  - say: "Hello ${caller_name}, thanks for calling."
+  - gather:
+      input: [dtmf, speech]
+      num_digits: 1
+      action: /menu
+      prompts:
+        - say:
+            text: "For sales, press 1."
+            voice: Polly.Joanna-Neural
+        - pause:
+            length: 1
+        - play: https://example.com/jingle.mp3
+  - dial:
+      number: "${agent_number}"
+      timeout: 20
+      record: record-from-answer
+  - record:
+      max_length: 60
+      play_beep: true
+      trim: trim-silence
+  - redirect:
+      url: /menu
+      method: GET
+  - hangup
+"#;
+
+#[test]
+fn test_yaml_flow_compiles_to_twiml() {
+    let flow = Flow::from_yaml(MENU).unwrap();
+    assert_eq!(flow.steps.len(), 6);
+    assert!(matches!(flow.steps[5], Step::Hangup));
+
+    let response = flow
+        .compile(&context(&[
+            ("caller_name", "Ada & Co"),
+            ("agent_number", "+15551234567"),
+        ]))
+        .unwrap();
+    let xml = response.to_xml();
+
+    assert!(xml.contains("<Say>Hello Ada &amp; Co, thanks for calling.</Say>"));
+    assert!(xml.contains("<Gather input=\"dtmf speech\" action=\"/menu\" numDigits=\"1\">"));
+    assert!(xml.contains("<Say voice=\"Polly.Joanna-Neural\">For sales, press 1.</Say>"));
+    assert!(xml.contains("<Pause length=\"1\" />"));
+    assert!(xml.contains("<Play>https://example.com/jingle.mp3</Play>"));
+    assert!(xml.contains("<Dial timeout=\"20\" record=\"record-from-answer\">"));
+    assert!(xml.contains("<Number>+15551234567</Number>"));
+    assert!(xml.contains("<Record maxLength=\"60\" playBeep=\"true\" trim=\"trim-silence\" />"));
+    assert!(xml.contains("<Redirect method=\"GET\">/menu</Redirect>"));
+    assert!(xml.contains("<Hangup />"));
+}
+
+#[test]
+fn test_json_flow() {
+    let flow = Flow::from_json(
+        r#"{"steps": [{"say": {"text": "Hi ${name}", "loop": 2}}, {"redirect": "/next"}, "hangup"]}"#,
+    )
+    .unwrap();
+    let xml = flow
+        .compile(&context(&[("name", "Grace")]))
+        .unwrap()
+        .to_xml();
+    assert!(xml.contains("<Say loop=\"2\">Hi Grace</Say>"));
+    assert!(xml.contains("<Redirect>/next</Redirect>"));
+}
+
+#[test]
+fn test_bare_pause_uses_the_default_length() {
+    let flow = Flow::from_yaml("steps:\n  - pause\n  - gather:\n      prompts: [pause]\n").unwrap();
+    assert!(matches!(&flow.steps[0], Step::Pause(pause) if pause.length.is_none()));
+
+    let xml = flow.compile(&HashMap::new()).unwrap().to_xml();
+    assert!(xml.contains("<Pause />\n"));
+    assert!(xml.contains("<Gather>\n    <Pause />\n  </Gather>"));
+
+    let flow = Flow::from_json(r#"{"steps": ["pause", {"pause": {"length": 2}}]}"#).unwrap();
+    let xml = flow.compile(&HashMap::new()).unwrap().to_xml();
+    assert!(xml.contains("<Pause />\n  <Pause length=\"2\" />"));
+}
+
+#[test]
+fn test_missing_placeholder_is_reported() {
+    let flow = Flow::from_yaml(MENU).unwrap();
+    match flow.compile(&context(&[("caller_name", "Ada")])) {
+        Err(Error::InvalidParameter { param, reason }) => {
+            assert_eq!(param, "steps[2].dial.number");
+            assert!(reason.contains("agent_number"));
+        }
+        other => panic!("expected a missing placeholder, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_misspelled_value_is_reported() {
+    let flow = Flow::from_yaml("steps:\n  - gather:\n      input: [dtmff]\n").unwrap();
+    match flow.compile(&HashMap::new()) {
+        Err(Error::InvalidParameter { param, .. }) => assert_eq!(param, "steps[0].gather.input"),
+        other => panic!("expected an invalid value, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_play_needs_url_or_digits() {
+    let flow = Flow::from_yaml("steps:\n  - play:\n      loop: 2\n").unwrap();
+    match flow.compile(&HashMap::new()) {
+        Err(Error::InvalidParameter { param, .. }) => assert_eq!(param, "steps[0].play"),
+        other => panic!("expected a missing url, got {:?}", other),
+    }
+
+    let flow = Flow::from_yaml("steps:\n  - play:\n      digits: ww1\n").unwrap();
+    assert!(flow.compile(&HashMap::new()).is_ok());
+}
+
+#[test]
+fn test_unknown_fields_are_rejected() {
+    assert!(Flow::from_yaml("steps:\n  - say:\n      txt: Hi\n").is_err());
+    assert!(Flow::from_yaml("steps:\n  - transfer: /x\n").is_err());
+    assert!(Flow::from_yaml("steps:\n  - transfer\n").is_err());
+}
+
+#[test]
+fn test_flow_is_validated_before_rendering() {
+    let flow = Flow::from_yaml("steps:\n  - record:\n      max_length: 20000\n").unwrap();
+    match flow.compile(&HashMap::new()) {
+        Err(Error::Validation(message)) => {
+            assert!(message.contains("verbs[0].Record.attributes.max_length"))
+        }
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+
+    let flow = Flow::from_yaml("steps:\n  - gather:\n      prompts: [hangup]\n").unwrap();
+    assert!(flow.compile(&HashMap::new()).is_err());
+}
@@ -0,0 +1,144 @@
+//! The signature checks every framework integration relies on: the known
+//! signature, rejections and forwarded headers. The axum and actix-web tests
+//! in `framework-tests` only cover what each integration adds.
+#![cfg(feature = "http")]
+
+mod common;
+
+use bytes::Bytes;
+use http::{header, Request, Response, StatusCode};
+use twiml_rust::fax::FaxResponse;
+use twiml_rust::http::{request_url, verify_form, VerifyError};
+use twiml_rust::signature::RequestValidator;
+use twiml_rust::webhook::{FormParams, MessagingRequest, VoiceRequest};
+use twiml_rust::{MessagingResponse, TwiML, VoiceResponse};
+
+use common::{TOKEN, VOICE_BODY};
+
+fn signed(host: &str, path: &str, body: &str) -> Request<Bytes> {
+    Request::post(path)
+        .header(header::HOST, host)
+        .header("X-Twilio-Signature", common::signature(host, path, body))
+        .body(Bytes::copy_from_slice(body.as_bytes()))
+        .unwrap()
+}
+
+#[test]
+fn test_responses_convert_with_content_type() {
+    let voice = VoiceResponse::new().say("Hello");
+    let response: Response<String> = voice.clone().into();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/xml; charset=utf-8"
+    );
+    assert_eq!(response.body(), &voice.to_xml());
+
+    let messaging = MessagingResponse::new().message("Hi");
+    let response = Response::<String>::from(messaging.clone());
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/xml; charset=utf-8"
+    );
+    assert_eq!(response.body(), &messaging.to_xml());
+
+    let response = Response::<String>::from(FaxResponse::new());
+    assert_eq!(response.body(), &FaxResponse::new().to_xml());
+}
+
+#[test]
+fn test_verify_form_parses_signed_requests() {
+    let validator = RequestValidator::new(TOKEN);
+    let call: VoiceRequest =
+        verify_form(&validator, &signed("example.com", "/voice", VOICE_BODY)).unwrap();
+    assert_eq!(call.call_sid, "CA123");
+    assert_eq!(call.from, "+15551230001");
+
+    // Any body that reads as bytes works
+    let request = signed("example.com", "/voice", VOICE_BODY).map(|body| body.to_vec());
+    assert!(verify_form::<FormParams, _>(&validator, &request).is_ok());
+}
+
+#[test]
+fn test_verify_form_accepts_known_signature() {
+    // The host comes from the URI this time
+    let uri = format!("https://{}{}", common::EXAMPLE_HOST, common::EXAMPLE_PATH);
+    let request = Request::post(uri)
+        .header("X-Twilio-Signature", common::EXAMPLE_SIGNATURE)
+        .body(common::EXAMPLE_BODY)
+        .unwrap();
+    let params: FormParams = verify_form(&RequestValidator::new(TOKEN), &request).unwrap();
+    assert_eq!(params.get("Digits"), Some("1234"));
+}
+
+#[test]
+fn test_verify_form_rejections() {
+    let validator = RequestValidator::new(TOKEN);
+
+    let mut request = signed("example.com", "/voice", VOICE_BODY);
+    request.headers_mut().remove("X-Twilio-Signature");
+    let err = verify_form::<VoiceRequest, _>(&validator, &request).unwrap_err();
+    assert!(matches!(err, VerifyError::MissingSignature));
+    assert_eq!(err.status(), StatusCode::FORBIDDEN);
+
+    let request = signed("example.com", "/voice", VOICE_BODY)
+        .map(|_| Bytes::from(VOICE_BODY.replace("ringing", "completed")));
+    let err = verify_form::<VoiceRequest, _>(&validator, &request).unwrap_err();
+    assert!(matches!(err, VerifyError::InvalidSignature));
+    assert_eq!(err.to_string(), "Invalid X-Twilio-Signature");
+
+    let err = verify_form::<VoiceRequest, _>(
+        &RequestValidator::new("54321"),
+        &signed("example.com", "/voice", VOICE_BODY),
+    )
+    .unwrap_err();
+    assert_eq!(err.status(), StatusCode::FORBIDDEN);
+
+    // Signed, but not a messaging request
+    let err = verify_form::<MessagingRequest, _>(
+        &validator,
+        &signed("example.com", "/voice", VOICE_BODY),
+    )
+    .unwrap_err();
+    assert!(matches!(err, VerifyError::InvalidRequest(_)));
+    assert_eq!(err.status(), StatusCode::BAD_REQUEST);
+    assert!(err.to_string().contains("MessageSid"));
+}
+
+#[test]
+fn test_request_url() {
+    let request = |uri: &str| Request::get(uri);
+
+    let req = request("/voice?x=1")
+        .header("Host", "example.com")
+        .body(())
+        .unwrap();
+    assert_eq!(request_url(&req, false), "https://example.com/voice?x=1");
+
+    let req = request("/voice")
+        .header("Host", "10.0.0.5:8080")
+        .header("X-Forwarded-Host", "public.example.com")
+        .header("X-Forwarded-Proto", "http, https")
+        .body(())
+        .unwrap();
+    assert_eq!(request_url(&req, true), "http://public.example.com/voice");
+    assert_eq!(request_url(&req, false), "https://10.0.0.5:8080/voice");
+
+    let req = request("http://example.com:8080/voice").body(()).unwrap();
+    assert_eq!(request_url(&req, false), "http://example.com:8080/voice");
+}
+
+#[test]
+fn test_forwarded_headers_are_ignored_unless_trusted() {
+    let mut request = signed("public.example.com", "/voice", VOICE_BODY);
+    let headers = request.headers_mut();
+    headers.insert(header::HOST, "10.0.0.5:8080".parse().unwrap());
+    headers.insert("X-Forwarded-Host", "public.example.com".parse().unwrap());
+    headers.insert("X-Forwarded-Proto", "https".parse().unwrap());
+
+    let err = verify_form::<VoiceRequest, _>(&RequestValidator::new(TOKEN), &request).unwrap_err();
+    assert!(matches!(err, VerifyError::InvalidSignature));
+
+    let trusting = RequestValidator::new(TOKEN).trust_forwarded_headers(true);
+    assert!(verify_form::<VoiceRequest, _>(&trusting, &request).is_ok());
+}
@@ -0,0 +1,156 @@
+use twiml_rust::messaging::{Body, Media, Message, MessageAttributes, MessagingResponse};
+use twiml_rust::voice::{
+    Dial, DialNumber, Gather, GatherInput, Pause, PauseAttributes, Say, Voice, VoiceResponse,
+};
+use twiml_rust::{twiml, TwiML};
+
+#[test]
+fn test_macro_matches_builders() {
+    let built = VoiceResponse::new()
+        .say_with(Say::new("Welcome").voice(Voice::Alice))
+        .gather_with(
+            Gather::new()
+                .input(vec![GatherInput::Dtmf])
+                .num_digits(1)
+                .action("/menu")
+                .add_say(Say::new("Press 1"))
+                .add_pause(Pause::new(Some(PauseAttributes { length: Some(2) }))),
+        )
+        .dial_with(
+            Dial::new()
+                .timeout(20)
+                .add_number(DialNumber::new("+15551230001")),
+        )
+        .hangup();
+
+    let expanded = twiml!(voice {
+        Say("Welcome").voice(Voice::Alice);
+        Gather.input(vec![GatherInput::Dtmf]).num_digits(1).action("/menu") {
+            Say("Press 1");
+            Pause(Some(PauseAttributes { length: Some(2) }));
+        }
+        Dial.timeout(20) {
+            DialNumber("+15551230001");
+        }
+        Hangup;
+    });
+
+    assert_eq!(expanded.to_xml(), built.to_xml());
+}
+
+#[test]
+fn test_macro_control_flow() {
+    fn menu(tier: u32, agents: &[&str]) -> String {
+        twiml!(voice {
+            if tier == 0 {
+                Say("Basic");
+            } else if tier == 1 {
+                Say("Gold");
+            } else {
+                Say("Platinum");
+            }
+            Dial {
+                for agent in agents.iter().copied() {
+                    DialNumber(agent);
+                }
+            }
+            if let Some(first) = agents.first() {
+                .comment(format!("first agent {first}"));
+            }
+            Hangup;
+        })
+        .to_xml()
+    }
+
+    let xml = menu(1, &["+15551230001", "+15551230002"]);
+    assert!(xml.contains("<Say>Gold</Say>"));
+    assert!(!xml.contains("Basic") && !xml.contains("Platinum"));
+    assert!(xml.contains("<Number>+15551230001</Number>"));
+    assert!(xml.contains("<Number>+15551230002</Number>"));
+    assert!(xml.contains("<!-- first agent +15551230001 -->"));
+    assert!(xml.contains("<Hangup />"));
+
+    let xml = menu(2, &[]);
+    assert!(xml.contains("<Say>Platinum</Say>"));
+    assert!(!xml.contains("<Number>"));
+    assert!(!xml.contains("first agent"));
+}
+
+#[test]
+fn test_macro_interpolation() {
+    let greeting = Say::new("Prebuilt");
+    let response = twiml!(voice {
+        let name = "Ada";
+        Say(format!("Hello {name}"));
+        (greeting);
+        .pause(Some(1));
+        Gather::with_attributes(Default::default()).action("/next") {
+            Say::new("Nested").loop_count(2);
+        }
+    });
+
+    let xml = response.to_xml();
+    assert!(xml.contains("<Say>Hello Ada</Say>"));
+    assert!(xml.contains("<Say>Prebuilt</Say>"));
+    assert!(xml.contains("<Pause length=\"1\" />"));
+    assert!(xml.contains("<Gather action=\"/next\">"));
+    assert!(xml.contains("<Say loop=\"2\">Nested</Say>"));
+}
+
+#[test]
+fn test_macro_messaging() {
+    let urls = ["https://example.com/a.png", "https://example.com/b.png"];
+    let response = twiml!(messaging {
+        Message::with_nouns(MessageAttributes::new().to("+15551234567")) {
+            Body("Your photos");
+            for url in urls {
+                Media(url);
+            }
+        }
+        .redirect("/next");
+    });
+
+    let built = MessagingResponse::new()
+        .message_with_nouns(
+            Message::with_nouns(MessageAttributes::new().to("+15551234567"))
+                .body(Body::new("Your photos"))
+                .add_media(Media::new(urls[0]))
+                .add_media(Media::new(urls[1])),
+        )
+        .redirect("/next");
+
+    assert_eq!(response.to_xml(), built.to_xml());
+}
+
+#[test]
+fn test_macro_empty_response() {
+    assert_eq!(twiml!(voice {}).to_xml(), VoiceResponse::new().to_xml());
+    assert_eq!(
+        twiml!(messaging {}).to_xml(),
+        MessagingResponse::new().to_xml()
+    );
+}
+
+#[test]
+fn test_macro_elements_with_required_values() {
+    let expanded = twiml!(voice {
+        Play::with_url("https://example.com/hold.mp3");
+        Start {
+            Stream::with_url("wss://example.com/audio") {
+                Parameter::with_value("caller", "Ada");
+            }
+        }
+    });
+
+    let xml = expanded.to_xml();
+    assert!(
+        xml.contains("<Play>https://example.com/hold.mp3</Play>"),
+        "{}",
+        xml
+    );
+    assert!(
+        xml.contains("<Parameter name=\"caller\" value=\"Ada\" />"),
+        "{}",
+        xml
+    );
+}
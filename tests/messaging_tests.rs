@@ -1,7 +1,7 @@
 use twiml_rust::messaging::{
     Body, Media, Message, MessageAttributes, MessagingResponse, RedirectAttributes,
 };
-use twiml_rust::TwiML;
+use twiml_rust::{RenderOptions, TwiML};
 
 #[test]
 fn test_simple_message() {
@@ -451,3 +451,90 @@ fn test_special_chars_in_body() {
     assert!(!xml.contains("Tom & Jerry"));
     assert!(!xml.contains("<Hello>"));
 }
+
+#[test]
+fn test_from_xml_round_trip() {
+    let message = Message::with_nouns(
+        MessageAttributes::new()
+            .to("+15551234567")
+            .action("https://example.com/status?a=1&b=2"),
+    )
+    .body(Body::new("Check out <these> photos"))
+    .add_media(Media::new("https://example.com/1.jpg"))
+    .add_media(Media::new("https://example.com/2.jpg"));
+    let response = MessagingResponse::new()
+        .comment("mms")
+        .message_with_nouns(message)
+        .redirect_with_attributes(RedirectAttributes::new().method("POST"), "/next");
+    let xml = response.to_xml();
+
+    let parsed = MessagingResponse::from_xml(&xml).unwrap();
+    assert_eq!(parsed.to_xml(), xml);
+}
+
+#[test]
+fn test_from_xml_attribute_only_message() {
+    let xml = r#"<Response><Message to="+15551234567" from="+15557654321"/></Response>"#;
+    let response = MessagingResponse::from_xml(xml).unwrap();
+
+    let out = response.to_xml();
+    assert!(out.contains("<Message from=\"+15557654321\" to=\"+15551234567\" />"));
+}
+
+#[test]
+fn test_from_xml_bare_text_message() {
+    let xml = r#"<Response>
+  <Message>
+    Thanks &amp; goodbye
+  </Message>
+</Response>"#;
+    let response = MessagingResponse::from_xml(xml).unwrap();
+
+    assert!(response
+        .to_xml()
+        .contains("<Message><Body>Thanks &amp; goodbye</Body></Message>"));
+}
+
+#[test]
+fn test_from_xml_unknown_noun() {
+    let xml = r#"<Response><Message><Attachment>x</Attachment></Message></Response>"#;
+    let err = MessagingResponse::from_xml(xml).unwrap_err();
+    assert!(matches!(
+        err,
+        twiml_rust::Error::UnknownElement { ref element, .. } if element == "Attachment"
+    ));
+}
+
+#[test]
+fn test_write_xml_matches_to_xml() {
+    let response = MessagingResponse::new().message("Hello").message("World");
+
+    let mut buffer = String::new();
+    response.write_xml(&mut buffer).unwrap();
+    assert_eq!(buffer, response.to_xml());
+
+    let mut bytes = Vec::new();
+    response.write_xml_io(&mut bytes).unwrap();
+    assert_eq!(bytes, response.to_xml().into_bytes());
+}
+
+#[test]
+fn test_to_xml_with_pretty_layout() {
+    let response = MessagingResponse::new()
+        .message_with_nouns(
+            Message::with_nouns(MessageAttributes::new())
+                .body(Body::new("Hi"))
+                .add_media(Media::new("https://example.com/a.png")),
+        )
+        .redirect("https://example.com/next");
+
+    assert_eq!(
+        response.to_xml_with(&RenderOptions::pretty()),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Response>\n  <Message>\n    <Body>Hi</Body>\n    <Media>https://example.com/a.png</Media>\n  </Message>\n  <Redirect>https://example.com/next</Redirect>\n</Response>"
+    );
+    // The default layout is unchanged
+    assert_eq!(
+        response.comment("c").to_xml(),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Response>\n  <!-- c --><Message><Body>Hi</Body><Media>https://example.com/a.png</Media></Message><Redirect>https://example.com/next</Redirect></Response>"
+    );
+}
@@ -0,0 +1,164 @@
+#![cfg(feature = "serde")]
+
+use twiml_rust::fax::{FaxResponse, ReceiveAttributes, ReceiveMediaType, ReceivePageSize};
+use twiml_rust::messaging::{Body, Media, Message, MessageAttributes, MessagingResponse};
+use twiml_rust::voice::*;
+use twiml_rust::TwiML;
+
+#[test]
+fn test_voice_round_trip() {
+    let response = VoiceResponse::new()
+        .comment("menu")
+        .say_with(
+            Say::new("Hi")
+                .voice(Voice::Polly(PollyVoice::Joanna, PollyEngine::Neural))
+                .add_break(None, Some("1s".to_string())),
+        )
+        .gather(
+            Gather::new()
+                .input(vec![GatherInput::Dtmf, GatherInput::Speech])
+                .speech_model(SpeechModel::PhoneCall)
+                .add_say(Say::new("Press 1")),
+        )
+        .dial_with(Dial::new().add_number(DialNumber::new("+15551234567")))
+        .hangup();
+
+    let json = serde_json::to_string(&response).unwrap();
+    let parsed: VoiceResponse = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.to_xml(), response.to_xml());
+}
+
+#[test]
+fn test_voice_from_minimal_json() {
+    let json = r#"{
+        "verbs": [
+            {"Say": {"message": "Hello", "attributes": {"voice": "Polly.Joanna", "loop": 2}}},
+            {"Gather": {"attributes": {"input": ["dtmf"], "numDigits": 1}, "nested": [
+                {"Say": {"message": "Press 1", "ssmlElements": [{"SayAs": {"interpret-as": "digits", "text": "1"}}]}}
+            ]}},
+            {"Dial": {"attributes": {"record": "record-from-answer"}, "nested": [
+                {"Number": {"number": "+15551234567"}}
+            ]}},
+            {"Hangup": null}
+        ]
+    }"#;
+    let response: VoiceResponse = serde_json::from_str(json).unwrap();
+    let xml = response.to_xml();
+
+    assert!(xml.contains("<Say voice=\"Polly.Joanna\" loop=\"2\">Hello</Say>"));
+    assert!(xml.contains("<Gather input=\"dtmf\" numDigits=\"1\">"));
+    assert!(xml.contains("<say-as interpret-as=\"digits\">1</say-as>"));
+    assert!(xml.contains("<Dial record=\"record-from-answer\">"));
+    assert!(xml.contains("<Number>+15551234567</Number>"));
+    assert!(xml.contains("<Hangup />"));
+}
+
+#[test]
+fn test_json_uses_twiml_attribute_names() {
+    let say = Say::new("Hi")
+        .loop_count(2)
+        .add_lang("fr-FR", "Bonjour")
+        .add_say_as(SsmlSayAsInterpretAs::SpellOut, "abc", None);
+    let json = serde_json::to_value(&say).unwrap();
+    assert_eq!(json["attributes"], serde_json::json!({"loop": 2}));
+    assert_eq!(json["ssmlElements"][0]["Lang"]["xml:lang"], "fr-FR");
+    assert_eq!(
+        json["ssmlElements"][1],
+        serde_json::json!({"SayAs": {"interpret-as": "spell-out", "text": "abc"}})
+    );
+
+    let prompt = Prompt::with_for(PromptFor::ExpirationDate);
+    assert_eq!(
+        serde_json::to_value(&prompt).unwrap(),
+        serde_json::json!({"attributes": {"for": "expiration-date"}})
+    );
+    assert_eq!(
+        serde_json::to_value(Language::new("en-US")).unwrap(),
+        serde_json::json!({"code": "en-US"})
+    );
+}
+
+#[test]
+fn test_empty_lists_are_left_out() {
+    let response = VoiceResponse::new().hangup();
+    assert_eq!(
+        serde_json::to_value(&response).unwrap(),
+        serde_json::json!({"verbs": [{"Hangup": null}]})
+    );
+
+    let json = serde_json::to_value(VoiceResponse::new().say("Hi")).unwrap();
+    assert!(
+        json["verbs"][0]["Say"].get("ssmlElements").is_none(),
+        "{}",
+        json
+    );
+
+    let json = serde_json::to_value(MessagingResponse::new().message("Hi")).unwrap();
+    assert!(json.get("comments").is_none(), "{}", json);
+}
+
+#[test]
+fn test_unknown_attribute_values_deserialize_as_custom() {
+    let json = r#"{"message": "Hi", "attributes": {"voice": "Acme.Bot"}}"#;
+    let say: Say = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        say.attributes.voice,
+        Some(Voice::Custom("Acme.Bot".to_string()))
+    );
+    assert_eq!(
+        serde_json::to_value(DialRecord::RecordFromAnswer).unwrap(),
+        "record-from-answer"
+    );
+}
+
+#[test]
+fn test_fragment_serializes_as_its_verbs() {
+    let fragment = VoiceResponse::new().say("Menu").hangup().to_fragment();
+    let greeting = VoiceVerb::Say(Say::new("Hi")).to_fragment();
+    let response = VoiceResponse::new().fragment(greeting).fragment(fragment);
+
+    let json = serde_json::to_value(&response).unwrap();
+    let inline = VoiceResponse::new().say("Hi").say("Menu").hangup();
+    assert_eq!(json, serde_json::to_value(&inline).unwrap());
+
+    let parsed: VoiceResponse = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed.to_xml(), response.to_xml());
+}
+
+#[test]
+fn test_messaging_round_trip() {
+    let response = MessagingResponse::new()
+        .message_with_nouns(
+            Message::with_nouns(MessageAttributes::new().to("+15551234567"))
+                .body(Body::new("Hi"))
+                .add_media(Media::new("https://example.com/a.png")),
+        )
+        .redirect("https://example.com/next");
+
+    let json = serde_json::to_string(&response).unwrap();
+    let parsed: MessagingResponse = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.to_xml(), response.to_xml());
+
+    let minimal: MessagingResponse =
+        serde_json::from_str(r#"{"verbs": [{"Message": {"body": {"message": "Hi"}}}]}"#).unwrap();
+    assert!(minimal.to_xml().contains("<Body>Hi</Body>"));
+}
+
+#[test]
+fn test_fax_round_trip() {
+    let response = FaxResponse::new().receive(Some(
+        ReceiveAttributes::new()
+            .media_type(ReceiveMediaType::ImageTiff)
+            .page_size(ReceivePageSize::A4),
+    ));
+
+    let json = serde_json::to_value(&response).unwrap();
+    assert_eq!(json["receive"]["attributes"]["mediaType"], "image/tiff");
+    assert_eq!(json["receive"]["attributes"]["pageSize"], "a4");
+
+    let parsed: FaxResponse = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed.to_xml(), response.to_xml());
+
+    let bad = r#"{"receive": {"attributes": {"pageSize": "tabloid"}}}"#;
+    assert!(serde_json::from_str::<FaxResponse>(bad).is_err());
+}
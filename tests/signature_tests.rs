@@ -0,0 +1,87 @@
+use twiml_rust::signature::RequestValidator;
+use twiml_rust::webhook::FormParams;
+
+const TOKEN: &str = "12345";
+const URL: &str = "https://mycompany.com/myapp.php?foo=1&bar=2";
+const BODY: &str = "CallSid=CA1234567890ABCDE&Caller=%2B14158675309&Digits=1234\
+&From=%2B14158675309&To=%2B18005551212";
+const SIGNATURE: &str = "RSOYDt4T1cUTdK1PDd93/VVr8B8=";
+
+#[test]
+fn test_compute_signature() {
+    let validator = RequestValidator::new(TOKEN);
+    let params = FormParams::parse(BODY).unwrap();
+    assert_eq!(validator.compute_signature(URL, &params), SIGNATURE);
+}
+
+#[test]
+fn test_signature_does_not_depend_on_parameter_order() {
+    let validator = RequestValidator::new(TOKEN);
+    let reversed = BODY.split('&').rev().collect::<Vec<_>>().join("&");
+    assert!(validator.validate_form(URL, &reversed, SIGNATURE));
+}
+
+#[test]
+fn test_tampered_requests_are_rejected() {
+    let validator = RequestValidator::new(TOKEN);
+    assert!(validator.validate_form(URL, BODY, SIGNATURE));
+
+    let tampered = BODY.replace("Digits=1234", "Digits=1235");
+    assert!(!validator.validate_form(URL, &tampered, SIGNATURE));
+    assert!(!validator.validate_form(&URL.replace("foo=1", "foo=2"), BODY, SIGNATURE));
+    assert!(!validator.validate_form(URL, BODY, "RSOYDt4T1cUTdK1PDd93/VVr8B8"));
+    assert!(!validator.validate_form(URL, BODY, ""));
+    assert!(!RequestValidator::new("54321").validate_form(URL, BODY, SIGNATURE));
+    assert!(!validator.validate_form(URL, "Digits=%zz", SIGNATURE));
+}
+
+#[test]
+fn test_port_variants() {
+    let validator = RequestValidator::new(TOKEN);
+    let with_port = "https://mycompany.com:443/myapp.php?foo=1&bar=2";
+
+    // Signed with the default port, requested without it
+    assert!(validator.validate_form(URL, BODY, "kvajT1Ptam85bY51eRf/AJRuM3w="));
+    // Signed without the port, requested with it
+    assert!(validator.validate_form(with_port, BODY, SIGNATURE));
+}
+
+#[test]
+fn test_duplicate_and_unicode_parameters() {
+    // Sorted by name, then by value
+    let validator = RequestValidator::new(TOKEN);
+    let params: FormParams = [
+        ("Body", "Café ✓"),
+        ("MediaUrl0", "https://x/y"),
+        ("A", "2"),
+        ("A", "1"),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        validator.compute_signature(URL, &params),
+        "wDNFpebGKcOqjtB3/rWc8Tej/Qg="
+    );
+}
+
+#[test]
+fn test_body_sha256() {
+    let validator = RequestValidator::new(TOKEN);
+    let body = br#"{"property": "value", "boolean": true}"#;
+    let url = format!(
+        "{}&bodySHA256={}",
+        URL, "0a1ff7634d9ab3b95db5c9a2dfe9416e41502b283a80c7cf19632632f96e6620"
+    );
+    let signature = "a9nBmqA0ju/hNViExpshrM61xv4=";
+
+    assert!(validator.validate_body(&url, body, signature));
+    assert!(!validator.validate_body(&url, br#"{"property": "other"}"#, signature));
+    assert!(!validator.validate_body(URL, body, signature));
+    assert!(!validator.validate_body(&url, body, SIGNATURE));
+}
+
+#[test]
+fn test_debug_hides_auth_token() {
+    let validator = RequestValidator::new("secret-token");
+    assert!(!format!("{:?}", validator).contains("secret-token"));
+}
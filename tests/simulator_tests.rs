@@ -0,0 +1,334 @@
+use std::time::Duration;
+
+use twiml_rust::simulator::{Caller, Event, Outcome, SimulatedRequest, Simulator};
+use twiml_rust::voice::{
+    Dial, DialAttributes, Gather, GatherInput, Pay, Play, Record, Reject, Say,
+};
+use twiml_rust::webhook::{CallStatus, GatherResult, VoiceRequest};
+use twiml_rust::VoiceResponse;
+
+const URL: &str = "https://example.com/ivr/voice";
+
+/// A small IVR: a menu, billing by keypad or speech, and sales by dialing
+fn ivr(request: &SimulatedRequest) -> Option<VoiceResponse> {
+    match request.path() {
+        "/ivr/voice" => Some(
+            VoiceResponse::new()
+                .say("Welcome")
+                .gather_with(
+                    Gather::new()
+                        .input(vec![GatherInput::Dtmf, GatherInput::Speech])
+                        .num_digits(1)
+                        .action("menu")
+                        .add_say(Say::new("Press 1 for sales or 2 for billing")),
+                )
+                .say("Goodbye"),
+        ),
+        "/ivr/menu" => {
+            let result = GatherResult::from_params(request.params.clone()).ok()?;
+            let choice = result
+                .digits
+                .or(result.speech_result)
+                .unwrap_or_default()
+                .to_lowercase();
+            match choice.as_str() {
+                "1" | "sales" => {
+                    let dial = Dial::with_attributes(DialAttributes {
+                        action: Some("/ivr/dialed".to_string()),
+                        ..Default::default()
+                    });
+                    Some(VoiceResponse::new().dial_with(dial.number("+15550001111")))
+                }
+                "2" | "billing" => Some(VoiceResponse::new().say("Billing").hangup()),
+                _ => Some(VoiceResponse::new().redirect("/ivr/voice")),
+            }
+        }
+        "/ivr/dialed" => Some(VoiceResponse::new().say("Thanks for calling")),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_press_follows_the_gather_action() {
+    let transcript = Simulator::new(URL)
+        .caller(Caller::new().press("2"))
+        .run(ivr);
+
+    assert_eq!(
+        transcript.heard(),
+        ["Welcome", "Press 1 for sales or 2 for billing", "Billing"]
+    );
+    assert_eq!(transcript.urls(), [URL, "https://example.com/ivr/menu"]);
+    assert!(transcript.events.contains(&Event::Pressed("2".to_string())));
+    // The <Say> after the <Gather> is never reached
+    assert!(transcript.events.contains(&Event::Unreachable(1)));
+    assert_eq!(transcript.outcome, Outcome::HungUp);
+}
+
+#[test]
+fn test_requests_carry_webhook_parameters() {
+    let transcript = Simulator::new(URL)
+        .from_number("+15551230001")
+        .to_number("+15559870002")
+        .caller(Caller::new().press("2"))
+        .run(ivr);
+
+    let requests: Vec<_> = transcript.requests().collect();
+    let first = VoiceRequest::from_params(requests[0].params.clone()).unwrap();
+    assert_eq!(first.from, "+15551230001");
+    assert_eq!(first.to, "+15559870002");
+    assert_eq!(first.call_status, CallStatus::Ringing);
+    assert_eq!(requests[0].method, "POST");
+
+    let gathered = GatherResult::from_params(requests[1].params.clone()).unwrap();
+    assert_eq!(gathered.digits.as_deref(), Some("2"));
+    assert_eq!(gathered.call.call_status, CallStatus::InProgress);
+    assert_eq!(gathered.call.call_sid, first.call_sid);
+}
+
+#[test]
+fn test_speech_and_dial() {
+    let transcript = Simulator::new(URL)
+        .caller(Caller::new().say("Sales"))
+        .run(ivr);
+
+    assert!(transcript.events.contains(&Event::Dialed {
+        targets: vec!["+15550001111".to_string()],
+        status: CallStatus::Completed,
+    }));
+    assert_eq!(
+        transcript.urls(),
+        [
+            URL,
+            "https://example.com/ivr/menu",
+            "https://example.com/ivr/dialed"
+        ]
+    );
+    let dialed = transcript.requests().last().unwrap();
+    assert_eq!(dialed.params.get("DialCallStatus"), Some("completed"));
+    assert_eq!(transcript.heard().last(), Some(&"Thanks for calling"));
+    assert_eq!(transcript.outcome, Outcome::Completed);
+}
+
+#[test]
+fn test_silence_falls_through_to_the_next_verb() {
+    let transcript = Simulator::new(URL).caller(Caller::new().silence()).run(ivr);
+
+    assert_eq!(transcript.urls(), [URL]);
+    assert_eq!(transcript.heard().last(), Some(&"Goodbye"));
+    assert_eq!(transcript.outcome, Outcome::Completed);
+}
+
+#[test]
+fn test_caller_hangs_up_after_a_while() {
+    // Every menu times out after 5 seconds and redirects back
+    let menu = |_: &SimulatedRequest| {
+        Some(
+            VoiceResponse::new()
+                .gather_with(Gather::new().add_say(Say::new("Please choose")))
+                .redirect("/ivr/voice"),
+        )
+    };
+    let transcript = Simulator::new(URL)
+        .caller(Caller::new().hang_up_after(Duration::from_secs(12)))
+        .run(menu);
+
+    assert_eq!(transcript.outcome, Outcome::CallerHungUp);
+    assert_eq!(transcript.duration, Duration::from_secs(12));
+    assert_eq!(transcript.heard().len(), 3);
+
+    // Without a script the caller hangs up at the first prompt
+    let transcript = Simulator::new(URL).run(menu);
+    assert_eq!(transcript.outcome, Outcome::CallerHungUp);
+    assert_eq!(transcript.heard(), ["Please choose"]);
+}
+
+#[test]
+fn test_loop_zero_repeats_until_the_caller_acts() {
+    let hold = VoiceResponse::new()
+        .play_with(Play::with_url("https://example.com/hold.mp3").loop_count(0))
+        .say("Never heard");
+    let transcript = Simulator::new(URL)
+        .caller(Caller::new().hang_up_after(Duration::from_secs(12)))
+        .run_response(&hold, |_| None);
+    let played = transcript
+        .events
+        .iter()
+        .filter(|event| matches!(event, Event::Played(_)));
+    assert_eq!(played.count(), 3);
+    assert_eq!(transcript.duration, Duration::from_secs(12));
+    assert_eq!(transcript.outcome, Outcome::CallerHungUp);
+
+    // Inside <Gather> the prompt repeats until the caller answers
+    let menu = VoiceResponse::new()
+        .gather_with(
+            Gather::new()
+                .action("/menu")
+                .add_say(Say::new("Press a key").loop_count(0)),
+        )
+        .say("Never heard");
+    let transcript = Simulator::new(URL)
+        .caller(Caller::new().press("1"))
+        .run_response(&menu, |_| Some(VoiceResponse::new().hangup()));
+    assert_eq!(transcript.heard(), ["Press a key"]);
+    assert_eq!(transcript.outcome, Outcome::HungUp);
+
+    // A caller who would listen forever gives up eventually
+    let transcript = Simulator::new(URL)
+        .caller(Caller::new().silence())
+        .run_response(&menu, |_| None);
+    assert_eq!(transcript.heard().len(), 1000);
+    assert_eq!(transcript.outcome, Outcome::CallerHungUp);
+}
+
+#[test]
+fn test_redirect_loops_hit_the_request_limit() {
+    let transcript = Simulator::new(URL)
+        .max_requests(5)
+        .run(|_| Some(VoiceResponse::new().redirect("/ivr/voice")));
+
+    assert_eq!(transcript.outcome, Outcome::RequestLimit);
+    assert_eq!(transcript.urls().len(), 5);
+}
+
+#[test]
+fn test_unknown_urls_are_application_errors() {
+    let transcript = Simulator::new(URL)
+        .caller(Caller::new().press("9"))
+        .run(|request| match request.path() {
+            "/ivr/voice" => {
+                Some(VoiceResponse::new().gather_with(Gather::new().action("/missing")))
+            }
+            _ => None,
+        });
+
+    assert_eq!(
+        transcript.outcome,
+        Outcome::ApplicationError("https://example.com/missing".to_string())
+    );
+}
+
+#[test]
+fn test_relative_urls_resolve_against_the_document_url() {
+    let redirects = |targets: &'static [&'static str]| {
+        let mut remaining = targets.iter();
+        move |_: &SimulatedRequest| match remaining.next() {
+            Some(target) => Some(VoiceResponse::new().redirect(*target)),
+            None => Some(VoiceResponse::new().hangup()),
+        }
+    };
+
+    let transcript = Simulator::new(URL).run(redirects(&[
+        "?step=2",
+        "../menu/./main",
+        "options?x=1",
+        "//other.example.com/start",
+        "/ivr/voice#top",
+    ]));
+    assert_eq!(
+        transcript.urls(),
+        [
+            URL,
+            "https://example.com/ivr/voice?step=2",
+            "https://example.com/menu/main",
+            "https://example.com/menu/options?x=1",
+            "https://other.example.com/start",
+            "https://other.example.com/ivr/voice",
+        ]
+    );
+    assert_eq!(transcript.outcome, Outcome::HungUp);
+
+    // A document URL without a host resolves to paths
+    let transcript = Simulator::new("/voice").run(redirects(&["/menu", "options", "../.."]));
+    assert_eq!(transcript.urls(), ["/voice", "/menu", "/options", "/"]);
+    assert_eq!(transcript.outcome, Outcome::HungUp);
+}
+
+#[test]
+fn test_run_response_and_unreachable_verbs() {
+    let response = VoiceResponse::new()
+        .say("One")
+        .reject(Reject::new())
+        .say("Two")
+        .say("Three");
+    let transcript = Simulator::new(URL).run_response(&response, |_| None);
+
+    assert_eq!(transcript.heard(), ["One"]);
+    assert_eq!(transcript.urls(), Vec::<&str>::new());
+    assert_eq!(transcript.events.last(), Some(&Event::Unreachable(2)));
+    assert_eq!(transcript.outcome, Outcome::Rejected);
+}
+
+#[test]
+fn test_gather_without_action_posts_to_the_document_url() {
+    let mut calls = 0;
+    let transcript = Simulator::new("https://example.com/menu?step=1")
+        .caller(Caller::new().press("42#"))
+        .run(|request| {
+            calls += 1;
+            match request.params.get("Digits") {
+                Some(_) => Some(VoiceResponse::new().say("Got it")),
+                None => Some(VoiceResponse::new().gather_with(Gather::new())),
+            }
+        });
+
+    assert_eq!(calls, 2);
+    let request = transcript.requests().last().unwrap();
+    assert_eq!(request.url, "https://example.com/menu?step=1");
+    assert_eq!(request.params.get("Digits"), Some("42"));
+    assert_eq!(request.params.get("FinishedOnKey"), Some("#"));
+    assert_eq!(transcript.heard(), ["Got it"]);
+}
+
+#[test]
+fn test_record_and_pay() {
+    let response = VoiceResponse::new()
+        .record_with(Record::new().action("/recorded").timeout(2))
+        .pay_with(Pay::new().action("/paid"));
+    let transcript = Simulator::new(URL)
+        .caller(
+            Caller::new()
+                .say("leave a message here")
+                .press("4242424242424242"),
+        )
+        .run_response(&response, |request| match request.path() {
+            "/recorded" => Some(VoiceResponse::new().pay_with(Pay::new().action("/paid"))),
+            _ => Some(VoiceResponse::new().hangup()),
+        });
+
+    assert!(transcript
+        .events
+        .contains(&Event::Recorded(Duration::from_millis(1600))));
+    let recorded = transcript.requests().next().unwrap();
+    assert_eq!(recorded.url, "https://example.com/recorded");
+    assert_eq!(recorded.params.get("RecordingDuration"), Some("1"));
+    assert!(recorded.params.get("RecordingUrl").is_some());
+
+    let paid = transcript.requests().last().unwrap();
+    assert_eq!(paid.params.get("Result"), Some("success"));
+    assert_eq!(
+        paid.params.get("PaymentCardNumber"),
+        Some("xxxxxxxxxxxx4242")
+    );
+    assert_eq!(transcript.outcome, Outcome::HungUp);
+}
+
+#[test]
+fn test_fragments_are_executed_inline() {
+    let greeting = VoiceResponse::new()
+        .say("Hello")
+        .pause(Some(2))
+        .to_fragment();
+    let response = VoiceResponse::new().fragment(greeting).dial("+15550001111");
+    let transcript = Simulator::new(URL)
+        .dial_status(CallStatus::NoAnswer)
+        .run_response(&response, |_| None);
+
+    assert_eq!(transcript.heard(), ["Hello"]);
+    assert!(transcript
+        .events
+        .contains(&Event::Paused(Duration::from_secs(2))));
+    // 0.4s of speech, the pause and the dial timeout
+    assert_eq!(transcript.duration, Duration::from_millis(32_400));
+    assert_eq!(transcript.outcome, Outcome::Completed);
+}
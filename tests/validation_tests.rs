@@ -1,8 +1,13 @@
+use twiml_rust::render::RenderOptions;
+use twiml_rust::schema::{self, AttributeDomain};
 use twiml_rust::validation::{
-    validate_twiml, validate_twiml_strict, TwiMLValidator, ValidationErrorType,
+    validate_twiml, validate_twiml_strict, TwiMLValidator, ValidateModel, ValidationErrorType,
 };
-use twiml_rust::voice::{Dial, DialNumber, Gather, Say, VoiceResponse};
-use twiml_rust::TwiML;
+use twiml_rust::voice::{
+    Dial, DialAttributes, DialNumber, DialRecord, Gather, GatherInput, Pay, PayPaymentMethod, Play,
+    PlayAttributes, PollyEngine, PollyVoice, Record, Say, SpeechModel, Trim, Voice, VoiceResponse,
+};
+use twiml_rust::{FaxResponse, MessagingResponse, ReceiveAttributes, TwiML};
 
 #[test]
 fn test_valid_xml() {
@@ -25,6 +30,38 @@ fn test_missing_xml_declaration() {
     assert!(validator.validate_xml(xml).is_err());
 }
 
+#[test]
+fn test_xml_declaration_can_be_optional() {
+    let xml = r#"<Response>
+  <Say>Hello World</Say>
+</Response>"#;
+
+    let validator = TwiMLValidator::new().set_require_declaration(false);
+    assert!(validator.validate_xml(xml).is_ok());
+    assert!(validator.validate(xml).unwrap().is_empty());
+}
+
+#[test]
+fn test_compact_output_passes_validation() {
+    let voice = VoiceResponse::new()
+        .say("Hello")
+        .gather_with(Gather::new().num_digits(1).add_say(Say::new("Press 1")));
+    let messaging = MessagingResponse::new().message("Hi");
+    let fax = FaxResponse::new().receive(Some(ReceiveAttributes::new().action("/fax")));
+    let options = RenderOptions::compact();
+    let validator = TwiMLValidator::new().set_require_declaration(options.xml_declaration);
+
+    for xml in [
+        voice.to_xml_with(&options),
+        messaging.to_xml_with(&options),
+        fax.to_xml_with(&options),
+    ] {
+        assert!(!xml.starts_with("<?xml"), "{}", xml);
+        assert_eq!(validator.validate(&xml).unwrap(), [], "{}", xml);
+        assert!(validate_twiml(&xml).is_ok_and(|errors| !errors.is_empty()));
+    }
+}
+
 #[test]
 fn test_missing_response_element() {
     let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
@@ -93,7 +130,7 @@ fn test_strict_url_validation() {
 fn test_real_world_voice_response() {
     // Create a realistic voice response
     let gather = Gather::new()
-        .input(vec!["dtmf".to_string()])
+        .input(vec![GatherInput::Dtmf])
         .action("https://example.com/process")
         .timeout(10)
         .add_say(Say::new("Press 1 for sales, 2 for support"));
@@ -176,3 +213,598 @@ fn test_message_body_length() {
         .message
         .contains("Message body exceeds 1600 characters"));
 }
+
+#[test]
+fn test_gt_inside_attribute_value_is_valid() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <Gather action="/menu?next=a>b" finishOnKey="*">
+    <Say>Press 1</Say>
+  </Gather>
+  <Hangup/>
+</Response>"#;
+
+    let validator = TwiMLValidator::new();
+    assert!(validator.validate_xml(xml).is_ok());
+    assert!(validator.validate(xml).unwrap().is_empty());
+}
+
+#[test]
+fn test_comments_cdata_and_processing_instructions() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- <Say>not a tag</Say> -->
+<?app-hint keep?>
+<Response>
+  <Say><![CDATA[1 < 2 & 3 > 2]]></Say>
+  <Say>Tom &amp; Jerry &#x263A;</Say>
+</Response>"#;
+
+    assert!(TwiMLValidator::new().validate_xml(xml).is_ok());
+}
+
+#[test]
+fn test_malformed_documents_are_rejected() {
+    let validator = TwiMLValidator::new();
+    let cases = [
+        // Unknown entity
+        r#"<?xml version="1.0"?><Response><Say>&nbsp;</Say></Response>"#,
+        // Unquoted attribute
+        r#"<?xml version="1.0"?><Response><Pause length=2/></Response>"#,
+        // '<' in an attribute value
+        r#"<?xml version="1.0"?><Response><Play loop="<1">a.mp3</Play></Response>"#,
+        // Duplicate attribute
+        r#"<?xml version="1.0"?><Response><Pause length="1" length="2"/></Response>"#,
+        // Unterminated comment
+        r#"<?xml version="1.0"?><Response><!-- oops</Response>"#,
+        // Second root element
+        r#"<?xml version="1.0"?><Response></Response><Response></Response>"#,
+        // Declaration not at the start
+        r#"<Response></Response><?xml version="1.0"?>"#,
+        // Control and noncharacters XML 1.0 does not allow
+        "<?xml version=\"1.0\"?><Response><Say>a\u{1}b</Say></Response>",
+        "<?xml version=\"1.0\"?><Response><Play digits=\"\u{ffff}\">a.mp3</Play></Response>",
+    ];
+
+    for xml in cases {
+        assert!(validator.validate_xml(xml).is_err(), "accepted: {}", xml);
+        let errors = validator.validate(xml).unwrap();
+        assert_eq!(errors.len(), 1, "for: {}", xml);
+        assert_eq!(errors[0].error_type, ValidationErrorType::MalformedXml);
+    }
+}
+
+#[test]
+fn test_errors_report_line_and_column() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <Say>Hello
+</Response>"#;
+
+    let errors = validate_twiml(xml).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].line, Some(4));
+    assert_eq!(errors[0].column, Some(1));
+    assert!(errors[0].to_string().contains("(line 4, column 1)"));
+
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <Say>Tom &amp Jerry</Say>
+</Response>"#;
+
+    let errors = validate_twiml(xml).unwrap();
+    assert_eq!(errors[0].line, Some(3));
+    assert_eq!(errors[0].column, Some(12));
+}
+
+#[test]
+fn test_content_errors_report_line_and_column() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <Dial>
+    <Number>5551234567</Number>
+  </Dial>
+  <Redirect method="POST" url="not-a-url"/>
+</Response>"#;
+
+    let errors = validate_twiml_strict(xml).unwrap();
+    assert_eq!(errors.len(), 2);
+
+    assert_eq!(
+        errors[0].error_type,
+        ValidationErrorType::InvalidPhoneNumber
+    );
+    assert_eq!((errors[0].line, errors[0].column), (Some(4), Some(5)));
+
+    assert_eq!(errors[1].error_type, ValidationErrorType::InvalidUrl);
+    assert_eq!(errors[1].context.as_deref(), Some("url"));
+    assert_eq!((errors[1].line, errors[1].column), (Some(6), Some(27)));
+}
+
+#[test]
+fn test_dial_inside_gather_is_invalid_nesting() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <Gather>
+    <Dial>+15551234567</Dial>
+  </Gather>
+</Response>"#;
+
+    let errors = validate_twiml(xml).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].error_type, ValidationErrorType::InvalidNesting);
+    assert_eq!(errors[0].context.as_deref(), Some("Response/Gather/Dial"));
+    assert_eq!((errors[0].line, errors[0].column), (Some(4), Some(5)));
+    assert!(errors[0]
+        .message
+        .contains("<Dial> is not allowed inside <Gather>"));
+}
+
+#[test]
+fn test_number_outside_dial_is_invalid_nesting() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <Number>+15551234567</Number>
+</Response>"#;
+
+    let errors = validate_twiml(xml).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].error_type, ValidationErrorType::InvalidNesting);
+    assert_eq!(errors[0].context.as_deref(), Some("Response/Number"));
+    assert!(errors[0].message.contains("allowed inside: Dial"));
+}
+
+#[test]
+fn test_unknown_element_is_invalid_nesting() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <Say>Hi<Bogus/></Say>
+  <Hangup><Say>Bye</Say></Hangup>
+</Response>"#;
+
+    let errors = validate_twiml(xml).unwrap();
+    let contexts: Vec<_> = errors
+        .iter()
+        .filter(|e| e.error_type == ValidationErrorType::InvalidNesting)
+        .map(|e| e.context.as_deref().unwrap())
+        .collect();
+    assert_eq!(contexts, ["Response/Say/Bogus", "Response/Hangup/Say"]);
+}
+
+#[test]
+fn test_valid_nesting_is_accepted() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <Gather input="dtmf">
+    <Say>Press <emphasis>one</emphasis><break time="1s"/></Say>
+    <Play>https://example.com/menu.mp3</Play>
+  </Gather>
+  <Dial><Number>+15551234567</Number><Client><Identity>alice</Identity></Client></Dial>
+  <Connect><Stream url="wss://example.com"><Parameter name="a" value="b"/></Stream></Connect>
+  <Start><Transcription/></Start>
+  <Pay><Prompt for="payment-card-number"><Say>Card number</Say></Prompt></Pay>
+  <Enqueue><Task>{"skill": "sales"}</Task></Enqueue>
+</Response>"#;
+
+    assert!(validate_twiml(xml).unwrap().is_empty());
+
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response><Message><Body>Hi</Body><Media>https://example.com/a.png</Media></Message></Response>"#;
+    assert!(validate_twiml(xml).unwrap().is_empty());
+}
+
+#[test]
+fn test_verbs_of_another_response_kind_are_invalid_nesting() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <Dial>+15551234567</Dial>
+  <Message>Hi</Message>
+</Response>"#;
+
+    let errors = validate_twiml(xml).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].error_type, ValidationErrorType::InvalidNesting);
+    assert_eq!(errors[0].context.as_deref(), Some("Response/Message"));
+    assert_eq!((errors[0].line, errors[0].column), (Some(4), Some(3)));
+    assert!(errors[0]
+        .message
+        .contains("<Message> is not allowed in a voice response (started by <Dial>)"));
+
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response><Receive/><Say>Hi</Say></Response>"#;
+    let errors = validate_twiml(xml).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].context.as_deref(), Some("Response/Say"));
+    assert!(errors[0].message.contains("in a fax response"));
+
+    // <Redirect> belongs to voice and messaging, so the next verb decides
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response><Redirect>/next</Redirect><Message>Hi</Message><Redirect>/next</Redirect></Response>"#;
+    assert!(validate_twiml(xml).unwrap().is_empty());
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response><Redirect>/next</Redirect><Message>Hi</Message><Hangup/></Response>"#;
+    let errors = validate_twiml(xml).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].message.contains("in a messaging response"));
+}
+
+#[test]
+fn test_invalid_enum_values() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <Record trim="trim" />
+  <Dial ringTone="xx"><Conference region="mars1">Room</Conference></Dial>
+  <Say><say-as interpret-as="digit">123</say-as></Say>
+  <Redirect method="PUT">/next</Redirect>
+</Response>"#;
+
+    let errors = validate_twiml(xml).unwrap();
+    let contexts: Vec<_> = errors
+        .iter()
+        .map(|e| {
+            assert_eq!(e.error_type, ValidationErrorType::InvalidEnumValue);
+            e.context.as_deref().unwrap()
+        })
+        .collect();
+    assert_eq!(
+        contexts,
+        ["trim", "ringTone", "region", "interpret-as", "method"]
+    );
+    assert!(errors[0]
+        .message
+        .contains("expected one of: trim-silence, do-not-trim"));
+}
+
+#[test]
+fn test_pay_payment_method_is_not_an_http_method() {
+    for method in [PayPaymentMethod::AchDebit, PayPaymentMethod::CreditCard] {
+        let xml = format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response><Pay paymentMethod="{}" method="POST" /></Response>"#,
+            method.as_str()
+        );
+        assert!(validate_twiml(&xml).unwrap().is_empty(), "{}", xml);
+
+        let response = VoiceResponse::new().pay_with(Pay::new().payment_method(method));
+        assert!(response.validate_model().is_empty());
+        assert!(validate_twiml(&response.to_xml()).unwrap().is_empty());
+    }
+
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response><Pay paymentMethod="POST" /></Response>"#;
+    let errors = validate_twiml(xml).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0]
+        .message
+        .contains("expected one of: ach-debit, credit-card"));
+}
+
+#[test]
+fn test_custom_attribute_values_pass_model_validation() {
+    let response = VoiceResponse::new()
+        .dial_with(Dial::with_attributes(DialAttributes {
+            record: Some(DialRecord::Custom("record-from-bridge".to_string())),
+            ..Default::default()
+        }))
+        .gather_with(Gather::new().input(vec![
+            GatherInput::Speech,
+            GatherInput::Custom("gesture".to_string()),
+        ]));
+    assert!(response.validate_model().is_empty());
+    assert!(response.validate().unwrap().is_empty());
+
+    // The rendered XML has no notion of Custom, so it is still reported there
+    let errors = validate_twiml(&response.to_xml()).unwrap();
+    let contexts: Vec<_> = errors
+        .iter()
+        .map(|e| e.context.as_deref().unwrap())
+        .collect();
+    assert_eq!(contexts, ["record", "input"]);
+
+    // Known values are still checked
+    let response = VoiceResponse::new().record_with(Record::new().max_length(0));
+    assert_eq!(response.validate_model().len(), 1);
+}
+
+#[test]
+fn test_schema_domains_come_from_the_attribute_enums() {
+    assert_eq!(
+        schema::attribute_domain("Dial", "record"),
+        Some(AttributeDomain::Enum(DialRecord::VALUES))
+    );
+    assert_eq!(
+        schema::attribute_domain("Gather", "speechModel"),
+        Some(AttributeDomain::Enum(SpeechModel::VALUES))
+    );
+    assert_eq!(
+        schema::attribute_domain("Record", "trim"),
+        Some(AttributeDomain::Enum(Trim::VALUES))
+    );
+    for value in DialRecord::VALUES {
+        assert_eq!(value.parse::<DialRecord>().unwrap().as_str(), *value);
+    }
+}
+
+#[test]
+fn test_invalid_enum_list_item() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <Gather input="dtmf voice" />
+</Response>"#;
+
+    let errors = validate_twiml(xml).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].error_type, ValidationErrorType::InvalidEnumValue);
+    assert_eq!(errors[0].context.as_deref(), Some("input"));
+    assert!(errors[0].message.contains("'voice' is not one of"));
+}
+
+#[test]
+fn test_say_voice_language_mismatch() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <Say voice="Polly.Hans" language="en-US">Hello</Say>
+  <Say voice="Polly.Vicki-Neural" language="de-DE">Hallo</Say>
+  <Say voice="Polly.Hans-Generative">Hallo</Say>
+  <Say voice="Acme.Bot" language="en-US">Hello</Say>
+</Response>"#;
+
+    let errors = validate_twiml(xml).unwrap();
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().all(
+        |e| e.error_type == ValidationErrorType::UnsupportedCombination
+            && e.context.as_deref() == Some("voice")
+    ));
+    assert!(errors[0]
+        .message
+        .contains("'Polly.Hans' does not speak language 'en-US'"));
+    assert_eq!(errors[0].line, Some(3));
+    assert_eq!(errors[1].line, Some(5));
+
+    let response = VoiceResponse::new().say_with(
+        Say::new("Hola")
+            .voice(Voice::Polly(PollyVoice::Joanna, PollyEngine::Neural))
+            .language("es-ES"),
+    );
+    let errors = response.validate_model();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].context.as_deref(),
+        Some("verbs[0].Say.attributes.voice")
+    );
+}
+
+#[test]
+fn test_gather_speech_model_combinations() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <Gather input="speech" enhanced="true" speechModel="phone_call" />
+  <Gather input="speech" enhanced="true" speechModel="numbers_and_commands" />
+  <Gather input="speech" enhanced="true" />
+  <Gather input="speech" speechModel="deepgram_nova-3" language="fr-FR" />
+  <Gather input="speech" speechModel="deepgram_nova-3" language="en-GB" />
+</Response>"#;
+
+    let errors = validate_twiml(xml).unwrap();
+    let found: Vec<_> = errors
+        .iter()
+        .map(|e| {
+            assert_eq!(e.error_type, ValidationErrorType::UnsupportedCombination);
+            (e.context.as_deref().unwrap(), e.line.unwrap())
+        })
+        .collect();
+    assert_eq!(
+        found,
+        [("enhanced", 4), ("enhanced", 5), ("speechModel", 6)]
+    );
+    assert!(errors[2].message.contains("'fr-FR'"));
+
+    let response = VoiceResponse::new().gather_with(
+        Gather::new()
+            .input(vec![GatherInput::Speech])
+            .enhanced(true)
+            .speech_model(SpeechModel::DeepgramNova3)
+            .language("es-ES"),
+    );
+    let contexts: Vec<_> = response
+        .validate_model()
+        .into_iter()
+        .map(|e| e.context.unwrap())
+        .collect();
+    assert_eq!(
+        contexts,
+        [
+            "verbs[0].Gather.attributes.speech_model",
+            "verbs[0].Gather.attributes.enhanced"
+        ]
+    );
+}
+
+#[test]
+fn test_speech_model_language_tables() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <Gather input="speech" speechModel="deepgram_nova-2" language="nl-BE" />
+  <Gather input="speech" speechModel="deepgram_nova-2" language="ar-EG" />
+  <Gather input="speech" speechModel="phone_call" language="sv-SE" />
+  <Gather input="speech" speechModel="googlev2_telephony" language="is-IS" />
+  <Gather input="speech" speechModel="googlev2_long" language="is-IS" />
+  <Gather input="speech" speechModel="default" language="is-IS" />
+</Response>"#;
+
+    let errors = validate_twiml(xml).unwrap();
+    let lines: Vec<_> = errors.iter().map(|e| e.line.unwrap()).collect();
+    assert_eq!(lines, [4, 5, 6]);
+    assert!(errors[0]
+        .message
+        .contains("Speech model 'deepgram_nova-2' does not recognize language 'ar-EG'"));
+
+    for model in SpeechModel::VALUES {
+        let model: SpeechModel = model.parse().unwrap();
+        // Twilio falls back to en-US, which every model recognizes
+        assert_ne!(model.supports_language("en-US"), Some(false), "{}", model);
+        if model != SpeechModel::Default {
+            assert_eq!(model.supports_language("xx-XX"), Some(false), "{}", model);
+        }
+    }
+
+    let response = VoiceResponse::new().gather_with(
+        Gather::new()
+            .input(vec![GatherInput::Speech])
+            .speech_model(SpeechModel::NumbersAndCommands)
+            .language("el-GR"),
+    );
+    assert_eq!(response.validate_model().len(), 1);
+}
+
+#[test]
+fn test_numeric_ranges() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <Gather timeout="0" />
+  <Pause length="-1" />
+  <Record maxLength="14401" />
+  <Dial><Conference maxParticipants="251">Room</Conference></Dial>
+</Response>"#;
+
+    let errors = validate_twiml(xml).unwrap();
+    let found: Vec<_> = errors
+        .iter()
+        .map(|e| {
+            assert_eq!(e.error_type, ValidationErrorType::InvalidAttributeValue);
+            (e.context.as_deref().unwrap(), e.line.unwrap())
+        })
+        .collect();
+    assert_eq!(
+        found,
+        [
+            ("timeout", 3),
+            ("length", 4),
+            ("maxLength", 5),
+            ("maxParticipants", 6)
+        ]
+    );
+
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <Record maxLength="14400" />
+  <Dial><Conference maxParticipants="250">Room</Conference></Dial>
+</Response>"#;
+    assert!(validate_twiml(xml).unwrap().is_empty());
+}
+
+#[test]
+fn test_boolean_spelling() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <Record playBeep="True" />
+</Response>"#;
+
+    let errors = validate_twiml(xml).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].error_type,
+        ValidationErrorType::InvalidAttributeValue
+    );
+    assert_eq!(errors[0].context.as_deref(), Some("playBeep"));
+}
+
+#[test]
+fn test_model_validation_reports_field_paths() {
+    let response = VoiceResponse::new()
+        .say("Welcome")
+        .pause(Some(1))
+        .dial_with(Dial::new().add_number(DialNumber::new("+15551234567").url("not-a-url")))
+        .record_with(Record::new().max_length(20000));
+
+    let errors = response.validate_model_strict();
+    let found: Vec<_> = errors
+        .iter()
+        .map(|e| (e.error_type.clone(), e.context.as_deref().unwrap()))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (
+                ValidationErrorType::InvalidUrl,
+                "verbs[2].Dial.nested[0].Number.url"
+            ),
+            (
+                ValidationErrorType::InvalidAttributeValue,
+                "verbs[3].Record.attributes.max_length"
+            ),
+        ]
+    );
+    assert!(errors.iter().all(|e| e.line.is_none()));
+
+    // Non-strict mode skips the URL check
+    assert_eq!(response.validate_model().len(), 1);
+}
+
+#[test]
+fn test_model_validation_matches_xml_validation() {
+    let response = VoiceResponse::new()
+        .gather_with(Gather::new().timeout(0).add_say(Say::new("a".repeat(5000))))
+        .dial_with(Dial::new().add_number(DialNumber::new("5551234567")));
+
+    let from_model = response.validate_strict().unwrap();
+    let from_xml = validate_twiml_strict(&response.to_xml()).unwrap();
+    let types = |errors: &[twiml_rust::ValidationError]| {
+        errors
+            .iter()
+            .map(|e| e.error_type.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(types(&from_model), types(&from_xml));
+    assert_eq!(
+        from_model[1].context.as_deref(),
+        Some("verbs[0].Gather.nested[0].Say.message")
+    );
+    assert_eq!(
+        from_model[2].context.as_deref(),
+        Some("verbs[1].Dial.nested[0].Number.number")
+    );
+}
+
+#[test]
+fn test_model_validation_messaging_and_fax() {
+    let response = MessagingResponse::new().message("a".repeat(1601));
+    let errors = response.validate_model();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].error_type, ValidationErrorType::ContentTooLong);
+    assert_eq!(
+        errors[0].context.as_deref(),
+        Some("verbs[0].Message.body.message")
+    );
+
+    let response = FaxResponse::new().receive(Some(ReceiveAttributes {
+        method: Some("PUT".to_string()),
+        ..Default::default()
+    }));
+    let errors = TwiML::validate(&response).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].error_type, ValidationErrorType::InvalidEnumValue);
+    assert_eq!(
+        errors[0].context.as_deref(),
+        Some("receive.attributes.method")
+    );
+}
+
+#[test]
+fn test_play_needs_a_url_or_digits() {
+    let empty = Play {
+        attributes: PlayAttributes::default(),
+        url: None,
+    };
+    let response = VoiceResponse::new()
+        .play_with(empty)
+        .play_with(Play::with_url("https://example.com/hold.mp3"))
+        .play_with(Play::with_digits("ww1"));
+
+    let errors = response.validate_model();
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert_eq!(
+        errors[0].error_type,
+        ValidationErrorType::EmptyRequiredField
+    );
+    assert_eq!(errors[0].context.as_deref(), Some("verbs[0].Play.url"));
+}
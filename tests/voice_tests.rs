@@ -1,5 +1,5 @@
 use twiml_rust::voice::*;
-use twiml_rust::TwiML;
+use twiml_rust::{Newline, RenderOptions, SelfClosing, TwiML};
 
 #[test]
 fn test_simple_say() {
@@ -12,7 +12,7 @@ fn test_simple_say() {
 fn test_say_with_attributes() {
     let response = VoiceResponse::new().say_with(
         Say::new("Hello")
-            .voice("alice")
+            .voice(Voice::Alice)
             .language("en-US")
             .loop_count(2),
     );
@@ -26,8 +26,8 @@ fn test_say_with_attributes() {
 fn test_say_with_ssml() {
     let response = VoiceResponse::new().say_with(
         Say::new("Hello")
-            .add_break(Some("medium".to_string()), None)
-            .add_emphasis(Some("strong".to_string()), "World"),
+            .add_break(Some(SsmlBreakStrength::Medium), None)
+            .add_emphasis(Some(SsmlEmphasisLevel::Strong), "World"),
     );
     let xml = response.to_xml();
     assert!(xml.contains("<break strength=\"medium\""));
@@ -37,7 +37,7 @@ fn test_say_with_ssml() {
 #[test]
 fn test_ssml_break() {
     let response = VoiceResponse::new().say_with(
-        Say::new("Please wait").add_break(Some("strong".to_string()), Some("2s".to_string())),
+        Say::new("Please wait").add_break(Some(SsmlBreakStrength::Strong), Some("2s".to_string())),
     );
     let xml = response.to_xml();
     assert!(xml.contains("<break strength=\"strong\" time=\"2s\""));
@@ -46,7 +46,7 @@ fn test_ssml_break() {
 #[test]
 fn test_ssml_emphasis() {
     let response = VoiceResponse::new()
-        .say_with(Say::new("This is").add_emphasis(Some("moderate".to_string()), "important"));
+        .say_with(Say::new("This is").add_emphasis(Some(SsmlEmphasisLevel::Moderate), "important"));
     let xml = response.to_xml();
     assert!(xml.contains("<emphasis level=\"moderate\">important</emphasis>"));
 }
@@ -87,7 +87,7 @@ fn test_ssml_phoneme() {
     let response = VoiceResponse::new().say_with(Say::new("You say").add_phoneme(
         "pɪˈkɑːn",
         "pecan",
-        Some("ipa".to_string()),
+        Some(SsmlPhonemeAlphabet::Ipa),
     ));
     let xml = response.to_xml();
     assert!(xml.contains("<phoneme alphabet=\"ipa\" ph=\"pɪˈkɑːn\">pecan</phoneme>"));
@@ -96,7 +96,7 @@ fn test_ssml_phoneme() {
 #[test]
 fn test_ssml_say_as() {
     let response = VoiceResponse::new().say_with(Say::new("Your number is").add_say_as(
-        "telephone",
+        SsmlSayAsInterpretAs::Telephone,
         "555-1234",
         None,
     ));
@@ -107,9 +107,9 @@ fn test_ssml_say_as() {
 #[test]
 fn test_ssml_say_as_with_format() {
     let response = VoiceResponse::new().say_with(Say::new("The date is").add_say_as(
-        "date",
+        SsmlSayAsInterpretAs::Date,
         "20260101",
-        Some("yyyymmdd".to_string()),
+        Some(SsmlSayAsFormat::Yyyymmdd),
     ));
     let xml = response.to_xml();
     assert!(xml.contains("<say-as interpret-as=\"date\" format=\"yyyymmdd\">20260101</say-as>"));
@@ -155,8 +155,8 @@ fn test_ssml_amazon_domain() {
 fn test_ssml_complex_combination() {
     let response = VoiceResponse::new().say_with(
         Say::new("Welcome to our service")
-            .add_break(Some("medium".to_string()), None)
-            .add_emphasis(Some("strong".to_string()), "Please listen carefully")
+            .add_break(Some(SsmlBreakStrength::Medium), None)
+            .add_emphasis(Some(SsmlEmphasisLevel::Strong), "Please listen carefully")
             .add_break(None, Some("1s".to_string()))
             .add_prosody(
                 Some("high".to_string()),
@@ -165,7 +165,7 @@ fn test_ssml_complex_combination() {
                 "This is important",
             )
             .add_lang("es-ES", "Hola")
-            .add_say_as("telephone", "555-1234", None),
+            .add_say_as(SsmlSayAsInterpretAs::Telephone, "555-1234", None),
     );
     let xml = response.to_xml();
     assert!(xml.contains("<break strength=\"medium\""));
@@ -185,15 +185,30 @@ fn test_play() {
 
 #[test]
 fn test_play_with_loop() {
-    let response = VoiceResponse::new().play_with(
-        Play::new()
-            .url("https://example.com/audio.mp3")
-            .loop_count(3),
-    );
+    let response = VoiceResponse::new()
+        .play_with(Play::with_url("https://example.com/audio.mp3").loop_count(3));
     let xml = response.to_xml();
     assert!(xml.contains("loop=\"3\""));
 }
 
+#[test]
+fn test_required_value_constructors() {
+    let response = VoiceResponse::new()
+        .play_with(Play::with_digits("ww1234"))
+        .prompt_with(Prompt::with_for(PromptFor::ExpirationDate))
+        .connect(
+            Connect::new().add_stream(
+                Stream::with_url("wss://example.com/stream")
+                    .add_parameter(Parameter::with_value("caller", "Ada")),
+            ),
+        );
+    let xml = response.to_xml();
+    assert!(xml.contains("<Play digits=\"ww1234\"></Play>"));
+    assert!(xml.contains("<Prompt for=\"expiration-date\""));
+    assert!(xml.contains("<Stream url=\"wss://example.com/stream\">"));
+    assert!(xml.contains("<Parameter name=\"caller\" value=\"Ada\" />"));
+}
+
 #[test]
 fn test_pause() {
     let response = VoiceResponse::new().pause(Some(5));
@@ -213,13 +228,13 @@ fn test_dial_with_conference() {
     let conference = DialConference {
         name: "MyRoom".to_string(),
         muted: Some(false),
-        beep: Some("true".to_string()),
+        beep: Some(ConferenceBeep::True),
         start_conference_on_enter: Some(true),
         end_conference_on_exit: Some(false),
         wait_url: None,
         wait_method: None,
         max_participants: Some(10),
-        record: Some("record-from-start".to_string()),
+        record: Some(ConferenceRecord::RecordFromStart),
         region: None,
         coach: None,
         trim: None,
@@ -248,7 +263,7 @@ fn test_dial_with_conference() {
 #[test]
 fn test_gather() {
     let gather = Gather::new()
-        .input(vec!["dtmf".to_string(), "speech".to_string()])
+        .input(vec![GatherInput::Dtmf, GatherInput::Speech])
         .action("https://example.com/gather")
         .timeout(10)
         .add_say(Say::new("Please enter your account number"));
@@ -289,7 +304,7 @@ fn test_redirect() {
 
 #[test]
 fn test_reject() {
-    let response = VoiceResponse::new().reject(Reject::new().reason("busy"));
+    let response = VoiceResponse::new().reject(Reject::new().reason(RejectReason::Busy));
     let xml = response.to_xml();
     assert!(xml.contains("<Reject"));
     assert!(xml.contains("reason=\"busy\""));
@@ -302,7 +317,7 @@ fn test_complex_response() {
         .pause(Some(1))
         .gather(
             Gather::new()
-                .input(vec!["dtmf".to_string()])
+                .input(vec![GatherInput::Dtmf])
                 .num_digits(1)
                 .add_say(Say::new("Press 1 for sales, 2 for support")),
         )
@@ -320,10 +335,9 @@ fn test_complex_response() {
 
 #[test]
 fn test_connect_with_stream() {
-    let stream = Stream::new()
+    let stream = Stream::with_url("wss://example.com/stream")
         .name("my-stream")
-        .url("wss://example.com/stream")
-        .track("both_tracks");
+        .track(MediaTrack::BothTracks);
 
     let connect = Connect::new()
         .action("https://example.com/connect-status")
@@ -414,7 +428,7 @@ fn test_enqueue() {
 #[test]
 fn test_pay() {
     let pay = Pay::new()
-        .input("dtmf")
+        .input(PayInput::Dtmf)
         .action("https://example.com/payment-complete")
         .charge_amount("10.00")
         .currency("USD");
@@ -445,9 +459,7 @@ fn test_refer_with_sip() {
 
 #[test]
 fn test_start_with_stream() {
-    let stream = Stream::new()
-        .name("recording-stream")
-        .url("wss://example.com/recording");
+    let stream = Stream::with_url("wss://example.com/recording").name("recording-stream");
 
     let start = Start::new().add_stream(stream);
     let response = VoiceResponse::new().start(start);
@@ -462,7 +474,7 @@ fn test_start_with_stream() {
 fn test_start_with_transcription() {
     let transcription = Transcription::new()
         .name("live-transcription")
-        .track("inbound_track")
+        .track(MediaTrack::InboundTrack)
         .language_code("en-US");
 
     let start = Start::new().add_transcription(transcription);
@@ -477,7 +489,7 @@ fn test_start_with_transcription() {
 
 #[test]
 fn test_start_with_recording_basic() {
-    let recording = Recording::new().track("both");
+    let recording = Recording::new().track(RecordingTrack::Both);
 
     let start = Start::new().add_recording(recording);
     let response = VoiceResponse::new().start(start);
@@ -494,10 +506,13 @@ fn test_start_with_recording_all_attributes() {
     let recording = Recording::new()
         .recording_status_callback("https://example.com/recording-status")
         .recording_status_callback_method("POST")
-        .recording_status_callback_event("in-progress completed")
-        .trim("trim-silence")
-        .track("both")
-        .channels("dual");
+        .recording_status_callback_event(vec![
+            RecordingEvent::InProgress,
+            RecordingEvent::Completed,
+        ])
+        .trim(Trim::TrimSilence)
+        .track(RecordingTrack::Both)
+        .channels(RecordingChannels::Dual);
 
     let start = Start::new()
         .action("https://example.com/start-action")
@@ -522,7 +537,9 @@ fn test_start_with_recording_all_attributes() {
 
 #[test]
 fn test_start_with_recording_inbound_track() {
-    let recording = Recording::new().track("inbound").channels("mono");
+    let recording = Recording::new()
+        .track(RecordingTrack::Inbound)
+        .channels(RecordingChannels::Mono);
 
     let start = Start::new().add_recording(recording);
     let response = VoiceResponse::new().start(start);
@@ -534,7 +551,9 @@ fn test_start_with_recording_inbound_track() {
 
 #[test]
 fn test_start_with_recording_outbound_track() {
-    let recording = Recording::new().track("outbound").trim("do-not-trim");
+    let recording = Recording::new()
+        .track(RecordingTrack::Outbound)
+        .trim(Trim::DoNotTrim);
 
     let start = Start::new().add_recording(recording);
     let response = VoiceResponse::new().start(start);
@@ -546,15 +565,15 @@ fn test_start_with_recording_outbound_track() {
 
 #[test]
 fn test_start_with_multiple_nouns() {
-    let stream = Stream::new()
-        .name("my-stream")
-        .url("wss://example.com/stream");
+    let stream = Stream::with_url("wss://example.com/stream").name("my-stream");
 
-    let recording = Recording::new().track("both").channels("dual");
+    let recording = Recording::new()
+        .track(RecordingTrack::Both)
+        .channels(RecordingChannels::Dual);
 
     let transcription = Transcription::new()
         .name("my-transcription")
-        .track("inbound_track");
+        .track(MediaTrack::InboundTrack);
 
     let start = Start::new()
         .add_stream(stream)
@@ -748,7 +767,7 @@ fn test_comments_with_all_features() {
         .comment("Gather input section")
         .gather(
             Gather::new()
-                .input(vec!["dtmf".to_string()])
+                .input(vec![GatherInput::Dtmf])
                 .num_digits(1)
                 .add_say(Say::new("Press 1 for sales")),
         )
@@ -804,8 +823,11 @@ fn test_empty_response_with_comments() {
 // Tests for new Record attributes
 #[test]
 fn test_record_with_recording_channels() {
-    let response =
-        VoiceResponse::new().record_with(Record::new().recording_channels("dual").max_length(60));
+    let response = VoiceResponse::new().record_with(
+        Record::new()
+            .recording_channels(RecordingChannels::Dual)
+            .max_length(60),
+    );
 
     let xml = response.to_xml();
     assert!(xml.contains("recordingChannels=\"dual\""));
@@ -814,8 +836,11 @@ fn test_record_with_recording_channels() {
 
 #[test]
 fn test_record_with_recording_track() {
-    let response =
-        VoiceResponse::new().record_with(Record::new().recording_track("both").timeout(10));
+    let response = VoiceResponse::new().record_with(
+        Record::new()
+            .recording_track(RecordingTrack::Both)
+            .timeout(10),
+    );
 
     let xml = response.to_xml();
     assert!(xml.contains("recordingTrack=\"both\""));
@@ -826,13 +851,16 @@ fn test_record_with_recording_track() {
 fn test_record_with_all_new_attributes() {
     let response = VoiceResponse::new().record_with(
         Record::new()
-            .recording_channels("dual")
-            .recording_track("both")
+            .recording_channels(RecordingChannels::Dual)
+            .recording_track(RecordingTrack::Both)
             .finish_on_key("#")
-            .trim("trim-silence")
+            .trim(Trim::TrimSilence)
             .recording_status_callback("https://example.com/status")
             .recording_status_callback_method("POST")
-            .recording_status_callback_event(vec!["completed".to_string(), "absent".to_string()]),
+            .recording_status_callback_event(vec![
+                RecordingEvent::Completed,
+                RecordingEvent::Absent,
+            ]),
     );
 
     let xml = response.to_xml();
@@ -882,7 +910,7 @@ fn test_transcription_with_all_new_attributes() {
         Start::new().add_transcription(
             Transcription::new()
                 .name("full_transcription")
-                .track("both")
+                .track(MediaTrack::BothTracks)
                 .language_code("en-US")
                 .enable_automatic_punctuation(true)
                 .hints("Twilio,API")
@@ -899,7 +927,7 @@ fn test_transcription_with_all_new_attributes() {
 
     let xml = response.to_xml();
     assert!(xml.contains("name=\"full_transcription\""));
-    assert!(xml.contains("track=\"both\""));
+    assert!(xml.contains("track=\"both_tracks\""));
     assert!(xml.contains("languageCode=\"en-US\""));
     assert!(xml.contains("enableAutomaticPunctuation=\"true\""));
     assert!(xml.contains("hints=\"Twilio,API\""));
@@ -948,24 +976,24 @@ fn test_conference_with_all_new_attributes() {
         Dial::new().add_conference(
             DialConference::new("FullFeaturedRoom")
                 .muted(false)
-                .beep("true")
+                .beep(ConferenceBeep::True)
                 .start_conference_on_enter(true)
                 .end_conference_on_exit(false)
                 .wait_url("https://example.com/wait")
                 .wait_method("GET")
                 .max_participants(10)
-                .record("record-from-start")
-                .region("us1")
+                .record(ConferenceRecord::RecordFromStart)
+                .region(ConferenceRegion::Us1)
                 .coach("CA1234567890abcdef1234567890abcdef")
-                .trim("trim-silence")
-                .status_callback_event(vec!["start".to_string(), "end".to_string()])
+                .trim(Trim::TrimSilence)
+                .status_callback_event(vec![ConferenceEvent::Start, ConferenceEvent::End])
                 .status_callback("https://example.com/status")
                 .status_callback_method("POST")
                 .recording_status_callback("https://example.com/recording")
                 .recording_status_callback_method("POST")
-                .recording_status_callback_event(vec!["completed".to_string()])
+                .recording_status_callback_event(vec![RecordingEvent::Completed])
                 .event_callback_url("https://example.com/events")
-                .jitter_buffer_size("large")
+                .jitter_buffer_size(ConferenceJitterBufferSize::Large)
                 .participant_label("Agent")
                 .call_sid_to_coach("CA9876543210fedcba9876543210fedcba")
                 .beep_on_customer_entrance(true)
@@ -1039,6 +1067,9 @@ fn test_sip_with_custom_headers() {
     let xml = response.to_xml();
     assert!(xml.contains("<SipHeader name=\"X-Custom-Header\" value=\"value1\"/>"));
     assert!(xml.contains("<SipHeader name=\"X-Another-Header\" value=\"value2\"/>"));
+    assert!(response
+        .to_xml_with(&RenderOptions::pretty())
+        .contains("<SipHeader name=\"X-Custom-Header\" value=\"value1\" />"));
 }
 
 #[test]
@@ -1065,7 +1096,7 @@ fn test_sip_with_all_new_attributes() {
                 .method("POST")
                 .username("user123")
                 .password("pass456")
-                .status_callback_event(vec!["initiated".to_string(), "ringing".to_string()])
+                .status_callback_event(vec![CallStatusEvent::Initiated, CallStatusEvent::Ringing])
                 .status_callback("https://example.com/status")
                 .status_callback_method("POST")
                 .codecs(vec![
@@ -1172,7 +1203,7 @@ fn test_number_with_all_attributes() {
                 .url("https://example.com/number")
                 .method("POST")
                 .status_callback("https://example.com/status")
-                .status_callback_event(vec!["initiated".to_string(), "answered".to_string()])
+                .status_callback_event(vec![CallStatusEvent::Initiated, CallStatusEvent::Answered])
                 .status_callback_method("POST")
                 .call_reason("Sales Call")
                 .byoc("byoc-trunk-sid")
@@ -1197,11 +1228,11 @@ fn test_number_with_all_attributes() {
 fn test_gather_with_speech_attributes() {
     let response = VoiceResponse::new().gather_with(
         Gather::new()
-            .input(vec!["speech".to_string()])
+            .input(vec![GatherInput::Speech])
             .language("en-US")
             .hints("Twilio,TwiML")
             .enhanced(true)
-            .speech_model("phone_call")
+            .speech_model(SpeechModel::PhoneCall)
             .speech_timeout("auto")
             .profanity_filter(true),
     );
@@ -1221,7 +1252,7 @@ fn test_gather_with_speech_attributes() {
 fn test_gather_with_partial_results() {
     let response = VoiceResponse::new().gather_with(
         Gather::new()
-            .input(vec!["speech".to_string()])
+            .input(vec![GatherInput::Speech])
             .partial_result_callback("https://example.com/partial")
             .partial_result_callback_method("POST"),
     );
@@ -1235,7 +1266,7 @@ fn test_gather_with_partial_results() {
 fn test_gather_with_all_new_attributes() {
     let response = VoiceResponse::new().gather_with(
         Gather::new()
-            .input(vec!["dtmf".to_string(), "speech".to_string()])
+            .input(vec![GatherInput::Dtmf, GatherInput::Speech])
             .action("https://example.com/gather")
             .method("POST")
             .timeout(5)
@@ -1251,7 +1282,7 @@ fn test_gather_with_all_new_attributes() {
             .partial_result_callback("https://example.com/partial")
             .partial_result_callback_method("POST")
             .profanity_filter(false)
-            .speech_model("numbers_and_commands")
+            .speech_model(SpeechModel::NumbersAndCommands)
             .speech_timeout("5"),
     );
 
@@ -1353,8 +1384,8 @@ fn test_connect_with_conversation_relay_with_languages() {
 
 #[test]
 fn test_connect_with_conversation_relay_with_parameters() {
-    let param1 = Parameter::new().name("custom_field").value("custom_value");
-    let param2 = Parameter::new().name("user_id").value("12345");
+    let param1 = Parameter::with_value("custom_field", "custom_value");
+    let param2 = Parameter::with_value("user_id", "12345");
 
     let relay = ConversationRelay::new("https://example.com/relay")
         .add_parameter(param1)
@@ -1377,7 +1408,7 @@ fn test_connect_with_conversation_relay_with_parameters() {
 #[test]
 fn test_connect_with_conversation_relay_complete() {
     let lang = Language::new("en-US").tts_provider("google");
-    let param = Parameter::new().name("session_id").value("abc123");
+    let param = Parameter::with_value("session_id", "abc123");
 
     let relay = ConversationRelay::new("https://example.com/relay")
         .welcome_greeting("Welcome!")
@@ -1437,7 +1468,7 @@ fn test_dial_application_with_copy_parent_to() {
 
 #[test]
 fn test_dial_application_with_parameters() {
-    let param = Parameter::new().name("user_id").value("12345");
+    let param = Parameter::with_value("user_id", "12345");
     let app = DialApplication::new("APxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx").add_parameter(param);
     let dial = Dial::new().add_application(app);
     let response = VoiceResponse::new().dial_with(dial);
@@ -1453,7 +1484,7 @@ fn test_dial_application_with_parameters() {
 
 #[test]
 fn test_dial_application_complete() {
-    let param = Parameter::new().name("session_id").value("abc123");
+    let param = Parameter::with_value("session_id", "abc123");
     let app = DialApplication::new("APxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx")
         .customer_id("CUST123")
         .copy_parent_to("parent_call_sid")
@@ -1489,7 +1520,7 @@ fn test_dial_client_with_all_attributes() {
     let client = DialClient::new("alice")
         .url("https://example.com/client")
         .method("POST")
-        .status_callback_event(vec!["initiated".to_string(), "ringing".to_string()])
+        .status_callback_event(vec![CallStatusEvent::Initiated, CallStatusEvent::Ringing])
         .status_callback("https://example.com/status")
         .status_callback_method("POST")
         .client_notification_url("https://example.com/notify");
@@ -1510,7 +1541,7 @@ fn test_dial_client_with_all_attributes() {
 #[test]
 fn test_gather_with_dtmf_detection_disabled() {
     let gather = Gather::new()
-        .input(vec!["speech".to_string()])
+        .input(vec![GatherInput::Speech])
         .dtmf_detection(false);
     let response = VoiceResponse::new().gather_with(gather);
     let xml = response.to_xml();
@@ -1523,7 +1554,7 @@ fn test_gather_with_dtmf_detection_disabled() {
 #[test]
 fn test_gather_with_dtmf_detection_enabled() {
     let gather = Gather::new()
-        .input(vec!["speech".to_string(), "dtmf".to_string()])
+        .input(vec![GatherInput::Speech, GatherInput::Dtmf])
         .dtmf_detection(true);
     let response = VoiceResponse::new().gather_with(gather);
     let xml = response.to_xml();
@@ -1532,3 +1563,325 @@ fn test_gather_with_dtmf_detection_enabled() {
     assert!(xml.contains("input=\"speech dtmf\""));
     assert!(xml.contains("dtmfDetection=\"true\""));
 }
+
+#[test]
+fn test_from_xml_round_trip_simple() {
+    let response = VoiceResponse::new()
+        .comment_before("generated")
+        .comment("menu")
+        .say("Hello & welcome")
+        .play("https://example.com/hold.mp3")
+        .pause(Some(2))
+        .redirect("https://example.com/next?a=1&b=2")
+        .hangup()
+        .comment_after("done");
+    let xml = response.to_xml();
+
+    let parsed = VoiceResponse::from_xml(&xml).unwrap();
+    assert_eq!(parsed.to_xml(), xml);
+}
+
+#[test]
+fn test_from_xml_keeps_comments_between_verbs() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Response>
+  <!-- greeting -->
+  <Say>Hello</Say>
+  <!-- then hang up -->
+  <Hangup />
+  <!-- end -->
+</Response>"#;
+
+    let parsed = VoiceResponse::from_xml(xml).unwrap();
+    assert_eq!(parsed.to_xml(), xml);
+
+    let built = VoiceResponse::new()
+        .comment("greeting")
+        .say("Hello")
+        .comment_here("then hang up")
+        .hangup()
+        .comment_here("end");
+    assert_eq!(built.to_xml(), xml);
+}
+
+#[test]
+fn test_from_xml_round_trip_nested() {
+    let gather = Gather::new()
+        .input(vec![GatherInput::Dtmf, GatherInput::Speech])
+        .action("https://example.com/menu")
+        .num_digits(1)
+        .add_say(
+            Say::new("Press 1")
+                .voice(Voice::Polly(PollyVoice::Joanna, PollyEngine::Standard))
+                .add_break(None, Some("500ms".to_string())),
+        )
+        .add_pause(Pause::new(None));
+    let dial = Dial::new()
+        .timeout(20)
+        .add_number(DialNumber::new("+15551234567").send_digits("ww1"))
+        .add_sip(DialSip::new("sip:agent@example.com").add_custom_header("X-Id", "42"))
+        .add_conference(DialConference::new("Room 1").beep(ConferenceBeep::OnEnter))
+        .add_application(
+            DialApplication::new("AP123").add_parameter(Parameter::with_value("k", "v")),
+        );
+    let connect = Connect::new().add_conversation_relay(
+        ConversationRelay::new("wss://example.com/relay")
+            .welcome_greeting("Hi")
+            .add_language(Language::new("en-US").tts_provider("google")),
+    );
+    let start = Start::new()
+        .add_stream(Stream::with_url("wss://example.com/stream"))
+        .add_transcription(Transcription::new().language_code("en-US"));
+    let response = VoiceResponse::new()
+        .gather(gather)
+        .dial_with(dial)
+        .connect(connect)
+        .start(start)
+        .refer(Refer::new().add_refer_sip(ReferSip::new("sip:transfer@example.com")));
+    let xml = response.to_xml();
+
+    let parsed = VoiceResponse::from_xml(&xml).unwrap();
+    assert_eq!(parsed.to_xml(), xml);
+}
+
+#[test]
+fn test_from_xml_say_with_interleaved_ssml() {
+    let xml = r#"<Response><Say>Hello <break time="1s"/> world <emphasis>now</emphasis></Say></Response>"#;
+    let response = VoiceResponse::from_xml(xml).unwrap();
+    let out = response.to_xml();
+    assert!(out.contains("<Say>Hello <break time=\"1s\" /> world <emphasis>now</emphasis></Say>"));
+}
+
+#[test]
+fn test_from_xml_unknown_element() {
+    let xml = r#"<Response><Speak>Hello</Speak></Response>"#;
+    let err = VoiceResponse::from_xml(xml).unwrap_err();
+    assert!(matches!(
+        err,
+        twiml_rust::Error::UnknownElement { ref element, .. } if element == "Speak"
+    ));
+}
+
+#[test]
+fn test_from_xml_unknown_attribute() {
+    let xml = r#"<Response><Dial timout="10">+15551234567</Dial></Response>"#;
+    let err = VoiceResponse::from_xml(xml).unwrap_err();
+    assert!(matches!(
+        err,
+        twiml_rust::Error::UnknownAttribute { ref path, ref attribute }
+            if path == "Response/Dial" && attribute == "timout"
+    ));
+}
+
+#[test]
+fn test_from_xml_malformed() {
+    let xml = r#"<Response><Say>Hello</Response>"#;
+    assert!(matches!(
+        VoiceResponse::from_xml(xml),
+        Err(twiml_rust::Error::Parse { .. })
+    ));
+}
+
+#[test]
+fn test_from_xml_invalid_boolean() {
+    let xml = r#"<Response><Record playBeep="yes" /></Response>"#;
+    assert!(matches!(
+        VoiceResponse::from_xml(xml),
+        Err(twiml_rust::Error::InvalidParameter { ref param, .. }) if param == "playBeep"
+    ));
+}
+
+#[test]
+fn test_attribute_enum_from_str() {
+    assert_eq!(
+        "record-from-answer".parse::<DialRecord>().unwrap(),
+        DialRecord::RecordFromAnswer
+    );
+    assert_eq!(DialRecord::RecordFromAnswer.as_str(), "record-from-answer");
+    assert!(matches!(
+        "record-from-anwser".parse::<DialRecord>(),
+        Err(twiml_rust::Error::InvalidParameter { ref param, .. }) if param == "record"
+    ));
+}
+
+#[test]
+fn test_attribute_enum_custom_value() {
+    let response = VoiceResponse::new()
+        .reject(Reject::new().reason(RejectReason::Custom("declined".to_string())));
+    assert!(response.to_xml().contains("<Reject reason=\"declined\" />"));
+}
+
+#[test]
+fn test_from_xml_keeps_unknown_enum_values() {
+    let xml = r#"<Response><Record trim="trim-silence" recordingChannels="stereo" /></Response>"#;
+    let response = VoiceResponse::from_xml(xml).unwrap();
+    let out = response.to_xml();
+    assert!(out.contains("trim=\"trim-silence\""));
+    assert!(out.contains("recordingChannels=\"stereo\""));
+}
+
+#[test]
+fn test_voice_names() {
+    assert_eq!(Voice::Alice.to_string(), "alice");
+    assert_eq!(
+        Voice::Polly(PollyVoice::Matthew, PollyEngine::Generative).to_string(),
+        "Polly.Matthew-Generative"
+    );
+    assert_eq!(
+        Voice::google("en-GB", GoogleVoiceModel::Wavenet, "B").to_string(),
+        "Google.en-GB-Wavenet-B"
+    );
+    assert_eq!(
+        "Polly.Lupe-Neural".parse::<Voice>().unwrap(),
+        Voice::Polly(PollyVoice::Lupe, PollyEngine::Neural)
+    );
+    assert_eq!(
+        "Google.fr-FR-Neural2-A".parse::<Voice>().unwrap(),
+        Voice::google("fr-FR", GoogleVoiceModel::Neural2, "A")
+    );
+    assert!("Polly.Nobody".parse::<Voice>().is_err());
+}
+
+#[test]
+fn test_voice_languages() {
+    let hans = Voice::Polly(PollyVoice::Hans, PollyEngine::Standard);
+    assert_eq!(hans.supports_language("de-DE"), Some(true));
+    assert_eq!(hans.supports_language("en-US"), Some(false));
+    assert_eq!(Voice::Man.supports_language("en"), Some(true));
+    assert_eq!(Voice::Alice.supports_language("ja-jp"), Some(true));
+    assert_eq!(
+        Voice::Custom("Acme.Bot".to_string()).supports_language("en-US"),
+        None
+    );
+    assert!(!Voice::Polly(PollyVoice::Hans, PollyEngine::Neural).is_available());
+}
+
+#[test]
+fn test_from_xml_say_voice() {
+    let xml = r#"<Response><Say voice="Polly.Joanna-Neural">Hi</Say><Say voice="Acme.Bot">Hi</Say></Response>"#;
+    let response = VoiceResponse::from_xml(xml).unwrap();
+    let out = response.to_xml();
+    assert!(out.contains("voice=\"Polly.Joanna-Neural\""));
+    assert!(out.contains("voice=\"Acme.Bot\""));
+}
+
+#[test]
+fn test_write_xml_matches_to_xml() {
+    let response = VoiceResponse::new()
+        .say("Hello")
+        .gather(Gather::new().input(vec![GatherInput::Dtmf]).num_digits(1))
+        .hangup();
+
+    let mut buffer = String::from("stale");
+    buffer.clear();
+    response.write_xml(&mut buffer).unwrap();
+    assert_eq!(buffer, response.to_xml());
+
+    let mut bytes = Vec::new();
+    response.write_xml_io(&mut bytes).unwrap();
+    assert_eq!(String::from_utf8(bytes).unwrap(), response.to_xml());
+}
+
+#[test]
+fn test_write_xml_io_reports_sink_error() {
+    struct Full;
+
+    impl std::io::Write for Full {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::new(std::io::ErrorKind::WriteZero, "full"))
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let err = VoiceResponse::new()
+        .say("Hello")
+        .write_xml_io(&mut Full)
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+}
+
+#[test]
+fn test_to_xml_with_compact() {
+    let response = VoiceResponse::new()
+        .say("Hi")
+        .dial_with(Dial::new().add_number(DialNumber::new("+15551234567")))
+        .hangup();
+
+    assert_eq!(
+        response.to_xml_with(&RenderOptions::compact()),
+        "<Response><Say>Hi</Say><Dial><Number>+15551234567</Number></Dial><Hangup/></Response>"
+    );
+}
+
+#[test]
+fn test_to_xml_with_custom_layout() {
+    let options = RenderOptions::new()
+        .indent(4)
+        .newline(Newline::CrLf)
+        .xml_declaration(false)
+        .self_closing(SelfClosing::Compact);
+    let response = VoiceResponse::new().gather(
+        Gather::new()
+            .input(vec![GatherInput::Dtmf])
+            .add_say(Say::new("Press 1")),
+    );
+
+    assert_eq!(
+        response.to_xml_with(&options),
+        "<Response>\r\n    <Gather input=\"dtmf\">\r\n        <Say>Press 1</Say>\r\n    </Gather>\r\n</Response>"
+    );
+    assert_eq!(
+        response.to_xml(),
+        response.to_xml_with(&RenderOptions::pretty())
+    );
+}
+
+#[test]
+fn test_fragment_splices_rendered_verbs() {
+    let menu = || {
+        Gather::new()
+            .input(vec![GatherInput::Dtmf])
+            .num_digits(1)
+            .add_say(Say::new("For sales, press 1. For support, press 2."))
+    };
+    let fragment = VoiceResponse::new()
+        .gather(menu())
+        .pause(Some(1))
+        .to_fragment();
+    assert!(fragment.as_str().starts_with("  <Gather"));
+    assert_eq!(fragment.as_bytes(), fragment.as_str().as_bytes());
+
+    let spliced = VoiceResponse::new()
+        .say("Welcome back")
+        .fragment(fragment.clone())
+        .hangup();
+    let direct = VoiceResponse::new()
+        .say("Welcome back")
+        .gather(menu())
+        .pause(Some(1))
+        .hangup();
+    assert_eq!(spliced.to_xml(), direct.to_xml());
+
+    // A different layout re-renders the verbs instead of copying the stored XML
+    let compact = RenderOptions::compact();
+    assert_eq!(spliced.to_xml_with(&compact), direct.to_xml_with(&compact));
+}
+
+#[test]
+fn test_fragment_is_validated() {
+    let fragment = VoiceResponse::new()
+        .say("Hi")
+        .record_with(Record::new().max_length(20000))
+        .to_fragment_with(&RenderOptions::compact());
+    assert_eq!(fragment.options(), &RenderOptions::compact());
+
+    let errors = VoiceResponse::new().fragment(fragment).validate().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].context.as_deref(),
+        Some("verbs[0].Fragment[1].Record.attributes.max_length")
+    );
+}
@@ -0,0 +1,199 @@
+use twiml_rust::voice::{Gather, GatherInput};
+use twiml_rust::webhook::{
+    CallDirection, CallStatus, FaxRequest, FaxStatus, FormParams, GatherEnd, GatherPartialResult,
+    GatherResult, GatheredInput, MessagingRequest, VoiceRequest,
+};
+use twiml_rust::Error;
+
+const VOICE: &str = "Called=%2B15559870002&ToState=CA&CallerCountry=US&Direction=inbound\
+&CallerState=CA&ToZip=94105&CallSid=CA0123456789abcdef0123456789abcdef&To=%2B15559870002\
+&CallerZip=94117&ToCountry=US&ApiVersion=2010-04-01&CalledZip=94105&CalledCity=SAN+FRANCISCO\
+&CallStatus=ringing&From=%2B15551230001&AccountSid=AC0123456789abcdef0123456789abcdef\
+&CalledCountry=US&CallerCity=SAN+FRANCISCO&Caller=%2B15551230001&FromCountry=US&ToCity=\
+&FromCity=SAN+FRANCISCO&CalledState=CA&FromZip=94117&FromState=CA";
+
+#[test]
+fn test_voice_request() {
+    let request = VoiceRequest::from_form(VOICE).unwrap();
+
+    assert_eq!(request.call_sid, "CA0123456789abcdef0123456789abcdef");
+    assert_eq!(request.account_sid, "AC0123456789abcdef0123456789abcdef");
+    assert_eq!(request.from, "+15551230001");
+    assert_eq!(request.to, "+15559870002");
+    assert_eq!(request.call_status, CallStatus::Ringing);
+    assert_eq!(request.direction, CallDirection::Inbound);
+    assert_eq!(request.api_version.as_deref(), Some("2010-04-01"));
+    assert_eq!(request.forwarded_from, None);
+    assert_eq!(request.caller.city.as_deref(), Some("SAN FRANCISCO"));
+    assert_eq!(request.caller.zip.as_deref(), Some("94117"));
+    assert_eq!(request.called.zip.as_deref(), Some("94105"));
+    assert_eq!(request.called.country.as_deref(), Some("US"));
+    assert_eq!(request.params.get("ToCity"), Some(""));
+}
+
+#[test]
+fn test_unknown_status_is_kept() {
+    let body = VOICE.replace("CallStatus=ringing", "CallStatus=paused");
+    let request = VoiceRequest::from_form(&body).unwrap();
+    assert_eq!(
+        request.call_status,
+        CallStatus::Custom("paused".to_string())
+    );
+    assert_eq!(request.call_status.to_string(), "paused");
+}
+
+#[test]
+fn test_missing_parameter_is_reported() {
+    let body = VOICE.replace("CallSid=", "CallSidX=");
+    match VoiceRequest::from_form(&body) {
+        Err(Error::InvalidParameter { param, .. }) => assert_eq!(param, "CallSid"),
+        other => panic!("expected a missing CallSid, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_messaging_request_with_media() {
+    let body = "MessageSid=MM123&AccountSid=AC123&MessagingServiceSid=MG123\
+&From=%2B15551230001&To=%2B15559870002&Body=Caf%C3%A9+%26+more%21&NumMedia=2&NumSegments=1\
+&MediaUrl0=https%3A%2F%2Fapi.twilio.com%2Fmedia%2FME0&MediaContentType0=image%2Fjpeg\
+&MediaUrl1=https%3A%2F%2Fapi.twilio.com%2Fmedia%2FME1&MediaContentType1=image%2Fpng\
+&FromCity=BOSTON&ToCountry=US";
+    let request = MessagingRequest::from_form(body).unwrap();
+
+    assert_eq!(request.message_sid, "MM123");
+    assert_eq!(request.messaging_service_sid.as_deref(), Some("MG123"));
+    assert_eq!(request.body, "Café & more!");
+    assert_eq!(request.num_media, 2);
+    assert_eq!(request.num_segments, Some(1));
+    assert_eq!(request.media.len(), 2);
+    assert_eq!(request.media[1].url, "https://api.twilio.com/media/ME1");
+    assert_eq!(request.media[1].content_type.as_deref(), Some("image/png"));
+    assert_eq!(request.from_location.city.as_deref(), Some("BOSTON"));
+    assert_eq!(request.to_location.country.as_deref(), Some("US"));
+}
+
+#[test]
+fn test_messaging_request_with_missing_media() {
+    let body = "MessageSid=MM1&AccountSid=AC1&From=a&To=b&Body=&NumMedia=1";
+    match MessagingRequest::from_form(body) {
+        Err(Error::InvalidParameter { param, .. }) => assert_eq!(param, "MediaUrl0"),
+        other => panic!("expected a missing MediaUrl0, got {:?}", other),
+    }
+
+    let body = "MessageSid=MM1&AccountSid=AC1&From=a&To=b&NumMedia=many";
+    assert!(MessagingRequest::from_form(body).is_err());
+}
+
+#[test]
+fn test_fax_request() {
+    let body = "FaxSid=FX123&AccountSid=AC123&From=%2B15551230001&To=%2B15559870002\
+&ApiVersion=v1&FaxStatus=received&NumPages=3&MediaUrl=https%3A%2F%2Fmedia.twiliocdn.com%2Ffax.pdf\
+&RemoteStationId=HQ+FAX";
+    let request = FaxRequest::from_form(body).unwrap();
+
+    assert_eq!(request.fax_sid, "FX123");
+    assert_eq!(request.fax_status, Some(FaxStatus::Received));
+    assert_eq!(request.num_pages, Some(3));
+    assert_eq!(
+        request.media_url.as_deref(),
+        Some("https://media.twiliocdn.com/fax.pdf")
+    );
+    assert_eq!(request.remote_station_id.as_deref(), Some("HQ FAX"));
+    assert_eq!(request.error_code, None);
+}
+
+#[test]
+fn test_form_decoding() {
+    let params = FormParams::parse("a=1&&b=x+y%2Bz&c&a=2&%E2%9C%93=%e2%9c%93").unwrap();
+    assert_eq!(params.len(), 5);
+    assert_eq!(params.get("a"), Some("1"));
+    assert_eq!(params.get("b"), Some("x y+z"));
+    assert_eq!(params.get("c"), Some(""));
+    assert_eq!(params.get("✓"), Some("✓"));
+    assert_eq!(params.iter().filter(|(n, _)| *n == "a").count(), 2);
+
+    assert!(FormParams::parse("a=%2").is_err());
+    assert!(FormParams::parse("a=%zz").is_err());
+    assert!(FormParams::parse("a=%+f").is_err());
+    assert!(FormParams::parse("a=%ff").is_err());
+    assert!(FormParams::parse("").unwrap().is_empty());
+}
+
+const CALL: &str = "CallSid=CA1&AccountSid=AC1&From=%2B15551230001&To=%2B15559870002\
+&CallStatus=in-progress&Direction=inbound";
+
+#[test]
+fn test_gather_result_dtmf() {
+    let result = GatherResult::from_form(&format!("{}&Digits=1234", CALL)).unwrap();
+    assert_eq!(result.call.call_sid, "CA1");
+    assert_eq!(result.input(), Some(GatheredInput::Dtmf("1234")));
+    assert!(result.is_dtmf() && !result.is_speech());
+
+    let gather = Gather::new().num_digits(4);
+    assert_eq!(result.ended_by(&gather), GatherEnd::NumDigits);
+    assert!(result.accepted_by(&gather));
+
+    let gather = Gather::new().num_digits(6);
+    assert_eq!(result.ended_by(&gather), GatherEnd::Timeout);
+    assert!(result.accepted_by(&gather));
+
+    assert!(!result.accepted_by(&Gather::new().num_digits(3)));
+    assert!(!result.accepted_by(&Gather::new().input(vec![GatherInput::Speech])));
+}
+
+#[test]
+fn test_gather_result_finish_on_key() {
+    let body = format!("{}&Digits=12&FinishedOnKey=%2A", CALL);
+    let result = GatherResult::from_form(&body).unwrap();
+
+    let gather = Gather::new().finish_on_key("*").num_digits(5);
+    assert_eq!(
+        result.ended_by(&gather),
+        GatherEnd::FinishOnKey("*".to_string())
+    );
+    assert!(result.accepted_by(&gather));
+    assert!(!result.accepted_by(&Gather::new()));
+}
+
+#[test]
+fn test_gather_result_speech() {
+    let body = format!("{}&SpeechResult=Talk+to+sales.&Confidence=0.87", CALL);
+    let result = GatherResult::from_form(&body).unwrap();
+
+    assert_eq!(
+        result.input(),
+        Some(GatheredInput::Speech {
+            text: "Talk to sales.",
+            confidence: Some(0.87)
+        })
+    );
+    assert!(result.is_speech());
+
+    let gather = Gather::new().input(vec![GatherInput::Dtmf, GatherInput::Speech]);
+    assert_eq!(result.ended_by(&gather), GatherEnd::Speech);
+    assert!(result.accepted_by(&gather));
+    assert!(!result.accepted_by(&Gather::new()));
+}
+
+#[test]
+fn test_gather_result_empty() {
+    let result = GatherResult::from_form(&format!("{}&Digits=", CALL)).unwrap();
+    assert_eq!(result.input(), None);
+    assert_eq!(result.ended_by(&Gather::new()), GatherEnd::NoInput);
+    assert!(result.accepted_by(&Gather::new()));
+
+    assert!(GatherResult::from_form(&format!("{}&Confidence=high", CALL)).is_err());
+}
+
+#[test]
+fn test_gather_partial_result() {
+    let body = "CallSid=CA1&AccountSid=AC1&SequenceNumber=3\
+&UnstableSpeechResult=to+sa&StableSpeechResult=I+want&Stability=0.4";
+    let partial = GatherPartialResult::from_form(body).unwrap();
+
+    assert_eq!(partial.sequence_number, Some(3));
+    assert_eq!(partial.unstable_speech_result, "to sa");
+    assert_eq!(partial.stable_speech_result.as_deref(), Some("I want"));
+    assert_eq!(partial.stability, Some(0.4));
+    assert!(GatherPartialResult::from_form("CallSid=CA1&AccountSid=AC1").is_err());
+}
@@ -1,4 +1,9 @@
-use twiml_rust::xml_escape::{escape_xml_attr, escape_xml_text};
+use std::borrow::Cow;
+
+use twiml_rust::xml_escape::{
+    escape_xml_attr, escape_xml_text, is_xml_char, write_xml_attr, write_xml_text,
+};
+use twiml_rust::{TwiML, VoiceResponse};
 
 #[test]
 fn test_escape_xml_text_basic() {
@@ -78,3 +83,40 @@ fn test_escape_xml_attr_url_with_params() {
         "https://example.com?a=1&amp;b=2"
     );
 }
+
+#[test]
+fn test_escape_borrows_when_unchanged() {
+    assert!(matches!(escape_xml_text("Hello World"), Cow::Borrowed(_)));
+    assert!(matches!(
+        escape_xml_attr("https://example.com/a"),
+        Cow::Borrowed(_)
+    ));
+    assert!(matches!(escape_xml_text("it's"), Cow::Borrowed(_)));
+    assert!(matches!(escape_xml_attr("it's"), Cow::Owned(_)));
+}
+
+#[test]
+fn test_escape_strips_illegal_characters() {
+    assert_eq!(escape_xml_text("a\u{0}b\u{1b}c\u{ffff}"), "abc");
+    assert_eq!(escape_xml_attr("x\u{fffe}&\u{8}"), "x&amp;");
+    assert_eq!(escape_xml_text("tab\tnew\nline\r"), "tab\tnew\nline\r");
+    assert!(!is_xml_char('\u{1f}'));
+    assert!(is_xml_char('\u{fffd}'));
+    assert!(is_xml_char('\u{10000}'));
+}
+
+#[test]
+fn test_write_escaped_into_sink() {
+    let mut out = String::new();
+    write_xml_text(&mut out, "5 < 10 & 'ok'").unwrap();
+    out.push('|');
+    write_xml_attr(&mut out, "say \"hi\"\u{7}").unwrap();
+    assert_eq!(out, "5 &lt; 10 &amp; 'ok'|say &quot;hi&quot;");
+}
+
+#[test]
+fn test_rendered_response_drops_illegal_characters() {
+    let xml = VoiceResponse::new().say("Caller\u{0} name\u{1}").to_xml();
+    assert!(xml.contains("<Say>Caller name</Say>"));
+    assert!(twiml_rust::validate_twiml(&xml).unwrap().is_empty());
+}